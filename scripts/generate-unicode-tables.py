@@ -0,0 +1,235 @@
+#!/usr/bin/env python3
+"""Generate `src/parser/unicode/tables.rs` from the Unicode data bundled with Python.
+
+Run from the repository root: `python3 scripts/generate-unicode-tables.py [--check] [Scripts.txt]`.
+With `--check`, the tables are not written; the script fails if they differ from the checked-in
+file.
+
+Script data is not bundled with Python, so it is read from the UCD `Scripts.txt` file for the same
+version of Unicode. If no path is provided, the file is downloaded from unicode.org.
+"""
+
+import re
+import sys
+import unicodedata
+import urllib.request
+from pathlib import Path
+
+OUTPUT = Path(__file__).resolve().parent.parent / "src" / "parser" / "unicode" / "tables.rs"
+
+# Every table is generated from this version of Unicode. It must match the version bundled with
+# the Python used to run this script.
+UNICODE_VERSION = "14.0.0"
+SCRIPTS_URL = f"https://www.unicode.org/Public/{UNICODE_VERSION}/ucd/Scripts.txt"
+
+
+def rust_char(cp):
+    return f"'\\u{{{cp:04X}}}'"
+
+
+def simple_case_folding():
+    """Pairs of characters and their simple case folding, excluding characters that fold to
+    themselves. Where the full folding is a single character, it is also the simple folding.
+    Otherwise the simple folding, if any, is the single-character lowercase mapping."""
+    pairs = []
+    for cp in range(0x110000):
+        c = chr(cp)
+        if unicodedata.category(c) in ("Cn", "Cs"):
+            continue
+        folded = c.casefold()
+        if len(folded) != 1:
+            folded = c.lower()
+        if len(folded) == 1 and folded != c:
+            pairs.append((cp, ord(folded)))
+    return pairs
+
+
+GENERAL_CATEGORIES = {
+    "Lu": "UppercaseLetter",
+    "Ll": "LowercaseLetter",
+    "Lt": "TitlecaseLetter",
+    "Lm": "ModifierLetter",
+    "Lo": "OtherLetter",
+    "Mn": "NonspacingMark",
+    "Mc": "SpacingMark",
+    "Me": "EnclosingMark",
+    "Nd": "DecimalNumber",
+    "Nl": "LetterNumber",
+    "No": "OtherNumber",
+    "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation",
+    "Ps": "OpenPunctuation",
+    "Pe": "ClosePunctuation",
+    "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation",
+    "Po": "OtherPunctuation",
+    "Sm": "MathSymbol",
+    "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol",
+    "So": "OtherSymbol",
+    "Zs": "SpaceSeparator",
+    "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator",
+    "Cc": "Control",
+    "Cf": "Format",
+    "Cs": "Surrogate",
+    "Co": "PrivateUse",
+    "Cn": "Unassigned",
+}
+
+SCRIPTS = """
+    Adlam Ahom Anatolian_Hieroglyphs Arabic Armenian Avestan Balinese Bamum Bassa_Vah Batak Bengali
+    Bhaiksuki Bopomofo Brahmi Braille Buginese Buhid Canadian_Aboriginal Carian Caucasian_Albanian
+    Chakma Cham Cherokee Chorasmian Common Coptic Cuneiform Cypriot Cypro_Minoan Cyrillic Deseret
+    Devanagari Dives_Akuru Dogra Duployan Egyptian_Hieroglyphs Elbasan Elymaic Ethiopic Georgian
+    Glagolitic Gothic Grantha Greek Gujarati Gunjala_Gondi Gurmukhi Han Hangul Hanifi_Rohingya
+    Hanunoo Hatran Hebrew Hiragana Imperial_Aramaic Inherited Inscriptional_Pahlavi
+    Inscriptional_Parthian Javanese Kaithi Kannada Katakana Kayah_Li Kharoshthi Khitan_Small_Script
+    Khmer Khojki Khudawadi Lao Latin Lepcha Limbu Linear_A Linear_B Lisu Lycian Lydian Mahajani
+    Makasar Malayalam Mandaic Manichaean Marchen Masaram_Gondi Medefaidrin Meetei_Mayek
+    Mende_Kikakui Meroitic_Cursive Meroitic_Hieroglyphs Miao Modi Mongolian Mro Multani Myanmar
+    Nabataean Nandinagari New_Tai_Lue Newa Nko Nushu Nyiakeng_Puachue_Hmong Ogham Ol_Chiki
+    Old_Hungarian Old_Italic Old_North_Arabian Old_Permic Old_Persian Old_Sogdian
+    Old_South_Arabian Old_Turkic Old_Uyghur Oriya Osage Osmanya Pahawh_Hmong Palmyrene Pau_Cin_Hau
+    Phags_Pa Phoenician Psalter_Pahlavi Rejang Runic Samaritan Saurashtra Sharada Shavian Siddham
+    SignWriting Sinhala Sogdian Sora_Sompeng Soyombo Sundanese Syloti_Nagri Syriac Tagalog
+    Tagbanwa Tai_Le Tai_Tham Tai_Viet Takri Tamil Tangsa Tangut Telugu Thaana Thai Tibetan Tifinagh
+    Tirhuta Toto Ugaritic Unknown Vai Vithkuqi Wancho Warang_Citi Yezidi Yi Zanabazar_Square
+""".split()
+
+
+def variant(name):
+    return name.replace("_", "")
+
+
+def assigned():
+    return [
+        cp
+        for cp in range(0x110000)
+        if unicodedata.category(chr(cp)) not in ("Cn", "Cs")
+    ]
+
+
+def ranges(values, default):
+    """Merge consecutive code points with the same value into inclusive ranges, omitting the
+    default value."""
+    result = []
+    for cp, value in sorted(values.items()):
+        if value == default:
+            continue
+        if result and result[-1][1] == cp - 1 and result[-1][2] == value:
+            result[-1][1] = cp
+        else:
+            result.append([cp, cp, value])
+    return result
+
+
+def general_categories():
+    values = {cp: unicodedata.category(chr(cp)) for cp in assigned()}
+    return ranges(values, "Cn")
+
+
+def read_scripts_txt(path):
+    if path is None:
+        with urllib.request.urlopen(SCRIPTS_URL) as response:
+            text = response.read().decode("utf-8")
+    else:
+        text = Path(path).read_text(encoding="utf-8")
+    header = text.splitlines()[0]
+    assert header == f"# Scripts-{UNICODE_VERSION}.txt", f"unexpected Scripts.txt: {header}"
+    return text
+
+
+def scripts(scripts_txt):
+    """Ranges of code points and their script. Code points not listed in `Scripts.txt` have the
+    script `Unknown`."""
+    values = {}
+    for line in scripts_txt.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        code_points, name = (field.strip() for field in line.split(";"))
+        assert name in SCRIPTS, f"unknown script {name}"
+        match = re.fullmatch(r"([0-9A-F]+)(?:\.\.([0-9A-F]+))?", code_points)
+        start = int(match.group(1), 16)
+        end = int(match.group(2) or match.group(1), 16)
+        for cp in range(start, end + 1):
+            assert cp not in values, f"U+{cp:04X} has multiple scripts"
+            values[cp] = name
+    unassigned = set(values) - set(assigned())
+    assert not unassigned, f"U+{min(unassigned):04X} is not assigned in Unicode {UNICODE_VERSION}"
+    return ranges(values, "Unknown")
+
+
+def xid():
+    """Ranges of XID_Start and XID_Continue characters. Python identifiers are defined as an
+    XID_Start character or `_`, followed by XID_Continue characters."""
+    start, cont = [], []
+    for cp in assigned():
+        c = chr(cp)
+        if c.isidentifier() and c != "_":
+            start.append(cp)
+        if ("a" + c).isidentifier():
+            cont.append(cp)
+    return merge(start), merge(cont)
+
+
+def merge(code_points):
+    return [(a, b) for a, b, _ in ranges({cp: True for cp in code_points}, None)]
+
+
+def main():
+    assert unicodedata.unidata_version == UNICODE_VERSION, (
+        f"Python bundles Unicode {unicodedata.unidata_version}, not {UNICODE_VERSION}"
+    )
+    args = sys.argv[1:]
+    check = "--check" in args
+    paths = [arg for arg in args if arg != "--check"]
+    scripts_txt = read_scripts_txt(paths[0] if paths else None)
+
+    lines = [
+        "// This file is generated by `scripts/generate-unicode-tables.py`. Do not edit it manually.",
+        "",
+        "use super::GeneralCategory::{self, *};",
+        "use self::Script::*;",
+        "",
+        "/// A Unicode script.",
+        "#[non_exhaustive]",
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]",
+        "pub enum Script {",
+        *(f"    {variant(name)}," for name in SCRIPTS),
+        "}",
+        "",
+        f"pub(super) const UNICODE_VERSION: (u8, u8, u8) = "
+        f"({', '.join(UNICODE_VERSION.split('.'))});",
+        "",
+        "pub(super) static CASE_FOLDING: &[(char, char)] = &[",
+    ]
+    lines += [f"    ({rust_char(a)}, {rust_char(b)})," for a, b in simple_case_folding()]
+    lines += [
+        "];",
+        "",
+        "pub(super) static GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[",
+    ]
+    lines += [
+        f"    ({rust_char(a)}, {rust_char(b)}, {GENERAL_CATEGORIES[c]}),"
+        for a, b, c in general_categories()
+    ]
+    lines += ["];", "", "pub(super) static SCRIPT: &[(char, char, Script)] = &["]
+    lines += [f"    ({rust_char(a)}, {rust_char(b)}, {variant(c)})," for a, b, c in scripts(scripts_txt)]
+    xid_start, xid_continue = xid()
+    lines += ["];", "", "pub(super) static XID_START: &[(char, char)] = &["]
+    lines += [f"    ({rust_char(a)}, {rust_char(b)})," for a, b in xid_start]
+    lines += ["];", "", "pub(super) static XID_CONTINUE: &[(char, char)] = &["]
+    lines += [f"    ({rust_char(a)}, {rust_char(b)})," for a, b in xid_continue]
+    lines += ["];", ""]
+    output = "\n".join(lines)
+    if check:
+        if OUTPUT.read_text() != output:
+            sys.exit(f"{OUTPUT} does not match the generated tables")
+    else:
+        OUTPUT.write_text(output)
+
+
+if __name__ == "__main__":
+    main()
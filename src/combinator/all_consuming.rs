@@ -0,0 +1,58 @@
+use crate::{error, Either, Parser};
+
+/// Run the parser, requiring that it consume the entire input.
+///
+/// If input remains after the parser succeeds, the error indicates the offset of the first byte
+/// that was not consumed.
+pub fn all_consuming<'input, P>(
+    parser: P,
+) -> impl Parser<'input, Output = P::Output, Error = Either<P::Error, error::TrailingInput>>
+where
+    P: Parser<'input>,
+{
+    move |input: &'input [u8]| {
+        let parsed_item = parser.parse(input).map_err(Either::A)?;
+        let remaining_input = parsed_item.input();
+
+        if remaining_input.is_empty() {
+            Ok(parsed_item)
+        } else {
+            Err(Either::B(error::TrailingInput {
+                offset: input.len() - remaining_input.len(),
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ascii;
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_all_consuming_success() {
+        assert_eq!(
+            ascii::int::<u8>
+                .all_consuming()
+                .parse(b"12")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 12))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_all_consuming_error() {
+        let parser = ascii::int::<u8>.all_consuming();
+
+        assert_eq!(
+            parser.parse(b"12a"),
+            Err(Either::B(error::TrailingInput { offset: 2 }))
+        );
+        assert_eq!(
+            parser.parse(b"a"),
+            Err(Either::A(Either::A(error::AsciiInteger)))
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Combinator, Either, Parser};
+
+/// Run the parser, then run the parser built from its output on the remaining input.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub const fn and_then<'input, P1, P2, F>(
+    f: F,
+) -> impl Combinator<
+    'input,
+    P1,
+    Output = P2::Output,
+    Error = Either<P1::Error, P2::Error>,
+> + Copy
+where
+    P1: Parser<'input>,
+    P2: Parser<'input>,
+    F: Fn(P1::Output) -> P2 + Copy,
+{
+    move |p1: P1| {
+        move |input| {
+            let (input, p1_value) = match p1.parse(input) {
+                Ok(parsed) => parsed.into_parts(),
+                Err(err) => return Err(Either::A(err)),
+            };
+            f(p1_value).parse(input).map_err(Either::B)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{any_byte, ascii};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_and_then_success() {
+        let parser = ascii::digit.and_then(
+            #[cfg_attr(coverage, coverage(off))]
+            |n| any_byte.exactly_n_raw(n.into()),
+        );
+
+        assert_eq!(
+            parser.parse(b"3abcd").map(ParsedItem::into_parts),
+            Ok((b"d".as_ref(), b"abc".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"0abcd").map(ParsedItem::into_parts),
+            Ok((b"abcd".as_ref(), b"".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_and_then_error() {
+        let parser = ascii::digit.and_then(
+            #[cfg_attr(coverage, coverage(off))]
+            |n| any_byte.exactly_n_raw(n.into()),
+        );
+
+        assert_eq!(
+            parser.parse(b"a"),
+            Err(Either::A(Either::A(error::AsciiDigit)))
+        );
+        assert_eq!(parser.parse(b"3ab"), Err(Either::B(error::EndOfInput)));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::Utf8ErrorKind;
+use crate::parser::any_utf8_char;
+use crate::{error, Either, ParsedItem, Parser};
+
+/// Run the parser, converting the bytes it outputs to a string.
+///
+/// Only the output is validated, so this is inexpensive for parsers that consume little input. If
+/// the output is not valid UTF-8, an error is returned. For parsers that are known to consume only
+/// valid UTF-8, [`Utf8::recognize_str`](crate::parser::Utf8::recognize_str) avoids validation.
+pub fn as_str<'input, P>(
+    parser: P,
+) -> impl Parser<'input, Output = &'input str, Error = Either<P::Error, error::InvalidUtf8>>
+where
+    P: Parser<'input, Output = &'input [u8]>,
+{
+    move |input| {
+        let (remaining_input, bytes) = parser.parse(input).map_err(Either::A)?.into_parts();
+        match core::str::from_utf8(bytes) {
+            Ok(output) => Ok(ParsedItem::from_parts(remaining_input, output)),
+            Err(err) => Err(Either::B(invalid_utf8(bytes, err))),
+        }
+    }
+}
+
+/// Determine the reason that the bytes are not valid UTF-8.
+pub(crate) fn invalid_utf8(bytes: &[u8], err: core::str::Utf8Error) -> error::InvalidUtf8 {
+    match any_utf8_char(&bytes[err.valid_up_to()..]) {
+        Err(Either::A(err)) => err,
+        Ok(_) | Err(Either::B(_)) => error::InvalidUtf8 {
+            kind: Utf8ErrorKind::Incomplete,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Utf8ErrorKind;
+    use crate::parser::{ascii, n_bytes};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_as_str_success() {
+        assert_eq!(
+            ascii::alphabetic
+                .discard_at_least_n(1)
+                .recognize()
+                .as_str()
+                .parse(b"abc1")
+                .map(ParsedItem::into_parts),
+            Ok((b"1".as_ref(), "abc"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_as_str_error() {
+        assert_eq!(
+            n_bytes(2).as_str().parse(b"\xC3\x28"),
+            Err(Either::B(error::InvalidUtf8 {
+                kind: Utf8ErrorKind::InvalidContinuationByte
+            }))
+        );
+        assert_eq!(
+            n_bytes(2).as_str().parse(b"a\xE2\x82"),
+            Err(Either::B(error::InvalidUtf8 {
+                kind: Utf8ErrorKind::Incomplete
+            }))
+        );
+        assert_eq!(
+            n_bytes(2).as_str().parse(b"a"),
+            Err(Either::A(error::EndOfInput))
+        );
+    }
+}
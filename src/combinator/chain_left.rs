@@ -0,0 +1,77 @@
+use crate::{Combinator, ParsedItem, Parser};
+
+/// Parse one or more operands separated by operators, combining them from left to right.
+///
+/// `a - b - c` is combined as `f(f(a, -, b), -, c)`. If an operator is not followed by an operand,
+/// the operator is not consumed.
+pub const fn chain_left<'input, P, O, F>(
+    operator: O,
+    f: F,
+) -> impl Combinator<'input, P, Output = P::Output, Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    O: Parser<'input>,
+    F: Fn(P::Output, O::Output, P::Output) -> P::Output + Copy,
+{
+    move |parser: P| {
+        move |input| {
+            let (mut input, mut value) = parser.parse(input)?.into_parts();
+
+            loop {
+                let Ok(parsed_operator) = operator.parse(input) else {
+                    break;
+                };
+                let (remaining_input, op) = parsed_operator.into_parts();
+                let Ok(parsed_operand) = parser.parse(remaining_input) else {
+                    break;
+                };
+                let (remaining_input, rhs) = parsed_operand.into_parts();
+                input = remaining_input;
+                value = f(value, op, rhs);
+            }
+
+            Ok(ParsedItem::from_parts(input, value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ascii, byte};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_chain_left_success() {
+        let parser = ascii::int::<i32>.chain_left(
+            byte(b'-'),
+            #[cfg_attr(coverage, coverage(off))]
+            |a, _, b| a - b,
+        );
+
+        assert_eq!(
+            parser.parse(b"1-2-3").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), -4))
+        );
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+        assert_eq!(
+            parser.parse(b"1-2-").map(ParsedItem::into_parts),
+            Ok((b"-".as_ref(), -1))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_chain_left_error() {
+        let parser = ascii::int::<i32>.chain_left(
+            byte(b'-'),
+            #[cfg_attr(coverage, coverage(off))]
+            |a, _, b| a - b,
+        );
+
+        assert_eq!(parser.parse(b"-"), Err(Either::A(error::AsciiInteger)));
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{Combinator, ParsedItem, Parser};
+
+/// Parse one or more operands separated by operators, combining them from right to left.
+///
+/// `a ^ b ^ c` is combined as `f(a, ^, f(b, ^, c))`. If an operator is not followed by an operand,
+/// the operator is not consumed.
+pub const fn chain_right<'input, P, O, F>(
+    operator: O,
+    f: F,
+) -> impl Combinator<'input, P, Output = P::Output, Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    O: Parser<'input>,
+    F: Fn(P::Output, O::Output, P::Output) -> P::Output + Copy,
+{
+    move |parser: P| {
+        move |input| {
+            let (mut input, first) = parser.parse(input)?.into_parts();
+
+            // Each operand is paired with the operator following it, with the final operand
+            // stored separately. This avoids recursion, which would overflow the stack on long
+            // chains.
+            let mut pairs = Vec::new();
+            let mut last = first;
+            loop {
+                let Ok(parsed_operator) = operator.parse(input) else {
+                    break;
+                };
+                let (remaining_input, op) = parsed_operator.into_parts();
+                let Ok(parsed_operand) = parser.parse(remaining_input) else {
+                    break;
+                };
+                let (remaining_input, rhs) = parsed_operand.into_parts();
+                input = remaining_input;
+                pairs.push((last, op));
+                last = rhs;
+            }
+
+            let value = pairs
+                .into_iter()
+                .rev()
+                .fold(last, |rhs, (lhs, op)| f(lhs, op, rhs));
+            Ok(ParsedItem::from_parts(input, value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ascii, byte};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_chain_right_success() {
+        let parser = ascii::int::<i32>.chain_right(
+            byte(b'-'),
+            #[cfg_attr(coverage, coverage(off))]
+            |a, _, b| a - b,
+        );
+
+        assert_eq!(
+            parser.parse(b"1-2-3").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 2))
+        );
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+        assert_eq!(
+            parser.parse(b"1-2-").map(ParsedItem::into_parts),
+            Ok((b"-".as_ref(), -1))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_chain_right_error() {
+        let parser = ascii::int::<i32>.chain_right(
+            byte(b'-'),
+            #[cfg_attr(coverage, coverage(off))]
+            |a, _, b| a - b,
+        );
+
+        assert_eq!(parser.parse(b"-"), Err(Either::A(error::AsciiInteger)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_chain_right_long_chain() {
+        let mut input = b"1".repeat(400_001);
+        for i in (1..input.len()).step_by(2) {
+            input[i] = b'^';
+        }
+        let parser = ascii::digit.chain_right(
+            byte(b'^'),
+            #[cfg_attr(coverage, coverage(off))]
+            |a, _, b| a.max(b),
+        );
+
+        assert_eq!(
+            parser.parse(&input).map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+    }
+}
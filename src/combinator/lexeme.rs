@@ -0,0 +1,63 @@
+use crate::parser::{trivia, Trivia};
+use crate::{error, Combinator, Either, ParsedItem, Parser};
+
+/// Run the parser, then skip any trivia that follows it.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub const fn lexeme<'input, P>(
+    trivia: Trivia<'_>,
+) -> impl Combinator<
+    'input,
+    P,
+    Output = P::Output,
+    Error = Either<P::Error, error::UnterminatedComment>,
+> + Copy + '_
+where
+    P: Parser<'input>,
+{
+    move |parser: P| {
+        move |input| {
+            let (input, value) = parser.parse(input).map_err(Either::A)?.into_parts();
+            let input = self::trivia(trivia).parse(input).map_err(Either::B)?.input();
+            Ok(ParsedItem::from_parts(input, value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ascii, Trivia};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    const TRIVIA: Trivia<'static> = Trivia::new().whitespace().line_comment(b"#");
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lexeme_success() {
+        let parser = ascii::int::<u8>.lexeme(TRIVIA);
+
+        assert_eq!(
+            parser.parse(b"1 # one\n 2").map(ParsedItem::into_parts),
+            Ok((b"2".as_ref(), 1))
+        );
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lexeme_error() {
+        let parser = ascii::int::<u8>.lexeme(TRIVIA);
+        assert_eq!(
+            parser.parse(b" 1"),
+            Err(Either::A(Either::A(error::AsciiInteger)))
+        );
+        assert_eq!(
+            ascii::int::<u8>
+                .lexeme(Trivia::new().block_comment(b"(", b")"))
+                .parse(b"1(2"),
+            Err(Either::B(error::UnterminatedComment))
+        );
+    }
+}
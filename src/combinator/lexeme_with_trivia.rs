@@ -0,0 +1,62 @@
+use crate::parser::{trivia, Trivia};
+use crate::{error, Combinator, Either, ParsedItem, Parser};
+
+/// Run the parser, then skip any trivia that follows it, outputting the skipped trivia alongside
+/// the value.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub const fn lexeme_with_trivia<'input, P>(
+    trivia: Trivia<'_>,
+) -> impl Combinator<
+    'input,
+    P,
+    Output = (P::Output, &'input [u8]),
+    Error = Either<P::Error, error::UnterminatedComment>,
+> + Copy + '_
+where
+    P: Parser<'input>,
+{
+    move |parser: P| {
+        move |input| {
+            let (input, value) = parser.parse(input).map_err(Either::A)?.into_parts();
+            let (input, trivia) = self::trivia(trivia)
+                .parse(input)
+                .map_err(Either::B)?
+                .into_parts();
+            Ok(ParsedItem::from_parts(input, (value, trivia)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ascii, Trivia};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    const TRIVIA: Trivia<'static> = Trivia::new().whitespace().line_comment(b"#");
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lexeme_with_trivia_success() {
+        let parser = ascii::int::<u8>.lexeme_with_trivia(TRIVIA);
+
+        assert_eq!(
+            parser.parse(b"1 # one\n 2").map(ParsedItem::into_parts),
+            Ok((b"2".as_ref(), (1, b" # one\n ".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (1, b"".as_ref())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lexeme_with_trivia_error() {
+        let parser = ascii::int::<u8>.lexeme_with_trivia(TRIVIA);
+        assert_eq!(
+            parser.parse(b" 1"),
+            Err(Either::A(Either::A(error::AsciiInteger)))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{error, Combinator, Either, ParsedItem, Parser};
+
+/// Consume items from the input until the terminator parser succeeds.
+///
+/// The terminator is attempted before each item. The output is a tuple containing the items parsed
+/// and the output of the terminator. If neither the terminator nor the item parser succeed, both
+/// errors are returned. If the item parser succeeds without consuming input, the terminator can
+/// never be reached, so an error is returned in place of the item parser's error.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub const fn many_till<'input, P, T>(
+    terminator: T,
+) -> impl Combinator<
+    'input,
+    P,
+    Output = (Vec<P::Output>, T::Output),
+    Error = (Either<P::Error, error::NoProgress>, T::Error),
+> + Copy
+where
+    P: Parser<'input>,
+    T: Parser<'input>,
+{
+    move |parser: P| {
+        move |mut input| {
+            let mut output = Vec::new();
+
+            loop {
+                let terminator_err = match terminator.parse(input) {
+                    Ok(parsed_item) => {
+                        let (remaining_input, terminator_value) = parsed_item.into_parts();
+                        return Ok(ParsedItem::from_parts(
+                            remaining_input,
+                            (output, terminator_value),
+                        ));
+                    }
+                    Err(err) => err,
+                };
+
+                match parser.parse(input) {
+                    Ok(parsed_item) if parsed_item.input().len() < input.len() => {
+                        let (remaining_input, value) = parsed_item.into_parts();
+                        input = remaining_input;
+                        output.push(value);
+                    }
+                    Ok(_) => return Err((Either::B(error::NoProgress), terminator_err)),
+                    Err(err) => return Err((Either::A(err), terminator_err)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use crate::parser::{ascii, byte, success, verbatim};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_many_till_success() {
+        let parser = ascii::digit.many_till(byte(b';'));
+
+        assert_eq!(
+            parser.parse(b"123;4").map(ParsedItem::into_parts),
+            Ok((b"4".as_ref(), (vec![1, 2, 3], b';')))
+        );
+        assert_eq!(
+            parser.parse(b";").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (vec![], b';')))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_many_till_terminator_priority() {
+        let parser = ascii::alphabetic.many_till(verbatim(b"end"));
+
+        assert_eq!(
+            parser.parse(b"abend").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (vec!['a', 'b'], b"end".as_ref())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_many_till_error() {
+        let parser = ascii::digit.many_till(byte(b';'));
+
+        assert_eq!(
+            parser.parse(b"12a"),
+            Err((
+                Either::A(Either::A(error::AsciiDigit)),
+                Either::A(error::Byte)
+            ))
+        );
+        assert_eq!(
+            parser.parse(b"12"),
+            Err((
+                Either::A(Either::B(error::EndOfInput)),
+                Either::B(error::EndOfInput)
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_many_till_no_progress() {
+        let parser = success(()).many_till(byte(b';'));
+
+        assert_eq!(
+            parser.parse(b"a"),
+            Err((Either::B(error::NoProgress), Either::A(error::Byte)))
+        );
+        assert_eq!(
+            parser.parse(b";").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (vec![], b';')))
+        );
+    }
+}
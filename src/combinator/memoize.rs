@@ -0,0 +1,241 @@
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::{error, Combinator, Parser, ParserResult};
+
+/// A table of parser results for a single top-level parse, used by [`memoize`] and
+/// [`memoize_left_recursive`].
+///
+/// The table borrows the input of the top-level parse. Results are keyed by a rule identifier and
+/// the offset from the start of that input, so rules with the same output and error types may
+/// share a table as long as each is given a distinct identifier. Input that is not a suffix of the
+/// top-level input is never cached. A new table should be created for each top-level parse; the
+/// results are discarded when the table is dropped.
+#[derive(Debug)]
+pub struct Memo<'input, T, E> {
+    start: &'input [u8],
+    entries: RefCell<BTreeMap<(usize, usize), ParserResult<'input, T, E>>>,
+}
+
+impl<'input, T, E> Memo<'input, T, E> {
+    /// Create an empty table for a parse of the provided input.
+    pub const fn new(input: &'input [u8]) -> Self {
+        Self {
+            start: input,
+            entries: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// The offset of the input from the start of the top-level input, if the input is a suffix of
+    /// it.
+    fn offset(&self, input: &'input [u8]) -> Option<usize> {
+        let offset = self.start.len().checked_sub(input.len())?;
+        (self.start[offset..].as_ptr() == input.as_ptr()).then_some(offset)
+    }
+
+    fn get(&self, rule: usize, input: &'input [u8]) -> Option<ParserResult<'input, T, E>>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        let offset = self.offset(input)?;
+        self.entries.borrow().get(&(rule, offset)).cloned()
+    }
+
+    fn insert(&self, rule: usize, input: &'input [u8], result: ParserResult<'input, T, E>) {
+        if let Some(offset) = self.offset(input) {
+            self.entries.borrow_mut().insert((rule, offset), result);
+        }
+    }
+}
+
+/// Cache the result of the parser at each position in the input.
+///
+/// Results are stored in the table under the provided rule identifier. When the parser is
+/// attempted at a position it has previously been attempted at, the previous result is returned
+/// without running the parser again. This avoids exponential behavior in
+/// grammars that backtrack heavily.
+pub const fn memoize<'input, 'memo, P>(
+    memo: &'memo Memo<'input, P::Output, P::Error>,
+    rule: usize,
+) -> impl Combinator<'input, P, Output = P::Output, Error = P::Error> + Copy + 'memo
+where
+    P: Parser<'input> + 'memo,
+    P::Output: Clone,
+    P::Error: Clone,
+{
+    move |parser: P| {
+        move |input| {
+            if let Some(result) = memo.get(rule, input) {
+                return result;
+            }
+
+            let result = parser.parse(input);
+            memo.insert(rule, input, result.clone());
+            result
+        }
+    }
+}
+
+/// Cache the result of the parser at each position in the input, permitting the parser to be left
+/// recursive.
+///
+/// When the parser is first attempted at a position, a [`error::LeftRecursion`] error is stored as
+/// the result. Any left-recursive attempt at the same position fails with that error, causing the
+/// parser to select a non-recursive alternative. The parser is then run repeatedly, with each
+/// successful result stored, for as long as each result consumes more input than the last.
+pub const fn memoize_left_recursive<'input, 'memo, P>(
+    memo: &'memo Memo<'input, P::Output, P::Error>,
+    rule: usize,
+) -> impl Combinator<'input, P, Output = P::Output, Error = P::Error> + Copy + 'memo
+where
+    P: Parser<'input> + 'memo,
+    P::Output: Clone,
+    P::Error: Clone + From<error::LeftRecursion>,
+{
+    move |parser: P| {
+        move |input| {
+            if let Some(result) = memo.get(rule, input) {
+                return result;
+            }
+
+            memo.insert(rule, input, Err(error::LeftRecursion.into()));
+            let mut result = parser.parse(input);
+
+            if let Ok(parsed_item) = &result {
+                let mut remaining_len = parsed_item.input().len();
+                loop {
+                    memo.insert(rule, input, result.clone());
+                    match parser.parse(input) {
+                        Ok(parsed_item) if parsed_item.input().len() < remaining_len => {
+                            remaining_len = parsed_item.input().len();
+                            result = Ok(parsed_item);
+                        }
+                        Ok(_) | Err(_) => break,
+                    }
+                }
+            }
+
+            memo.insert(rule, input, result.clone());
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::parser::{ascii, byte};
+    use crate::{Either, Error, ParsedItem};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_memoize() {
+        let input = b"1b".as_ref();
+        let calls = Cell::new(0);
+        let memo = Memo::new(input);
+        let digit = ascii::digit
+            .inspect(
+                #[cfg_attr(coverage, coverage(off))]
+                |_| calls.set(calls.get() + 1),
+            )
+            .memoize(&memo, 0);
+        let parser = digit.and(byte(b'a')).or(digit.and(byte(b'b')));
+
+        assert_eq!(
+            parser.parse(input).map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), Either::B((1, b'b'))))
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_memoize_error() {
+        let input = b"a".as_ref();
+        let memo = Memo::new(input);
+        let parser = ascii::digit.memoize(&memo, 0);
+
+        assert_eq!(parser.parse(input), Err(Either::A(error::AsciiDigit)));
+        assert_eq!(parser.parse(input), Err(Either::A(error::AsciiDigit)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_memoize_distinct_rules() {
+        let input = b"12".as_ref();
+        let memo = Memo::new(input);
+        let digit = ascii::digit.memoize(&memo, 0);
+        let doubled = ascii::digit.map(|digit| digit * 2).memoize(&memo, 1);
+
+        assert_eq!(
+            digit.parse(input).map(ParsedItem::into_parts),
+            Ok((b"2".as_ref(), 1))
+        );
+        assert_eq!(
+            doubled.parse(input).map(ParsedItem::into_parts),
+            Ok((b"2".as_ref(), 2))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_memoize_other_input() {
+        let input = b"1".as_ref();
+        let memo = Memo::new(input);
+        let parser = ascii::digit.memoize(&memo, 0);
+
+        assert_eq!(
+            parser.parse(input).map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+        // The input is not a suffix of the input the table was created for, so the result for the
+        // same offset must not be reused.
+        let other = [b'2'];
+        assert_eq!(
+            parser.parse(&other).map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 2))
+        );
+    }
+
+    /// `expr = expr '-' int | int`
+    #[cfg_attr(coverage, coverage(off))]
+    fn expr<'a>(memo: &Memo<'a, i32, Error>, input: &'a [u8]) -> ParserResult<'a, i32, Error> {
+        (move |input| expr(memo, input))
+            .and(byte(b'-'))
+            .and(ascii::int::<i32>)
+            .map(|((lhs, _), rhs)| lhs - rhs)
+            .or(ascii::int::<i32>)
+            .map(Either::unify)
+            .map_err(|(_, err)| err.into())
+            .with(&memoize_left_recursive(memo, 0))
+            .parse(input)
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn parse_expr(input: &[u8]) -> ParserResult<'_, i32, Error> {
+        expr(&Memo::new(input), input)
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_memoize_left_recursive() {
+        assert_eq!(
+            parse_expr(b"1-2-3x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), -4))
+        );
+        assert_eq!(
+            parse_expr(b"7").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 7))
+        );
+        assert_eq!(
+            parse_expr(b"-"),
+            Err(Error::AsciiInteger(error::AsciiInteger))
+        );
+    }
+}
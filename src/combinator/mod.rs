@@ -1,11 +1,17 @@
+mod all_consuming;
 mod and;
 mod and_infallible;
+mod and_then;
+mod as_str;
 #[cfg(feature = "alloc")]
 mod at_least_n;
 mod at_least_n_raw;
 #[cfg(feature = "alloc")]
 mod at_most_n;
 mod at_most_n_raw;
+mod chain_left;
+#[cfg(feature = "alloc")]
+mod chain_right;
 #[cfg(feature = "alloc")]
 mod consume_while;
 mod discard;
@@ -18,23 +24,44 @@ mod filter;
 mod filter_map;
 mod inspect;
 mod inspect_err;
+mod lexeme;
+mod lexeme_with_trivia;
+#[cfg(feature = "alloc")]
+mod many_till;
 mod map;
 mod map_err;
 #[cfg(feature = "alloc")]
+mod memoize;
+#[cfg(feature = "alloc")]
 mod n_to_m;
 mod n_to_m_raw;
 mod optional;
 mod or;
+mod recognize;
+#[cfg(feature = "alloc")]
+mod repeat;
+mod repeat_count;
+mod repeat_fold;
+mod repeat_raw;
 mod trait_def;
+mod with_consumed;
 
+pub use self::all_consuming::all_consuming;
 pub use self::and::and;
 pub use self::and_infallible::and_infallible;
+pub use self::and_then::and_then;
+pub use self::as_str::as_str;
+#[cfg(feature = "alloc")]
+pub(crate) use self::as_str::invalid_utf8;
 #[cfg(feature = "alloc")]
 pub use self::at_least_n::at_least_n;
 pub use self::at_least_n_raw::at_least_n_raw;
 #[cfg(feature = "alloc")]
 pub use self::at_most_n::at_most_n;
 pub use self::at_most_n_raw::at_most_n_raw;
+pub use self::chain_left::chain_left;
+#[cfg(feature = "alloc")]
+pub use self::chain_right::chain_right;
 #[cfg(feature = "alloc")]
 pub use self::consume_while::consume_while;
 pub use self::discard::discard;
@@ -47,11 +74,24 @@ pub use self::filter::filter;
 pub use self::filter_map::filter_map;
 pub use self::inspect::inspect;
 pub use self::inspect_err::inspect_err;
+pub use self::lexeme::lexeme;
+pub use self::lexeme_with_trivia::lexeme_with_trivia;
+#[cfg(feature = "alloc")]
+pub use self::many_till::many_till;
 pub use self::map::map;
 pub use self::map_err::map_err;
 #[cfg(feature = "alloc")]
+pub use self::memoize::{memoize, memoize_left_recursive, Memo};
+#[cfg(feature = "alloc")]
 pub use self::n_to_m::n_to_m;
 pub use self::n_to_m_raw::n_to_m_raw;
 pub use self::optional::optional;
 pub use self::or::or;
+pub use self::recognize::recognize;
+#[cfg(feature = "alloc")]
+pub use self::repeat::repeat;
+pub use self::repeat_count::repeat_count;
+pub use self::repeat_fold::{repeat_fold, RepeatCount};
+pub use self::repeat_raw::repeat_raw;
 pub use self::trait_def::Combinator;
+pub use self::with_consumed::with_consumed;
@@ -0,0 +1,48 @@
+use crate::{ParsedItem, Parser};
+
+/// Run the parser, outputting the slice of input it consumed in place of its value.
+pub fn recognize<'input, P>(
+    parser: P,
+) -> impl Parser<'input, Output = &'input [u8], Error = P::Error>
+where
+    P: Parser<'input>,
+{
+    move |input: &'input [u8]| {
+        let remaining_input = parser.parse(input)?.input();
+        let consumed = &input[..(input.len() - remaining_input.len())];
+        Ok(ParsedItem::from_parts(remaining_input, consumed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{any_byte, ascii};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_recognize_success() {
+        let parser = ascii::digit.at_least_n_raw(1).and(any_byte).recognize();
+
+        assert_eq!(
+            parser.parse(b"12a").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"12a".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"1ab").map(ParsedItem::into_parts),
+            Ok((b"b".as_ref(), b"1a".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_recognize_error() {
+        let parser = ascii::digit.and(any_byte).recognize();
+
+        assert_eq!(
+            parser.parse(b"a"),
+            Err(Either::A(Either::A(error::AsciiDigit)))
+        );
+        assert_eq!(parser.parse(b"1"), Err(Either::B(error::EndOfInput)));
+    }
+}
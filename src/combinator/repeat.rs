@@ -0,0 +1,76 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::combinator::repeat_fold::{parse_repeat, RepeatCount};
+use crate::{Combinator, Parser};
+
+/// Run the parser repeatedly, collecting each output.
+///
+/// Repetition behaves as in [`repeat_fold`](crate::combinator::repeat_fold).
+pub fn repeat<'input, P, R>(
+    count: R,
+) -> impl Combinator<'input, P, Output = Vec<P::Output>, Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    R: RepeatCount,
+{
+    let bounds = count.bounds();
+    move |parser: P| {
+        move |input| {
+            parse_repeat(parser, bounds, input, Vec::new(), |mut output, value| {
+                output.push(value);
+                output
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use crate::parser::{ascii, success};
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_success() {
+        let parser = ascii::digit.repeat(2..);
+
+        assert_eq!(
+            parser.parse(b"12").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), vec![1, 2]))
+        );
+        assert_eq!(
+            parser.parse(b"123a").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), vec![1, 2, 3]))
+        );
+        assert_eq!(
+            ascii::digit
+                .repeat(3)
+                .parse(b"1234")
+                .map(ParsedItem::into_parts),
+            Ok((b"4".as_ref(), vec![1, 2, 3]))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_no_progress() {
+        assert_eq!(
+            success(())
+                .repeat(1..)
+                .parse(b"a")
+                .map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), vec![()]))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_error() {
+        let parser = ascii::digit.repeat(2..);
+        assert_eq!(parser.parse(b"1"), Err(Either::B(error::EndOfInput)));
+    }
+}
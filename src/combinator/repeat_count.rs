@@ -0,0 +1,44 @@
+use crate::combinator::repeat_fold::{parse_repeat, RepeatCount};
+use crate::{Combinator, Parser};
+
+/// Run the parser repeatedly, discarding its output and outputting the number of repetitions.
+///
+/// Repetition behaves as in [`repeat_fold`](crate::combinator::repeat_fold).
+pub fn repeat_count<'input, P, R>(
+    count: R,
+) -> impl Combinator<'input, P, Output = usize, Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    R: RepeatCount,
+{
+    let bounds = count.bounds();
+    move |parser: P| move |input| parse_repeat(parser, bounds, input, 0, |count, _| count + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ascii;
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_count_success() {
+        let parser = ascii::alphabetic.repeat_count(..=2);
+
+        assert_eq!(
+            parser.parse(b"").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 0))
+        );
+        assert_eq!(
+            parser.parse(b"abc").map(ParsedItem::into_parts),
+            Ok((b"c".as_ref(), 2))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_count_error() {
+        let parser = ascii::alphabetic.repeat_count(3);
+        assert_eq!(parser.parse(b"ab"), Err(Either::B(error::EndOfInput)));
+    }
+}
@@ -0,0 +1,253 @@
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::{Combinator, ParsedItem, Parser, ParserResult};
+
+/// Run the parser repeatedly, combining each output into an accumulator.
+///
+/// The number of repetitions may be a `usize` or any range of `usize`. If the parser fails before
+/// the minimum number of repetitions is reached, its error is returned. Repetition stops once the
+/// maximum is reached, the parser fails, or the parser succeeds without consuming input.
+///
+/// # Panics
+///
+/// Panics if the range of repetitions is empty.
+pub fn repeat_fold<'input, P, R, T, I, F>(
+    count: R,
+    init: I,
+    f: F,
+) -> impl Combinator<'input, P, Output = T, Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    R: RepeatCount,
+    I: Fn() -> T + Copy,
+    F: Fn(T, P::Output) -> T + Copy,
+{
+    let bounds = count.bounds();
+    move |parser: P| move |input| parse_repeat(parser, bounds, input, init(), f)
+}
+
+/// Run the parser repeatedly according to the bounds, folding each output into `acc`.
+pub(super) fn parse_repeat<'input, P, T, F>(
+    parser: P,
+    (min, max): (usize, Option<usize>),
+    mut input: &'input [u8],
+    mut acc: T,
+    mut f: F,
+) -> ParserResult<'input, T, P::Error>
+where
+    P: Parser<'input>,
+    F: FnMut(T, P::Output) -> T,
+{
+    for _ in 0..min {
+        let (remaining_input, value) = parser.parse(input)?.into_parts();
+        input = remaining_input;
+        acc = f(acc, value);
+    }
+
+    let mut count = min;
+    while max.map_or(true, |max| count < max) {
+        match parser.parse(input) {
+            Ok(parsed_item) if parsed_item.input().len() < input.len() => {
+                let (remaining_input, value) = parsed_item.into_parts();
+                input = remaining_input;
+                acc = f(acc, value);
+                count += 1;
+            }
+            Ok(_) | Err(_) => break,
+        }
+    }
+
+    Ok(ParsedItem::from_parts(input, acc))
+}
+
+mod sealed {
+    pub trait Sealed: Sized {
+        /// The minimum and (inclusive) maximum number of repetitions, or `None` if no number of
+        /// repetitions is permitted.
+        fn try_bounds(self) -> Option<(usize, Option<usize>)>;
+
+        /// The minimum and (inclusive) maximum number of repetitions.
+        fn bounds(self) -> (usize, Option<usize>) {
+            match self.try_bounds() {
+                Some(bounds) => bounds,
+                None => panic!("repetition range must not be empty"),
+            }
+        }
+    }
+}
+
+use self::sealed::Sealed;
+
+/// A number of repetitions, either exact or as a non-empty range.
+pub trait RepeatCount: Sealed {}
+
+/// The bounds, provided the maximum is not less than the minimum.
+const fn non_empty(min: usize, max: Option<usize>) -> Option<(usize, Option<usize>)> {
+    match max {
+        Some(max) if max < min => None,
+        _ => Some((min, max)),
+    }
+}
+
+impl Sealed for usize {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        Some((self, Some(self)))
+    }
+}
+
+impl Sealed for Range<usize> {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        non_empty(self.start, Some(self.end.checked_sub(1)?))
+    }
+}
+
+impl Sealed for RangeInclusive<usize> {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        non_empty(*self.start(), Some(*self.end()))
+    }
+}
+
+impl Sealed for RangeFrom<usize> {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        Some((self.start, None))
+    }
+}
+
+impl Sealed for RangeTo<usize> {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        Some((0, Some(self.end.checked_sub(1)?)))
+    }
+}
+
+impl Sealed for RangeToInclusive<usize> {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        Some((0, Some(self.end)))
+    }
+}
+
+impl Sealed for RangeFull {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        Some((0, None))
+    }
+}
+
+impl Sealed for (Bound<usize>, Bound<usize>) {
+    fn try_bounds(self) -> Option<(usize, Option<usize>)> {
+        let min = match self.0 {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let max = match self.1 {
+            Bound::Included(end) => Some(end),
+            Bound::Excluded(end) => Some(end.checked_sub(1)?),
+            Bound::Unbounded => None,
+        };
+        non_empty(min, max)
+    }
+}
+
+impl RepeatCount for usize {}
+impl RepeatCount for Range<usize> {}
+impl RepeatCount for RangeInclusive<usize> {}
+impl RepeatCount for RangeFrom<usize> {}
+impl RepeatCount for RangeTo<usize> {}
+impl RepeatCount for RangeToInclusive<usize> {}
+impl RepeatCount for RangeFull {}
+impl RepeatCount for (Bound<usize>, Bound<usize>) {}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    use crate::parser::ascii;
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_fold_success() {
+        let parser = ascii::digit.repeat_fold(
+            1..=3,
+            #[cfg_attr(coverage, coverage(off))]
+            || 0_u32,
+            #[cfg_attr(coverage, coverage(off))]
+            |acc, digit| acc * 10 + u32::from(digit),
+        );
+
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+        assert_eq!(
+            parser.parse(b"1234").map(ParsedItem::into_parts),
+            Ok((b"4".as_ref(), 123))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_fold_error() {
+        let parser = ascii::digit.repeat_fold(
+            2..,
+            #[cfg_attr(coverage, coverage(off))]
+            || 0_u32,
+            #[cfg_attr(coverage, coverage(off))]
+            |acc, digit| acc * 10 + u32::from(digit),
+        );
+
+        assert_eq!(parser.parse(b"1a"), Err(Either::A(error::AsciiDigit)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_bounds() {
+        use super::Sealed as _;
+
+        assert_eq!(3.bounds(), (3, Some(3)));
+        assert_eq!((1..4).bounds(), (1, Some(3)));
+        assert_eq!((1..=4).bounds(), (1, Some(4)));
+        assert_eq!((2..).bounds(), (2, None));
+        assert_eq!((..5).bounds(), (0, Some(4)));
+        assert_eq!((..=5).bounds(), (0, Some(5)));
+        assert_eq!((..).bounds(), (0, None));
+        assert_eq!((Bound::Excluded(1), Bound::Unbounded).bounds(), (2, None));
+        assert_eq!((2..3).bounds(), (2, Some(2)));
+        assert_eq!((2..=2).bounds(), (2, Some(2)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_bounds_empty() {
+        use super::Sealed as _;
+
+        assert_eq!((2..2).try_bounds(), None);
+        assert_eq!((0..0).try_bounds(), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        {
+            assert_eq!((3..1).try_bounds(), None);
+            assert_eq!((3..=1).try_bounds(), None);
+        }
+        assert_eq!((..0).try_bounds(), None);
+        assert_eq!(
+            (Bound::Excluded(usize::MAX), Bound::Unbounded).try_bounds(),
+            None
+        );
+        assert_eq!((Bound::Unbounded, Bound::Excluded(0)).try_bounds(), None);
+        assert_eq!((Bound::Included(2), Bound::Excluded(2)).try_bounds(), None);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "repetition range must not be empty"]
+    fn test_repeat_fold_empty_range() {
+        let _ = ascii::digit.repeat_count(2..2);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "repetition range must not be empty"]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_repeat_fold_reversed_range() {
+        let _ = ascii::digit.repeat_count(3..1);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::combinator::repeat_fold::{parse_repeat, RepeatCount};
+use crate::{Combinator, ParsedItem, Parser};
+
+/// Run the parser repeatedly, outputting the slice of input consumed.
+///
+/// Repetition behaves as in [`repeat_fold`](crate::combinator::repeat_fold).
+pub fn repeat_raw<'input, P, R>(
+    count: R,
+) -> impl Combinator<'input, P, Output = &'input [u8], Error = P::Error> + Copy
+where
+    P: Parser<'input>,
+    R: RepeatCount,
+{
+    let bounds = count.bounds();
+    move |parser: P| {
+        move |input: &'input [u8]| {
+            let remaining_input = parse_repeat(parser, bounds, input, (), |(), _| ())?.input();
+            let output = &input[..(input.len() - remaining_input.len())];
+            Ok(ParsedItem::from_parts(remaining_input, output))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ascii;
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_raw_success() {
+        let parser = ascii::alphabetic.repeat_raw(1..3);
+
+        assert_eq!(
+            parser.parse(b"a1").map(ParsedItem::into_parts),
+            Ok((b"1".as_ref(), b"a".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"abc").map(ParsedItem::into_parts),
+            Ok((b"c".as_ref(), b"ab".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_repeat_raw_error() {
+        let parser = ascii::alphabetic.repeat_raw(1..3);
+        assert_eq!(parser.parse(b"1"), Err(Either::A(error::AsciiAlphabetic)));
+    }
+}
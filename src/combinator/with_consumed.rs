@@ -0,0 +1,41 @@
+use crate::{ParsedItem, Parser};
+
+/// Run the parser, outputting the slice of input it consumed alongside its value.
+pub fn with_consumed<'input, P>(
+    parser: P,
+) -> impl Parser<'input, Output = (&'input [u8], P::Output), Error = P::Error>
+where
+    P: Parser<'input>,
+{
+    move |input: &'input [u8]| {
+        let (remaining_input, value) = parser.parse(input)?.into_parts();
+        let consumed = &input[..(input.len() - remaining_input.len())];
+        Ok(ParsedItem::from_parts(remaining_input, (consumed, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ascii;
+    use crate::{error, Either, ParsedItem, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_with_consumed_success() {
+        let parser = ascii::int::<u8>.with_consumed();
+
+        assert_eq!(
+            parser.parse(b"0042,").map(ParsedItem::into_parts),
+            Ok((b",".as_ref(), (b"0042".as_ref(), 42)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_with_consumed_error() {
+        let parser = ascii::int::<u8>.with_consumed();
+
+        assert_eq!(parser.parse(b"a"), Err(Either::A(error::AsciiInteger)));
+        assert_eq!(parser.parse(b""), Err(Either::B(error::EndOfInput)));
+    }
+}
@@ -54,6 +54,36 @@ impl<A, B> Either<A, B> {
     }
 }
 
+macro_rules! declare_either {
+    ($($name:ident<$($variant:ident),+> => $($unified:ident),+;)*) => {$(
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $name<$($variant),+> {
+            $($variant($variant)),+
+        }
+
+        impl<T> $name<$($unified),+> {
+            pub fn unify(self) -> T {
+                match self {
+                    $(Self::$variant(value))|+ => value,
+                }
+            }
+        }
+    )*};
+}
+
+declare_either! {
+    Either3<A, B, C> => T, T, T;
+    Either4<A, B, C, D> => T, T, T, T;
+    Either5<A, B, C, D, E> => T, T, T, T, T;
+    Either6<A, B, C, D, E, F> => T, T, T, T, T, T;
+    Either7<A, B, C, D, E, F, G> => T, T, T, T, T, T, T;
+    Either8<A, B, C, D, E, F, G, H> => T, T, T, T, T, T, T, T;
+    Either9<A, B, C, D, E, F, G, H, I> => T, T, T, T, T, T, T, T, T;
+    Either10<A, B, C, D, E, F, G, H, I, J> => T, T, T, T, T, T, T, T, T, T;
+    Either11<A, B, C, D, E, F, G, H, I, J, K> => T, T, T, T, T, T, T, T, T, T, T;
+    Either12<A, B, C, D, E, F, G, H, I, J, K, L> => T, T, T, T, T, T, T, T, T, T, T, T;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +130,15 @@ mod tests {
         assert_eq!(Either::<u8, u8>::A(0).map_b(f), Either::A(0));
         assert_eq!(Either::<u8, u8>::B(1).map_b(f), Either::B(3));
     }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn unify_n() {
+        assert_eq!(Either3::<u8, u8, u8>::A(0).unify(), 0);
+        assert_eq!(Either3::<u8, u8, u8>::C(2).unify(), 2);
+        assert_eq!(
+            Either12::<u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8>::L(11).unify(),
+            11
+        );
+    }
 }
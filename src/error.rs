@@ -1,6 +1,9 @@
 use core::convert::Infallible;
 
-use crate::Either;
+use crate::{
+    Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6, Either7, Either8,
+    Either9,
+};
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,6 +20,22 @@ pub enum Error {
     NotEndOfInput(NotEndOfInput),
     NonMatchingInput(NonMatchingInput),
     Utf8Char(Utf8Char),
+    NoProgress(NoProgress),
+    PermutationMissing(PermutationMissing),
+    PermutationDuplicate(PermutationDuplicate),
+    MissingOperand(MissingOperand),
+    NonAssociativeOperator(NonAssociativeOperator),
+    ExpressionTooDeep(ExpressionTooDeep),
+    LeftRecursion(LeftRecursion),
+    TrailingInput(TrailingInput),
+    MixedIndentation(MixedIndentation),
+    InsufficientIndentation(InsufficientIndentation),
+    InconsistentIndentation(InconsistentIndentation),
+    UnterminatedComment(UnterminatedComment),
+    AmbiguousMatch(AmbiguousMatch),
+    UnrecognizedToken(UnrecognizedToken),
+    InvalidUtf8(InvalidUtf8),
+    InvalidLength(InvalidLength),
 }
 
 impl From<Infallible> for Error {
@@ -38,7 +57,35 @@ where
     }
 }
 
-#[allow(unused_macro_rules)] // will be used in the future
+macro_rules! impl_from_either_n {
+    ($($name:ident<$($variant:ident),+>)*) => {$(
+        impl<$($variant),+> From<$name<$($variant),+>> for Error
+        where
+            $($variant: Into<Self>),+
+        {
+            fn from(e: $name<$($variant),+>) -> Self {
+                match e {
+                    $($name::$variant(e) => e.into()),+
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_either_n! {
+    Either3<A, B, C>
+    Either4<A, B, C, D>
+    Either5<A, B, C, D, E>
+    Either6<A, B, C, D, E, F>
+    Either7<A, B, C, D, E, F, G>
+    Either8<A, B, C, D, E, F, G, H>
+    Either9<A, B, C, D, E, F, G, H, I>
+    Either10<A, B, C, D, E, F, G, H, I, J>
+    Either11<A, B, C, D, E, F, G, H, I, J, K>
+    Either12<A, B, C, D, E, F, G, H, I, J, K, L>
+}
+
+#[cfg_attr(not(test), allow(unused_macro_rules))] // `@value` is only used in tests
 macro_rules! declare_parcom_error {
     (@single
         $(#[$struct_attr:meta])*
@@ -64,6 +111,17 @@ macro_rules! declare_parcom_error {
             $field_vis $field_name: $field_ty
         ),*}
     };
+    (@value $name:ident ;) => {
+        $name
+    };
+    (@value $name:ident {$(
+        $(#[$field_attr:meta])*
+        $field_vis:vis $field_name:ident : $field_ty:ty
+    ),* $(,)?}) => {
+        $name {$(
+            $field_name: TestValue::test_value()
+        ),*}
+    };
     ($(
         $(#[$struct_attr:meta])*
         $vis:vis struct $name:ident $fields:tt
@@ -84,20 +142,63 @@ macro_rules! declare_parcom_error {
         mod tests {
             use super::*;
 
+            /// A value for each type of field, used to construct errors.
+            trait TestValue {
+                fn test_value() -> Self;
+            }
+
+            impl TestValue for usize {
+                #[cfg_attr(coverage, coverage(off))]
+                fn test_value() -> Self {
+                    0
+                }
+            }
+
+            impl TestValue for Utf8ErrorKind {
+                #[cfg_attr(coverage, coverage(off))]
+                fn test_value() -> Self {
+                    Self::InvalidStartByte
+                }
+            }
+
             #[test]
             #[cfg_attr(coverage, coverage(off))]
-            fn test_into_error_variants() {
-                $(assert_eq!(Error::from($name), Error::$name($name));)*
-            }
+            fn test_into_error_variants() {$(
+                let value = declare_parcom_error!(@value $name $fields);
+                assert_eq!(Error::from(value.clone()), Error::$name(value));
+            )*}
 
             #[test]
             #[cfg_attr(coverage, coverage(off))]
-            fn test_into_error_either() {
-                $(assert_eq!(Error::from(Either::<_, Filter>::A($name)), Error::$name($name));)*
-                $(assert_eq!(Error::from(Either::<$name, _>::B(Filter)), Error::Filter(Filter));)*
-                $(assert_eq!(Error::from(Either::<Infallible, _>::B($name)), Error::$name($name));)*
-                $(assert_eq!(Error::from(Either::<_, $name>::A(Filter)), Error::Filter(Filter));)*
-            }
+            fn test_into_error_either() {$(
+                let value = declare_parcom_error!(@value $name $fields);
+                assert_eq!(
+                    Error::from(Either::<_, Filter>::A(value.clone())),
+                    Error::$name(value.clone())
+                );
+                assert_eq!(
+                    Error::from(Either::<$name, _>::B(Filter)),
+                    Error::Filter(Filter)
+                );
+                assert_eq!(
+                    Error::from(Either::<Infallible, _>::B(value.clone())),
+                    Error::$name(value)
+                );
+                assert_eq!(
+                    Error::from(Either::<_, $name>::A(Filter)),
+                    Error::Filter(Filter)
+                );
+            )*}
+
+            #[test]
+            #[cfg_attr(coverage, coverage(off))]
+            fn test_into_error_either_n() {$(
+                let value = declare_parcom_error!(@value $name $fields);
+                assert_eq!(
+                    Error::from(Either3::<Filter, Infallible, _>::C(value.clone())),
+                    Error::$name(value)
+                );
+            )*}
         }
     };
 }
@@ -116,4 +217,90 @@ declare_parcom_error! {
     pub struct NonMatchingInput;
     pub struct Utf8Char;
     pub struct AsciiInteger;
+    /// A parser that was run repeatedly succeeded without consuming input.
+    pub struct NoProgress;
+    /// A required member of a permutation was not present.
+    pub struct PermutationMissing {
+        /// The index of the missing member.
+        pub index: usize,
+    }
+    /// A member of a permutation was present more than once.
+    pub struct PermutationDuplicate {
+        /// The index of the duplicated member.
+        pub index: usize,
+    }
+    /// An operator was not followed by an operand.
+    pub struct MissingOperand {
+        /// The offset of the operator from the start of the expression.
+        pub offset: usize,
+    }
+    /// A non-associative operator was chained with another of the same binding power.
+    pub struct NonAssociativeOperator {
+        /// The offset of the second operator from the start of the expression.
+        pub offset: usize,
+    }
+    /// Operators were nested more deeply than permitted.
+    pub struct ExpressionTooDeep {
+        /// The offset of the operator that exceeded the limit from the start of the expression.
+        pub offset: usize,
+    }
+    /// A left-recursive rule was attempted at a position where it is already being parsed.
+    pub struct LeftRecursion;
+    /// Input remained after parsing was complete.
+    pub struct TrailingInput {
+        /// The offset of the first byte that was not consumed.
+        pub offset: usize,
+    }
+    /// Indentation contained both spaces and tabs, either within a line or between a block and its
+    /// parent.
+    pub struct MixedIndentation {
+        /// The offset of the first indentation byte that differs from the preceding indentation.
+        pub offset: usize,
+    }
+    /// A block was not indented further than its parent.
+    pub struct InsufficientIndentation;
+    /// A line was indented further than the block containing it.
+    pub struct InconsistentIndentation;
+    /// A block comment was not terminated before the end of input.
+    pub struct UnterminatedComment;
+    /// Multiple alternatives matched the longest input.
+    pub struct AmbiguousMatch {
+        /// The index of the first alternative that matched.
+        pub first: usize,
+        /// The index of the second alternative that matched the same input.
+        pub second: usize,
+    }
+    /// No lexer rule matched the input.
+    pub struct UnrecognizedToken {
+        /// The offset of the input that was not recognized.
+        pub offset: usize,
+    }
+    /// The input was not a valid UTF-8 encoding of a character.
+    pub struct InvalidUtf8 {
+        /// The reason the input was invalid.
+        pub kind: Utf8ErrorKind,
+    }
+    /// A length or count was negative or could not be represented as a `usize`.
+    pub struct InvalidLength {
+        /// The offset of the first byte following the length.
+        pub offset: usize,
+    }
+}
+
+/// The reason that input was not valid UTF-8.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf8ErrorKind {
+    /// The first byte cannot begin a character.
+    InvalidStartByte,
+    /// A byte following the first byte was not a continuation byte.
+    InvalidContinuationByte,
+    /// The character was encoded using more bytes than necessary.
+    Overlong,
+    /// The encoded value is a surrogate, which is not a character.
+    Surrogate,
+    /// The encoded value is greater than `U+10FFFF`.
+    OutOfRange,
+    /// The input ended partway through a character.
+    Incomplete,
 }
@@ -0,0 +1,528 @@
+//! Tokenization of input prior to parsing.
+//!
+//! A lexer is described by a set of rules, each of which is a parser that outputs `Some(kind)`
+//! for a token or `None` for trivia that should be skipped. [`rules`] tries every rule and uses
+//! the one that consumes the most input. When multiple rules consume the same amount of input, the
+//! earliest one is used, so keywords should be listed before identifiers.
+//!
+//! Parsers operate on bytes, so a [`TokenStream`] exposes the kind of each token as the UTF-8
+//! encoding of a character. Any parser can then be run on [`TokenStream::kinds`], with the input
+//! remaining before and after mapped back to the tokens that were consumed.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::parser::longest;
+use crate::{
+    error, hrtb_hack, Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6,
+    Either7, Either8, Either9, ParsedItem, Parser, ParserResult,
+};
+
+/// The range of input, in bytes, that a token was produced from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The offset of the first byte of the token.
+    pub start: usize,
+    /// The offset immediately following the last byte of the token.
+    pub end: usize,
+}
+
+/// A token produced by a lexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token<'input, K> {
+    /// The kind of token.
+    pub kind: K,
+    /// The range of input the token was produced from.
+    pub span: Span,
+    /// The input the token was produced from.
+    pub text: &'input [u8],
+}
+
+/// A rule that produces a token of the given kind when the parser succeeds.
+pub fn token<'input, P, K>(
+    parser: P,
+    kind: K,
+) -> impl Parser<'input, Output = Option<K>, Error = P::Error>
+where
+    P: Parser<'input>,
+    K: Copy,
+{
+    parser.map(move |_| Some(kind))
+}
+
+/// A rule that skips the input consumed by the parser without producing a token.
+pub fn skip<'input, P, K>(parser: P) -> impl Parser<'input, Output = Option<K>, Error = P::Error>
+where
+    P: Parser<'input>,
+{
+    parser.map(|_| None)
+}
+
+/// Combine rules, using the one that consumes the most input.
+///
+/// Rules may be provided as an array or a tuple. When multiple rules consume the same amount of
+/// input, the earliest one is used.
+pub fn rules<'input, T, K>(rules: T) -> impl Parser<'input, Output = Option<K>, Error = T::Error>
+where
+    T: Rules<'input, K>,
+{
+    move |input| rules.parse_rules(input)
+}
+
+mod sealed {
+    use crate::ParserResult;
+
+    pub trait Sealed<'input, K>: Copy {
+        type Error;
+
+        fn parse_rules(self, input: &'input [u8]) -> ParserResult<'input, Option<K>, Self::Error>;
+    }
+}
+
+use self::sealed::Sealed;
+
+pub trait Rules<'input, K>: Sealed<'input, K> {}
+
+impl<'input, const N: usize, P, K> Sealed<'input, K> for [P; N]
+where
+    P: Parser<'input, Output = Option<K>>,
+{
+    type Error = P::Error;
+
+    fn parse_rules(self, input: &'input [u8]) -> ParserResult<'input, Option<K>, Self::Error> {
+        longest(self).parse(input)
+    }
+}
+
+impl<'input, const N: usize, P, K> Rules<'input, K> for [P; N] where
+    P: Parser<'input, Output = Option<K>>
+{
+}
+
+macro_rules! impl_rules {
+    ($either:ident; $($parser:ident),+) => {
+        impl<'input, K, $($parser),+> Sealed<'input, K> for ($($parser,)+)
+        where
+            $($parser: Parser<'input, Output = Option<K>>),+
+        {
+            type Error = ($($parser::Error,)+);
+
+            fn parse_rules(
+                self,
+                input: &'input [u8],
+            ) -> ParserResult<'input, Option<K>, Self::Error> {
+                longest(self).map($either::unify).parse(input)
+            }
+        }
+
+        impl<'input, K, $($parser),+> Rules<'input, K> for ($($parser,)+)
+        where
+            $($parser: Parser<'input, Output = Option<K>>),+
+        {}
+    };
+}
+
+impl_rules!(Either; P0, P1);
+impl_rules!(Either3; P0, P1, P2);
+impl_rules!(Either4; P0, P1, P2, P3);
+impl_rules!(Either5; P0, P1, P2, P3, P4);
+impl_rules!(Either6; P0, P1, P2, P3, P4, P5);
+impl_rules!(Either7; P0, P1, P2, P3, P4, P5, P6);
+impl_rules!(Either8; P0, P1, P2, P3, P4, P5, P6, P7);
+impl_rules!(Either9; P0, P1, P2, P3, P4, P5, P6, P7, P8);
+impl_rules!(Either10; P0, P1, P2, P3, P4, P5, P6, P7, P8, P9);
+impl_rules!(Either11; P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10);
+impl_rules!(Either12; P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11);
+
+/// Lazily tokenize the input using the rule.
+///
+/// Input skipped by the rule does not produce a token. If the rule fails or succeeds without
+/// consuming input, an error is yielded and iteration ends.
+pub const fn tokens<'input, R, K>(rule: R, input: &'input [u8]) -> Tokens<'input, R>
+where
+    R: Parser<'input, Output = Option<K>>,
+{
+    Tokens {
+        rule,
+        input,
+        offset: 0,
+        is_finished: false,
+    }
+}
+
+/// An iterator over the tokens in the input.
+///
+/// This is created by [`tokens`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'input, R> {
+    rule: R,
+    input: &'input [u8],
+    offset: usize,
+    is_finished: bool,
+}
+
+impl<'input, R> Tokens<'input, R> {
+    /// The input that has not yet been tokenized.
+    pub const fn input(&self) -> &'input [u8] {
+        self.input
+    }
+
+    /// The offset of the input that has not yet been tokenized.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'input, R, K> Iterator for Tokens<'input, R>
+where
+    R: Parser<'input, Output = Option<K>>,
+{
+    type Item = Result<Token<'input, K>, error::UnrecognizedToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_finished && !self.input.is_empty() {
+            let (remaining_input, kind) = match self.rule.parse(self.input) {
+                Ok(parsed_item) if parsed_item.input().len() < self.input.len() => {
+                    parsed_item.into_parts()
+                }
+                Ok(_) | Err(_) => {
+                    self.is_finished = true;
+                    return Some(Err(error::UnrecognizedToken {
+                        offset: self.offset,
+                    }));
+                }
+            };
+
+            let len = self.input.len() - remaining_input.len();
+            let text = &self.input[..len];
+            let span = Span {
+                start: self.offset,
+                end: self.offset + len,
+            };
+            self.input = remaining_input;
+            self.offset = span.end;
+
+            if let Some(kind) = kind {
+                return Some(Ok(Token { kind, span, text }));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'input, K, R: Parser<'input, Output = Option<K>>> FusedIterator for Tokens<'input, R> {}
+
+/// Tokenize the entire input using the rule.
+///
+/// Each token kind is encoded as a character, allowing parsers to be run on the sequence of kinds.
+#[cfg(feature = "alloc")]
+pub fn lex<'input, R, K>(
+    rule: R,
+    input: &'input [u8],
+) -> Result<TokenStream<'input, K>, error::UnrecognizedToken>
+where
+    R: Parser<'input, Output = Option<K>>,
+    K: Copy + Into<u16>,
+{
+    let tokens = tokens(rule, input).collect::<Result<Vec<_>, _>>()?;
+    let mut kinds = Vec::with_capacity(tokens.len());
+    let mut starts = Vec::with_capacity(tokens.len());
+    for token in &tokens {
+        starts.push(kinds.len());
+        kinds.extend_from_slice(
+            kind_char(token.kind.into())
+                .encode_utf8(&mut [0; 4])
+                .as_bytes(),
+        );
+    }
+    Ok(TokenStream {
+        input,
+        tokens,
+        kinds,
+        starts,
+    })
+}
+
+/// The character representing a token kind in [`TokenStream::kinds`]. Kinds that would correspond
+/// to a surrogate are shifted past the surrogate range, so every kind has a distinct character.
+fn kind_char(kind: u16) -> char {
+    let value = u32::from(kind);
+    let value = if value < 0xD800 { value } else { value + 0x800 };
+    // The value is at most `0x107FF` and is never a surrogate, so this is always `Some`.
+    char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// The tokens produced by [`lex`], along with their encoded kinds.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenStream<'input, K> {
+    input: &'input [u8],
+    tokens: Vec<Token<'input, K>>,
+    kinds: Vec<u8>,
+    /// The offset in `kinds` at which each token begins.
+    starts: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'input, K> TokenStream<'input, K> {
+    /// The tokens in the order they appear in the input.
+    pub fn tokens(&self) -> &[Token<'input, K>] {
+        &self.tokens
+    }
+
+    /// The kind of each token, encoded as UTF-8. This is the input for parsers run on the token
+    /// stream.
+    pub fn kinds(&self) -> &[u8] {
+        &self.kinds
+    }
+
+    /// The index of the token at the start of the remaining input, where the remaining input is a
+    /// suffix of [`kinds`](Self::kinds). If the remaining input is empty, this is the number of
+    /// tokens.
+    fn index_of(&self, remaining_input: &[u8]) -> Option<usize> {
+        let offset = self.kinds.len().checked_sub(remaining_input.len())?;
+        if offset == self.kinds.len() {
+            return Some(self.tokens.len());
+        }
+        self.starts.binary_search(&offset).ok()
+    }
+
+    /// The token at the start of the remaining input, where the remaining input is a suffix of
+    /// [`kinds`](Self::kinds).
+    pub fn token_at(&self, remaining_input: &[u8]) -> Option<&Token<'input, K>> {
+        self.tokens.get(self.index_of(remaining_input)?)
+    }
+
+    /// The tokens consumed by a parser, given the input before and after it was run. Both must be
+    /// suffixes of [`kinds`](Self::kinds) that begin at a token.
+    pub fn tokens_between(&self, before: &[u8], after: &[u8]) -> Option<&[Token<'input, K>]> {
+        self.tokens
+            .get(self.index_of(before)?..self.index_of(after)?)
+    }
+
+    /// The input that the tokens consumed by a parser were produced from, given the input before
+    /// and after it was run. This includes any skipped input between the tokens.
+    pub fn text_between(&self, before: &[u8], after: &[u8]) -> Option<&'input [u8]> {
+        match self.tokens_between(before, after)? {
+            [] => Some(&[]),
+            [first, .., last] | [first @ last] => {
+                Some(&self.input[first.span.start..last.span.end])
+            }
+        }
+    }
+}
+
+/// Consume a token of the given kind from the kinds of a [`TokenStream`].
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn kind<K>(
+    kind: K,
+) -> impl for<'input> Parser<
+    'input,
+    Output = K,
+    Error = Either<error::NonMatchingInput, error::EndOfInput>,
+>
+where
+    K: Copy + Into<u16>,
+{
+    let mut encoded = [0; 4];
+    let len = kind_char(kind.into()).encode_utf8(&mut encoded).len();
+
+    hrtb_hack(move |input: &[u8]| {
+        if input.len() < len {
+            return Err(Either::B(error::EndOfInput));
+        }
+
+        match input.strip_prefix(&encoded[..len]) {
+            Some(remaining_input) => Ok(ParsedItem::from_parts(remaining_input, kind)),
+            None => Err(Either::A(error::NonMatchingInput)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{ascii, verbatim};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u16)]
+    enum Kind {
+        Let,
+        Ident,
+        Number,
+        Equals = 300,
+        Semicolon = 0xD800,
+    }
+
+    impl From<Kind> for u16 {
+        #[cfg_attr(coverage, coverage(off))]
+        fn from(kind: Kind) -> Self {
+            kind as Self
+        }
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn rule<'input>() -> impl Parser<'input, Output = Option<Kind>, Error = impl Sized> {
+        rules((
+            token(verbatim(b"let"), Kind::Let),
+            token(ascii::alphabetic.discard_at_least_n(1), Kind::Ident),
+            token(ascii::digit.discard_at_least_n(1), Kind::Number),
+            token(verbatim(b"="), Kind::Equals),
+            token(verbatim(b";"), Kind::Semicolon),
+            skip(ascii::whitespace),
+        ))
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_tokens() {
+        let mut tokens = tokens(rule(), b"let lettuce = 42");
+
+        assert_eq!(
+            tokens.next(),
+            Some(Ok(Token {
+                kind: Kind::Let,
+                span: Span { start: 0, end: 3 },
+                text: b"let".as_ref(),
+            }))
+        );
+        assert_eq!(
+            tokens.next(),
+            Some(Ok(Token {
+                kind: Kind::Ident,
+                span: Span { start: 4, end: 11 },
+                text: b"lettuce".as_ref(),
+            }))
+        );
+        assert_eq!(
+            tokens.next().map(|token| token.map(|token| token.kind)),
+            Some(Ok(Kind::Equals))
+        );
+        assert_eq!(
+            tokens.next().map(|token| token.map(|token| token.span)),
+            Some(Ok(Span { start: 14, end: 16 }))
+        );
+        assert_eq!(tokens.next(), None);
+        assert_eq!(tokens.offset(), 16);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_tokens_unrecognized() {
+        let mut tokens = tokens(rule(), b"a ? b");
+
+        assert!(matches!(tokens.next(), Some(Ok(_))));
+        assert_eq!(
+            tokens.next(),
+            Some(Err(error::UnrecognizedToken { offset: 2 }))
+        );
+        assert_eq!(tokens.next(), None);
+        assert_eq!(tokens.input(), b"? b");
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_tokens_array() {
+        let rule = rules([
+            token(verbatim(b"a"), 'a'),
+            token(verbatim(b"ab"), 'b'),
+            token(verbatim(b"b"), 'c'),
+        ]);
+        let mut tokens = tokens(rule, b"abb");
+        assert_eq!(
+            tokens.next().map(|token| token.map(|t| t.kind)),
+            Some(Ok('b'))
+        );
+        assert_eq!(
+            tokens.next().map(|token| token.map(|t| t.kind)),
+            Some(Ok('c'))
+        );
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lex() {
+        let stream = lex(rule(), b"let x = 1;").expect("input is valid");
+        assert_eq!(stream.kinds(), "\u{0}\u{1}\u{12C}\u{2}\u{E000}".as_bytes());
+        assert_eq!(stream.tokens().len(), 5);
+
+        let parser = kind(Kind::Let)
+            .and(kind(Kind::Ident))
+            .and(kind(Kind::Equals));
+        let (remaining_input, ((let_kind, _), _)) = parser
+            .parse(stream.kinds())
+            .map(ParsedItem::into_parts)
+            .expect("tokens are valid");
+
+        assert_eq!(let_kind, Kind::Let);
+        assert_eq!(
+            stream
+                .tokens_between(stream.kinds(), remaining_input)
+                .map(|tokens| tokens[1].text),
+            Some(b"x".as_ref())
+        );
+        assert_eq!(
+            stream.text_between(stream.kinds(), remaining_input),
+            Some(b"let x =".as_ref())
+        );
+        assert_eq!(
+            stream.token_at(remaining_input).map(|token| token.kind),
+            Some(Kind::Number)
+        );
+
+        let (end, _) = kind(Kind::Number)
+            .and(kind(Kind::Semicolon))
+            .parse(remaining_input)
+            .map(ParsedItem::into_parts)
+            .expect("tokens are valid");
+        assert_eq!(stream.token_at(end), None);
+        assert_eq!(stream.text_between(end, end), Some(b"".as_ref()));
+        assert_eq!(
+            stream.text_between(remaining_input, end),
+            Some(b"1;".as_ref())
+        );
+
+        assert_eq!(
+            lex(rule(), b"1 + 2"),
+            Err(error::UnrecognizedToken { offset: 2 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_lex_invalid_position() {
+        let stream = lex(rule(), b"x = 1").expect("input is valid");
+
+        // The second byte of the encoded `=` token.
+        let inside_token = &stream.kinds()[2..];
+        assert_eq!(stream.token_at(inside_token), None);
+        assert_eq!(stream.tokens_between(inside_token, &[]), None);
+        assert_eq!(stream.token_at(b"too long for the stream"), None);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_kind() {
+        let encoded = "\u{12C}".as_bytes();
+
+        assert_eq!(
+            kind(Kind::Equals)
+                .parse(encoded)
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), Kind::Equals))
+        );
+        assert_eq!(
+            kind(Kind::Equals).parse(&encoded[..1]),
+            Err(Either::B(error::EndOfInput))
+        );
+        assert_eq!(
+            kind(Kind::Ident).parse(encoded),
+            Err(Either::A(error::NonMatchingInput))
+        );
+    }
+}
@@ -13,12 +13,16 @@ extern crate alloc;
 pub mod combinator;
 mod either;
 pub mod error;
+pub mod lexer;
 mod parsed_item;
 pub mod parser;
 
 #[doc(inline)]
 pub use self::combinator::Combinator;
-pub use self::either::Either;
+pub use self::either::{
+    Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6, Either7, Either8,
+    Either9,
+};
 #[doc(inline)]
 pub use self::error::Error;
 pub use self::parsed_item::ParsedItem;
@@ -27,6 +31,23 @@ pub use self::parser::{Parser, ParserResult};
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+/// Parse the entire input, returning the output of the parser.
+///
+/// If input remains after the parser succeeds, the error indicates the offset of the first byte
+/// that was not consumed.
+pub fn parse_complete<'input, P>(
+    parser: P,
+    input: &'input [u8],
+) -> Result<P::Output, Either<P::Error, error::TrailingInput>>
+where
+    P: Parser<'input>,
+{
+    parser
+        .all_consuming()
+        .parse(input)
+        .map(ParsedItem::into_value)
+}
+
 #[macro_export]
 macro_rules! seq {
     () => {};
@@ -38,6 +59,23 @@ macro_rules! seq {
     };
 }
 
+/// Implement `FromStr` for a type using a parser whose output is that type.
+///
+/// The entire string must be consumed by the parser. The error type is [`Error`], so the error of
+/// the parser must be convertible into it.
+#[macro_export]
+macro_rules! impl_from_str {
+    ($ty:ty, $parser:expr $(,)?) => {
+        impl ::core::str::FromStr for $ty {
+            type Err = $crate::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::parse_complete($parser, s.as_bytes()).map_err(::core::convert::Into::into)
+            }
+        }
+    };
+}
+
 /// When attempting to return `impl Parser` from a function, a lifetime is necessary. If this
 /// lifetime is entirely up to the caller, a higher ranked trait bound (HRTB) should be used. The
 /// compiler can be quite bad at inferring lifetimes of parameters to closures (namely the input),
@@ -54,3 +92,42 @@ where
 {
     f
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ascii;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Port(u16);
+
+    impl_from_str!(Port, ascii::int::<u16>.map(Port));
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_parse_complete() {
+        assert_eq!(parse_complete(ascii::int::<u8>, b"12"), Ok(12));
+        assert_eq!(
+            parse_complete(ascii::int::<u8>, b"12a"),
+            Err(Either::B(error::TrailingInput { offset: 2 }))
+        );
+        assert_eq!(
+            parse_complete(ascii::int::<u8>, b""),
+            Err(Either::A(Either::B(error::EndOfInput)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_impl_from_str() {
+        assert_eq!("8080".parse(), Ok(Port(8080)));
+        assert_eq!(
+            "8080 ".parse::<Port>(),
+            Err(Error::TrailingInput(error::TrailingInput { offset: 4 }))
+        );
+        assert_eq!(
+            "80800".parse::<Port>(),
+            Err(Error::AsciiInteger(error::AsciiInteger))
+        );
+    }
+}
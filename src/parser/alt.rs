@@ -0,0 +1,207 @@
+use crate::{
+    Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6, Either7, Either8,
+    Either9, ParsedItem, Parser, ParserResult,
+};
+
+/// Attempt each parser in the tuple in order, returning the output of the first that succeeds.
+///
+/// The output indicates which parser succeeded. When every parser has the same output type,
+/// [`choice`] outputs the value directly. If no parser succeeds, the errors from every parser are
+/// returned in declaration order.
+pub fn alt<'input, T>(parsers: T) -> impl Parser<'input, Output = T::Output, Error = T::Error>
+where
+    T: Alt<'input>,
+{
+    move |input| parsers.parse_alt(input)
+}
+
+/// Attempt each parser in the tuple in order, returning the output of the first that succeeds.
+///
+/// This behaves as [`alt`], except that every parser must have the same output type, which is
+/// output directly.
+pub fn choice<'input, T, O>(parsers: T) -> impl Parser<'input, Output = O, Error = T::Error>
+where
+    T: Choice<'input, O>,
+{
+    move |input| Ok(parsers.parse_alt(input)?.map_value(T::unify))
+}
+
+mod sealed {
+    use super::*;
+
+    pub trait Sealed<'input>: Copy {
+        type Output;
+        type Error;
+
+        fn parse_alt(self, input: &'input [u8]) -> ParserResult<'input, Self::Output, Self::Error>;
+    }
+
+    pub trait SealedChoice<'input, O>: Sealed<'input> {
+        fn unify(output: Self::Output) -> O;
+    }
+}
+
+use self::sealed::{Sealed, SealedChoice};
+
+pub trait Alt<'input>: Sealed<'input> {}
+
+/// A tuple of parsers that all have the output type `O`.
+pub trait Choice<'input, O>: Alt<'input> + SealedChoice<'input, O> {}
+
+macro_rules! impl_alt {
+    ($either:ident; $($parser:ident $err:ident $variant:ident $index:tt),+) => {
+        impl<'input, $($parser),+> Sealed<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {
+            type Output = $either<$($parser::Output),+>;
+            type Error = ($($parser::Error,)+);
+
+            fn parse_alt(
+                self,
+                input: &'input [u8],
+            ) -> ParserResult<'input, Self::Output, Self::Error> {
+                $(let $err = match self.$index.parse(input) {
+                    Ok(parsed_item) => {
+                        let (remaining_input, value) = parsed_item.into_parts();
+                        return Ok(ParsedItem::from_parts(
+                            remaining_input,
+                            $either::$variant(value),
+                        ));
+                    }
+                    Err(err) => err,
+                };)+
+                Err(($($err,)+))
+            }
+        }
+
+        impl<'input, $($parser),+> Alt<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {}
+
+        impl<'input, O, $($parser),+> SealedChoice<'input, O> for ($($parser,)+)
+        where
+            $($parser: Parser<'input, Output = O>),+
+        {
+            fn unify(output: Self::Output) -> O {
+                output.unify()
+            }
+        }
+
+        impl<'input, O, $($parser),+> Choice<'input, O> for ($($parser,)+)
+        where
+            $($parser: Parser<'input, Output = O>),+
+        {}
+    };
+}
+
+impl_alt!(Either; P0 e0 A 0, P1 e1 B 1);
+impl_alt!(Either3; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2);
+impl_alt!(Either4; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3);
+impl_alt!(Either5; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4);
+impl_alt!(Either6; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5);
+impl_alt!(Either7; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6);
+impl_alt!(
+    Either8;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7
+);
+impl_alt!(
+    Either9;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8
+);
+impl_alt!(
+    Either10;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9
+);
+impl_alt!(
+    Either11;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9, P10 e10 K 10
+);
+impl_alt!(
+    Either12;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9, P10 e10 K 10, P11 e11 L 11
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error;
+    use crate::parser::{ascii, byte, verbatim};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_alt_valid() {
+        let parser = alt((byte(b'a'), verbatim(b"bc"), ascii::digit));
+
+        assert_eq!(
+            parser.parse(b"ax").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), Either3::A(b'a')))
+        );
+        assert_eq!(
+            parser.parse(b"bcx").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), Either3::B(b"bc".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"1x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), Either3::C(1)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_alt_unify() {
+        let parser = alt((
+            verbatim(b"a"),
+            verbatim(b"b"),
+            verbatim(b"c"),
+            verbatim(b"d"),
+        ));
+
+        assert_eq!(
+            parser
+                .map(Either4::unify)
+                .parse(b"d")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"d".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_choice() {
+        let parser = choice((verbatim(b"a"), verbatim(b"bc"), verbatim(b"d")));
+
+        assert_eq!(
+            parser.parse(b"bcx").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"bc".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"x"),
+            Err((
+                Either::A(error::NonMatchingInput),
+                Either::B(error::EndOfInput),
+                Either::A(error::NonMatchingInput)
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_alt_invalid() {
+        let parser = alt((byte(b'a'), verbatim(b"bc"), ascii::digit));
+
+        assert_eq!(
+            parser.parse(b"b"),
+            Err((
+                Either::A(error::Byte),
+                Either::B(error::EndOfInput),
+                Either::A(error::AsciiDigit)
+            ))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::Utf8ErrorKind;
+use crate::{error, Either, ParsedItem, ParserResult};
+
+/// Consume a single UTF-8 encoded character.
+///
+/// Overlong encodings, surrogates, and values greater than `U+10FFFF` are rejected. These are
+/// detected from the first two bytes, so a truncated character is only reported as
+/// [`Utf8ErrorKind::Incomplete`] if the bytes that are present could begin a valid character. An
+/// end of input error is returned only if the input is empty.
+pub fn any_utf8_char(
+    input: &[u8],
+) -> ParserResult<'_, char, Either<error::InvalidUtf8, error::EndOfInput>> {
+    let invalid = |kind| Err(Either::A(error::InvalidUtf8 { kind }));
+
+    // The length of the character, the bits of the value from the first byte, and the range of
+    // the second byte that does not produce an overlong encoding, a surrogate, or a value greater
+    // than `U+10FFFF`, along with the error if it is outside that range.
+    let (len, initial, second, second_kind) = match input.first() {
+        None => return Err(Either::B(error::EndOfInput)),
+        Some(&b @ 0x00..=0x7F) => return Ok(ParsedItem::from_parts(&input[1..], char::from(b))),
+        Some(0xC0 | 0xC1) => return invalid(Utf8ErrorKind::Overlong),
+        Some(&b @ 0xC2..=0xDF) => (2, b & 0x1F, 0x80..=0xBF, Utf8ErrorKind::Overlong),
+        Some(&b @ 0xE0) => (3, b & 0x0F, 0xA0..=0xBF, Utf8ErrorKind::Overlong),
+        Some(&b @ 0xED) => (3, b & 0x0F, 0x80..=0x9F, Utf8ErrorKind::Surrogate),
+        Some(&b @ 0xE1..=0xEF) => (3, b & 0x0F, 0x80..=0xBF, Utf8ErrorKind::Overlong),
+        Some(&b @ 0xF0) => (4, b & 0x07, 0x90..=0xBF, Utf8ErrorKind::Overlong),
+        Some(&b @ 0xF4) => (4, b & 0x07, 0x80..=0x8F, Utf8ErrorKind::OutOfRange),
+        Some(&b @ 0xF1..=0xF3) => (4, b & 0x07, 0x80..=0xBF, Utf8ErrorKind::OutOfRange),
+        Some(0xF5..=0xF7) => return invalid(Utf8ErrorKind::OutOfRange),
+        Some(_) => return invalid(Utf8ErrorKind::InvalidStartByte),
+    };
+
+    let mut value = u32::from(initial);
+    for i in 1..len {
+        match input.get(i) {
+            Some(&b @ 0x80..=0xBF) if i != 1 || second.contains(&b) => {
+                value = (value << 6) | u32::from(b & 0x3F);
+            }
+            Some(0x80..=0xBF) => return invalid(second_kind),
+            Some(_) => return invalid(Utf8ErrorKind::InvalidContinuationByte),
+            None => return invalid(Utf8ErrorKind::Incomplete),
+        }
+    }
+
+    match char::from_u32(value) {
+        Some(c) => Ok(ParsedItem::from_parts(&input[len..], c)),
+        // The range of the second byte excludes every value that is not a character.
+        None => invalid(second_kind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn invalid(kind: Utf8ErrorKind) -> Either<error::InvalidUtf8, error::EndOfInput> {
+        Either::A(error::InvalidUtf8 { kind })
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_any_utf8_char_valid() {
+        for c in ['$', '£', '€', '🦀', '\u{10FFFF}'] {
+            let mut buf = [0; 5];
+            let len = c.encode_utf8(&mut buf).len();
+            buf[len] = b'0';
+            assert_eq!(
+                any_utf8_char(&buf[..=len]).map(ParsedItem::into_parts),
+                Ok((b"0".as_ref(), c))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_any_utf8_char_invalid() {
+        assert_eq!(
+            any_utf8_char(b"\x80"),
+            Err(invalid(Utf8ErrorKind::InvalidStartByte))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF8"),
+            Err(invalid(Utf8ErrorKind::InvalidStartByte))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xE2\x28\xA1"),
+            Err(invalid(Utf8ErrorKind::InvalidContinuationByte))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xC0\xAF"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xE0\x80\xAF"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF0\x80\x80\xAF"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xED\xA0\x80"),
+            Err(invalid(Utf8ErrorKind::Surrogate))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF4\x90\x80\x80"),
+            Err(invalid(Utf8ErrorKind::OutOfRange))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_any_utf8_char_end_of_input() {
+        assert_eq!(any_utf8_char(b""), Err(Either::B(error::EndOfInput)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_any_utf8_char_incomplete() {
+        for input in [
+            b"\xC2".as_ref(),
+            b"\xE2\x82",
+            b"\xE0\xA0",
+            b"\xED\x9F",
+            b"\xF0\x90\x80",
+            b"\xF4\x8F",
+        ] {
+            assert_eq!(
+                any_utf8_char(input),
+                Err(invalid(Utf8ErrorKind::Incomplete))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_any_utf8_char_invalid_prefix() {
+        assert_eq!(
+            any_utf8_char(b"\xE0\x80"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF0\x8F"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xC1"),
+            Err(invalid(Utf8ErrorKind::Overlong))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xED\xA0"),
+            Err(invalid(Utf8ErrorKind::Surrogate))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF4\x90"),
+            Err(invalid(Utf8ErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xF5"),
+            Err(invalid(Utf8ErrorKind::OutOfRange))
+        );
+        assert_eq!(
+            any_utf8_char(b"\xE2\x28"),
+            Err(invalid(Utf8ErrorKind::InvalidContinuationByte))
+        );
+    }
+}
@@ -3,6 +3,7 @@ mod alphanumeric;
 mod digit;
 mod hex_digit;
 mod int;
+pub mod utf8;
 mod whitespace;
 
 pub use self::alphabetic::alphabetic;
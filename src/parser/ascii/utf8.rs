@@ -0,0 +1,70 @@
+//! The ASCII classes as [`Utf8`] parsers, permitting their consumed input to be output as a string
+//! without validation.
+
+use crate::parser::{ascii, Utf8};
+use crate::{error, Either, Parser};
+
+/// Consume exactly one ASCII letter.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn alphabetic() -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = char,
+        Error = Either<error::AsciiAlphabetic, error::EndOfInput>,
+    >,
+> {
+    // Safety: The parser consumes a single ASCII byte.
+    unsafe { Utf8::new_unchecked(ascii::alphabetic) }
+}
+
+/// Consume exactly one ASCII letter or digit.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn alphanumeric() -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = char,
+        Error = Either<error::AsciiAlphanumeric, error::EndOfInput>,
+    >,
+> {
+    // Safety: The parser consumes a single ASCII byte.
+    unsafe { Utf8::new_unchecked(ascii::alphanumeric) }
+}
+
+/// Consume exactly one ASCII digit.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn digit() -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = u8,
+        Error = Either<error::AsciiDigit, error::EndOfInput>,
+    >,
+> {
+    // Safety: The parser consumes a single ASCII byte.
+    unsafe { Utf8::new_unchecked(ascii::digit) }
+}
+
+/// Consume exactly one ASCII hex digit.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn hex_digit() -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = u8,
+        Error = Either<error::AsciiHexDigit, error::EndOfInput>,
+    >,
+> {
+    // Safety: The parser consumes a single ASCII byte.
+    unsafe { Utf8::new_unchecked(ascii::hex_digit) }
+}
+
+/// Consume exactly one ASCII whitespace character.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn whitespace() -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = char,
+        Error = Either<error::AsciiWhitespace, error::EndOfInput>,
+    >,
+> {
+    // Safety: The parser consumes a single ASCII byte.
+    unsafe { Utf8::new_unchecked(ascii::whitespace) }
+}
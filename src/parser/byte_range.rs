@@ -0,0 +1,41 @@
+use core::ops::RangeInclusive;
+
+use crate::parser::one_of;
+use crate::{error, Either, Parser};
+
+/// Consume a byte that is within the range.
+pub fn byte_range(
+    range: RangeInclusive<u8>,
+) -> impl for<'input> Parser<'input, Output = u8, Error = Either<error::Byte, error::EndOfInput>> {
+    one_of(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_byte_range_valid() {
+        assert_eq!(
+            byte_range(b'a'..=b'f')
+                .parse(b"f0")
+                .map(ParsedItem::into_parts),
+            Ok((b"0".as_ref(), b'f'))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_byte_range_invalid() {
+        assert_eq!(
+            byte_range(b'a'..=b'f').parse(b"g"),
+            Err(Either::A(error::Byte))
+        );
+        assert_eq!(
+            byte_range(b'a'..=b'f').parse(b""),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
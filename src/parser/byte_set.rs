@@ -0,0 +1,138 @@
+use core::ops::RangeInclusive;
+
+/// A set of bytes, stored as a 256-bit table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// A set containing no bytes.
+    pub const fn new() -> Self {
+        Self([0; 4])
+    }
+
+    /// A set containing every byte.
+    pub const fn full() -> Self {
+        Self([u64::MAX; 4])
+    }
+
+    /// A set containing each of the provided bytes.
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.with(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// A set containing every byte from `start` through `end`, inclusive.
+    pub const fn range(start: u8, end: u8) -> Self {
+        let mut set = Self::new();
+        let mut byte = start as usize;
+        while byte <= end as usize {
+            set = set.with(byte as u8);
+            byte += 1;
+        }
+        set
+    }
+
+    /// Add the byte to the set.
+    #[must_use]
+    pub const fn with(mut self, byte: u8) -> Self {
+        self.0[(byte / 64) as usize] |= 1 << (byte % 64);
+        self
+    }
+
+    /// Whether the set contains the byte.
+    pub const fn contains(self, byte: u8) -> bool {
+        self.0[(byte / 64) as usize] & (1 << (byte % 64)) != 0
+    }
+
+    /// The bytes contained in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self([
+            self.0[0] | other.0[0],
+            self.0[1] | other.0[1],
+            self.0[2] | other.0[2],
+            self.0[3] | other.0[3],
+        ])
+    }
+
+    /// The bytes contained in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self([
+            self.0[0] & other.0[0],
+            self.0[1] & other.0[1],
+            self.0[2] & other.0[2],
+            self.0[3] & other.0[3],
+        ])
+    }
+
+    /// The bytes not contained in the set.
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self([!self.0[0], !self.0[1], !self.0[2], !self.0[3]])
+    }
+}
+
+impl From<&[u8]> for ByteSet {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for ByteSet {
+    fn from(bytes: &[u8; N]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<u8> for ByteSet {
+    fn from(byte: u8) -> Self {
+        Self::new().with(byte)
+    }
+}
+
+impl From<RangeInclusive<u8>> for ByteSet {
+    fn from(range: RangeInclusive<u8>) -> Self {
+        Self::range(*range.start(), *range.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_byte_set_contains() {
+        const SET: ByteSet = ByteSet::from_bytes(b"+-").with(0xFF);
+
+        assert!(SET.contains(b'+'));
+        assert!(SET.contains(b'-'));
+        assert!(SET.contains(0xFF));
+        assert!(!SET.contains(b'*'));
+        assert!(!ByteSet::new().contains(0));
+        assert!(ByteSet::full().contains(0));
+        assert!(ByteSet::from(0..=255).contains(128));
+        assert_eq!(ByteSet::from(b'a'), ByteSet::from(b"a"));
+        assert_eq!(ByteSet::from(b"ab".as_ref()), ByteSet::from(b'a'..=b'b'));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_byte_set_operations() {
+        let hex = ByteSet::range(b'0', b'9').union(ByteSet::range(b'a', b'f'));
+        let letters = ByteSet::range(b'a', b'z');
+
+        assert!(hex.contains(b'5'));
+        assert!(hex.intersection(letters).contains(b'c'));
+        assert!(!hex.intersection(letters).contains(b'5'));
+        assert!(!hex.complement().contains(b'a'));
+        assert!(hex.complement().contains(b'g'));
+        assert_eq!(ByteSet::new().complement(), ByteSet::full());
+    }
+}
@@ -0,0 +1,34 @@
+/// How letters are compared when matching expected input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Every byte must be identical.
+    #[default]
+    Sensitive,
+    /// ASCII letters may differ in case. All other bytes must be identical.
+    InsensitiveAscii,
+}
+
+impl Case {
+    /// Whether the input matches the expected bytes.
+    pub fn matches(self, input: &[u8], expected: &[u8]) -> bool {
+        match self {
+            Self::Sensitive => input == expected,
+            Self::InsensitiveAscii => input.eq_ignore_ascii_case(expected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_case_matches() {
+        assert!(Case::Sensitive.matches(b"Select", b"Select"));
+        assert!(!Case::Sensitive.matches(b"SELECT", b"select"));
+        assert!(Case::InsensitiveAscii.matches(b"SELECT", b"select"));
+        assert!(!Case::InsensitiveAscii.matches(b"\xC3\x89", b"\xC3\xA9"));
+        assert!(!Case::InsensitiveAscii.matches(b"sel", b"select"));
+    }
+}
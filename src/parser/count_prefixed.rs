@@ -0,0 +1,118 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{error, Either4, ParsedItem, Parser};
+
+/// Parse a count, then run the item parser exactly that many times.
+///
+/// If the count is negative or cannot be represented as a `usize`, [`error::InvalidLength`] is
+/// returned. Each item must consume input; if an item does not, [`error::NoProgress`] is returned.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn count_prefixed<'input, C, P>(
+    count: C,
+    item: P,
+) -> impl Parser<
+    'input,
+    Output = Vec<P::Output>,
+    Error = Either4<C::Error, error::InvalidLength, P::Error, error::NoProgress>,
+>
+where
+    C: Parser<'input>,
+    C::Output: TryInto<usize>,
+    P: Parser<'input>,
+{
+    move |original_input: &'input [u8]| {
+        let (mut input, count) = match count.parse(original_input) {
+            Ok(parsed_item) => parsed_item.into_parts(),
+            Err(err) => return Err(Either4::A(err)),
+        };
+        let Ok(count) = count.try_into() else {
+            return Err(Either4::B(error::InvalidLength {
+                offset: original_input.len() - input.len(),
+            }));
+        };
+
+        // The count is untrusted. As every item consumes at least one byte, the remaining input
+        // bounds the number of items that can be parsed.
+        let mut output = Vec::with_capacity(count.min(input.len()));
+        for _ in 0..count {
+            let (remaining_input, value) = match item.parse(input) {
+                Ok(parsed_item) if parsed_item.input().len() < input.len() => {
+                    parsed_item.into_parts()
+                }
+                Ok(_) => return Err(Either4::D(error::NoProgress)),
+                Err(err) => return Err(Either4::C(err)),
+            };
+            input = remaining_input;
+            output.push(value);
+        }
+
+        Ok(ParsedItem::from_parts(input, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::*;
+    use crate::parser::{int_be, int_le, success};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_count_prefixed_valid() {
+        let parser = count_prefixed(int_be::<u8>, int_le::<u16>);
+
+        assert_eq!(
+            parser
+                .parse(b"\x02\x01\x00\x02\x00\x03")
+                .map(ParsedItem::into_parts),
+            Ok((b"\x03".as_ref(), vec![1, 2]))
+        );
+        assert_eq!(
+            parser.parse(b"\x00").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), vec![]))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_count_prefixed_invalid() {
+        let parser = count_prefixed(int_be::<i8>, int_le::<u16>);
+
+        assert_eq!(parser.parse(b""), Err(Either4::A(error::EndOfInput)));
+        assert_eq!(
+            parser.parse(b"\xff"),
+            Err(Either4::B(error::InvalidLength { offset: 1 }))
+        );
+        assert_eq!(
+            parser.parse(b"\x02\x01\x00\x02"),
+            Err(Either4::C(error::EndOfInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_count_prefixed_large_count() {
+        let parser = count_prefixed(int_be::<u64>, int_le::<u16>);
+
+        assert_eq!(
+            parser.parse(b"\xff\xff\xff\xff\xff\xff\xff\xff\x01\x00"),
+            Err(Either4::C(error::EndOfInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_count_prefixed_no_progress() {
+        let parser = count_prefixed(int_be::<u8>, success(()));
+
+        assert_eq!(parser.parse(b"\x02"), Err(Either4::D(error::NoProgress)));
+        assert_eq!(
+            parser.parse(b"\x00").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), vec![]))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{ParsedItem, Parser};
+
+/// Consume a run of input consisting of normal content and escape sequences, outputting the
+/// consumed bytes without modification.
+///
+/// At each position, `normal` is attempted first. If it does not consume any input and the next
+/// byte is `escape_byte`, `escape_handler` is run on the input following the escape byte. Parsing
+/// stops when neither matches. If an escape byte is not followed by a valid escape sequence, the
+/// error from `escape_handler` is returned.
+pub fn escaped<'input, N, H>(
+    normal: N,
+    escape_byte: u8,
+    escape_handler: H,
+) -> impl Parser<'input, Output = &'input [u8], Error = H::Error>
+where
+    N: Parser<'input>,
+    H: Parser<'input>,
+{
+    move |input: &'input [u8]| {
+        let mut remaining_input = input;
+
+        loop {
+            if let Ok(parsed_item) = normal.parse(remaining_input) {
+                if parsed_item.input().len() < remaining_input.len() {
+                    remaining_input = parsed_item.input();
+                    continue;
+                }
+            }
+
+            match remaining_input {
+                [first, rest @ ..] if *first == escape_byte => {
+                    remaining_input = escape_handler.parse(rest)?.input();
+                }
+                _ => break,
+            }
+        }
+
+        let consumed = &input[..(input.len() - remaining_input.len())];
+        Ok(ParsedItem::from_parts(remaining_input, consumed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{any_byte, byte, first_success};
+    use crate::{error, Either};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_valid() {
+        let normal = any_byte
+            .filter(|&b| b != b'"' && b != b'\\')
+            .at_least_n_raw(1);
+        let parser = escaped(normal, b'\\', first_success([byte(b'"'), byte(b'\\')]));
+
+        assert_eq!(
+            parser.parse(br#"ab\"c\\d"x"#).map(ParsedItem::into_parts),
+            Ok((br#""x"#.as_ref(), br#"ab\"c\\d"#.as_ref()))
+        );
+        assert_eq!(
+            parser.parse(br#""x"#).map(ParsedItem::into_parts),
+            Ok((br#""x"#.as_ref(), b"".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_invalid() {
+        let normal = any_byte
+            .filter(|&b| b != b'"' && b != b'\\')
+            .at_least_n_raw(1);
+        let parser = escaped(normal, b'\\', first_success([byte(b'"'), byte(b'\\')]));
+
+        assert_eq!(parser.parse(br"ab\n"), Err(Either::A(error::Byte)));
+        assert_eq!(parser.parse(br"ab\"), Err(Either::B(error::EndOfInput)));
+    }
+}
@@ -0,0 +1,255 @@
+#[cfg(not(feature = "std"))]
+use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+use crate::combinator::invalid_utf8;
+use crate::{error, Either, ParsedItem, Parser};
+
+/// Consume a run of input consisting of normal content and escape sequences, outputting the
+/// content with escape sequences replaced by the output of `escape_handler`.
+///
+/// Input is consumed in the same manner as [`escaped`](crate::parser::escaped). The output borrows
+/// from the input if no escape sequences are present, allocating only when necessary.
+pub fn escaped_transform<'input, N, H>(
+    normal: N,
+    escape_byte: u8,
+    escape_handler: H,
+) -> impl Parser<'input, Output = Cow<'input, [u8]>, Error = H::Error>
+where
+    N: Parser<'input>,
+    H: Parser<'input>,
+    H::Output: Unescaped,
+{
+    move |input: &'input [u8]| {
+        let mut remaining_input = input;
+        let mut output = None::<Vec<u8>>;
+
+        loop {
+            if let Ok(parsed_item) = normal.parse(remaining_input) {
+                let new_remaining_input = parsed_item.input();
+                if new_remaining_input.len() < remaining_input.len() {
+                    if let Some(output) = &mut output {
+                        let consumed = remaining_input.len() - new_remaining_input.len();
+                        output.extend_from_slice(&remaining_input[..consumed]);
+                    }
+                    remaining_input = new_remaining_input;
+                    continue;
+                }
+            }
+
+            match remaining_input {
+                [first, rest @ ..] if *first == escape_byte => {
+                    let (new_remaining_input, value) = escape_handler.parse(rest)?.into_parts();
+                    let output = output.get_or_insert_with(|| {
+                        input[..(input.len() - remaining_input.len())].to_vec()
+                    });
+                    value.push_to(output);
+                    remaining_input = new_remaining_input;
+                }
+                _ => break,
+            }
+        }
+
+        let output = match output {
+            Some(output) => Cow::Owned(output),
+            None => Cow::Borrowed(&input[..(input.len() - remaining_input.len())]),
+        };
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    }
+}
+
+/// Consume a run of input consisting of normal content and escape sequences, outputting the
+/// content as a string with escape sequences replaced by the output of `escape_handler`.
+///
+/// This behaves identically to [`escaped_transform`], but the output is validated as UTF-8. The
+/// output borrows from the input if no escape sequences are present.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn escaped_transform_str<'input, N, H>(
+    normal: N,
+    escape_byte: u8,
+    escape_handler: H,
+) -> impl Parser<
+    'input,
+    Output = Cow<'input, str>,
+    Error = Either<H::Error, error::InvalidUtf8>,
+>
+where
+    N: Parser<'input>,
+    H: Parser<'input>,
+    H::Output: Unescaped,
+{
+    let parser = escaped_transform(normal, escape_byte, escape_handler);
+    move |input| {
+        let (remaining_input, output) = parser.parse(input).map_err(Either::A)?.into_parts();
+        let output = match output {
+            Cow::Borrowed(bytes) => match core::str::from_utf8(bytes) {
+                Ok(output) => Cow::Borrowed(output),
+                Err(err) => return Err(Either::B(invalid_utf8(bytes, err))),
+            },
+            Cow::Owned(bytes) => match String::from_utf8(bytes) {
+                Ok(output) => Cow::Owned(output),
+                Err(err) => {
+                    return Err(Either::B(invalid_utf8(err.as_bytes(), err.utf8_error())));
+                }
+            },
+        };
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    }
+}
+
+mod sealed {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    pub trait Sealed {
+        fn push_to(self, output: &mut Vec<u8>);
+    }
+
+    impl Sealed for u8 {
+        fn push_to(self, output: &mut Vec<u8>) {
+            output.push(self);
+        }
+    }
+
+    impl Sealed for char {
+        fn push_to(self, output: &mut Vec<u8>) {
+            output.extend_from_slice(self.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+
+    impl Sealed for &[u8] {
+        fn push_to(self, output: &mut Vec<u8>) {
+            output.extend_from_slice(self);
+        }
+    }
+
+    impl Sealed for &str {
+        fn push_to(self, output: &mut Vec<u8>) {
+            output.extend_from_slice(self.as_bytes());
+        }
+    }
+
+    impl<const N: usize> Sealed for [u8; N] {
+        fn push_to(self, output: &mut Vec<u8>) {
+            output.extend_from_slice(&self);
+        }
+    }
+}
+
+use self::sealed::Sealed;
+
+/// The output of an escape handler, which is appended to the unescaped output.
+pub trait Unescaped: Sealed {}
+
+impl Unescaped for u8 {}
+impl Unescaped for char {}
+impl Unescaped for &[u8] {}
+impl Unescaped for &str {}
+impl<const N: usize> Unescaped for [u8; N] {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::error::Utf8ErrorKind;
+    use crate::parser::{any_byte, ascii, take_while1};
+    use crate::ParserResult;
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn escape_handler(input: &[u8]) -> ParserResult<'_, u8, Either<(), error::EndOfInput>> {
+        any_byte
+            .filter_map(|b| match b {
+                b'n' => Ok(b'\n'),
+                b'"' | b'\\' => Ok(b),
+                _ => Err(()),
+            })
+            .parse(input)
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_borrowed() {
+        let normal = ascii::alphanumeric.at_least_n_raw(1);
+        let parsed = escaped_transform(normal, b'\\', escape_handler)
+            .parse(b"abc\"")
+            .map(ParsedItem::into_parts);
+
+        assert_eq!(parsed, Ok((b"\"".as_ref(), Cow::Borrowed(b"abc".as_ref()))));
+        assert!(matches!(parsed, Ok((_, Cow::Borrowed(_)))));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_owned() {
+        let normal = ascii::alphanumeric.at_least_n_raw(1);
+        let parsed = escaped_transform(normal, b'\\', escape_handler)
+            .parse(br#"ab\nc\\\"d""#)
+            .map(ParsedItem::into_parts);
+
+        assert_eq!(
+            parsed,
+            Ok((b"\"".as_ref(), Cow::Owned(b"ab\nc\\\"d".to_vec())))
+        );
+        assert!(matches!(parsed, Ok((_, Cow::Owned(_)))));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_char() {
+        let normal = ascii::alphanumeric.at_least_n_raw(1);
+        let parser = escaped_transform(normal, b'$', any_byte.map(|_| '€'));
+
+        assert_eq!(
+            parser.parse(b"a$xb").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), Cow::Owned("a€b".as_bytes().to_vec())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_invalid() {
+        let normal = ascii::alphanumeric.at_least_n_raw(1);
+        let parser = escaped_transform(normal, b'\\', escape_handler);
+
+        assert_eq!(parser.parse(br"ab\x"), Err(Either::A(())));
+        assert_eq!(parser.parse(br"ab\"), Err(Either::B(error::EndOfInput)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_str() {
+        let normal = ascii::alphanumeric.at_least_n_raw(1);
+        let parser = escaped_transform_str(normal, b'\\', escape_handler);
+
+        let parsed = parser.parse(b"abc\"").map(ParsedItem::into_parts);
+        assert_eq!(parsed, Ok((b"\"".as_ref(), Cow::Borrowed("abc"))));
+        assert!(matches!(parsed, Ok((_, Cow::Borrowed(_)))));
+
+        let parsed = parser.parse(br"ab\nc").map(ParsedItem::into_parts);
+        assert_eq!(parsed, Ok((b"".as_ref(), Cow::Owned("ab\nc".to_string()))));
+        assert!(matches!(parsed, Ok((_, Cow::Owned(_)))));
+
+        assert_eq!(parser.parse(br"ab\x"), Err(Either::A(Either::A(()))));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_escaped_transform_str_invalid_utf8() {
+        let normal = take_while1(|b| b != b'\\');
+        let parser = escaped_transform_str(normal, b'\\', escape_handler);
+        let invalid = Err(Either::B(error::InvalidUtf8 {
+            kind: Utf8ErrorKind::InvalidStartByte,
+        }));
+
+        assert_eq!(parser.parse(b"a\xff"), invalid);
+        assert_eq!(parser.parse(b"a\xff\\n"), invalid);
+    }
+}
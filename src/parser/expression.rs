@@ -0,0 +1,413 @@
+//! Operator-precedence expression parsing.
+//!
+//! [`expression`] builds a parser from an atom parser and parsers for prefix, infix, and postfix
+//! operators. Each operator parser outputs a description of the operator, including its binding
+//! power. Operators with a greater binding power bind more tightly. Parsed operands and operators
+//! are combined by a user-provided fold function.
+
+use crate::{error, Either4, ParsedItem, Parser, ParserResult};
+
+/// How an infix operator groups with other operators of the same binding power.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    /// `a ~ b ~ c` is parsed as `(a ~ b) ~ c`.
+    Left,
+    /// `a ~ b ~ c` is parsed as `a ~ (b ~ c)`.
+    Right,
+    /// `a ~ b ~ c` is an error.
+    None,
+}
+
+/// A prefix operator, such as unary negation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Prefix<O> {
+    op: O,
+    power: u8,
+}
+
+impl<O> Prefix<O> {
+    pub const fn new(op: O, power: u8) -> Self {
+        Self { op, power }
+    }
+}
+
+/// An infix operator, such as addition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Infix<O> {
+    op: O,
+    power: u8,
+    associativity: Associativity,
+}
+
+impl<O> Infix<O> {
+    pub const fn new(op: O, power: u8, associativity: Associativity) -> Self {
+        Self {
+            op,
+            power,
+            associativity,
+        }
+    }
+
+    pub const fn left(op: O, power: u8) -> Self {
+        Self::new(op, power, Associativity::Left)
+    }
+
+    pub const fn right(op: O, power: u8) -> Self {
+        Self::new(op, power, Associativity::Right)
+    }
+
+    pub const fn non_associative(op: O, power: u8) -> Self {
+        Self::new(op, power, Associativity::None)
+    }
+}
+
+/// A postfix operator, such as a factorial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Postfix<O> {
+    op: O,
+    power: u8,
+}
+
+impl<O> Postfix<O> {
+    pub const fn new(op: O, power: u8) -> Self {
+        Self { op, power }
+    }
+}
+
+/// An operator applied to its operands, provided to the fold function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation<O, T> {
+    Prefix(O, T),
+    Infix(T, O, T),
+    Postfix(T, O),
+}
+
+/// A parser for operators that never succeeds. Use this when an expression has no operators of a
+/// given kind.
+pub const fn no_operators<T>(_: &[u8]) -> ParserResult<'_, T, error::NonMatchingInput> {
+    Err(error::NonMatchingInput)
+}
+
+/// Parse an expression of atoms and operators.
+///
+/// Operator parsers are attempted where an operator may appear; their errors are not reported.
+/// Operands are combined with `fold` as soon as their grouping is known. Nesting of operators that
+/// require recursion (prefix operators and the right-hand side of infix operators that bind more
+/// tightly than the preceding operator) is limited to `max_depth`, ensuring the stack is bounded.
+///
+/// If no expression is present, the error from the atom parser is returned. Other errors indicate
+/// the offset of the relevant operator from the start of the input.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn expression<'input, A, Pre, In, Post, O, F>(
+    atom: A,
+    prefix: Pre,
+    infix: In,
+    postfix: Post,
+    fold: F,
+    max_depth: usize,
+) -> impl Parser<
+    'input,
+    Output = A::Output,
+    Error = Either4<
+        A::Error,
+        error::MissingOperand,
+        error::NonAssociativeOperator,
+        error::ExpressionTooDeep,
+    >,
+>
+where
+    A: Parser<'input>,
+    Pre: Parser<'input, Output = Prefix<O>>,
+    In: Parser<'input, Output = Infix<O>>,
+    Post: Parser<'input, Output = Postfix<O>>,
+    F: Fn(Operation<O, A::Output>) -> A::Output + Copy,
+{
+    let expression = Expression {
+        atom,
+        prefix,
+        infix,
+        postfix,
+        fold,
+        max_depth,
+    };
+
+    move |input| {
+        let (remaining_input, value) = expression.parse_from(input, input, 0, 0, None)?;
+        Ok(ParsedItem::from_parts(remaining_input, value))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Expression<A, Pre, In, Post, F> {
+    atom: A,
+    prefix: Pre,
+    infix: In,
+    postfix: Post,
+    fold: F,
+    max_depth: usize,
+}
+
+type ExpressionResult<'input, T, E> = Result<
+    (&'input [u8], T),
+    Either4<E, error::MissingOperand, error::NonAssociativeOperator, error::ExpressionTooDeep>,
+>;
+
+impl<A, Pre, In, Post, F> Expression<A, Pre, In, Post, F> {
+    /// Parse an expression whose operators have a left binding power of at least `min_power`.
+    /// `operator_offset` is the offset of the operator whose operand is being parsed, if any.
+    fn parse_from<'input, O>(
+        self,
+        start: &'input [u8],
+        mut input: &'input [u8],
+        min_power: u16,
+        depth: usize,
+        operator_offset: Option<usize>,
+    ) -> ExpressionResult<'input, A::Output, A::Error>
+    where
+        A: Parser<'input>,
+        Pre: Parser<'input, Output = Prefix<O>>,
+        In: Parser<'input, Output = Infix<O>>,
+        Post: Parser<'input, Output = Postfix<O>>,
+        F: Fn(Operation<O, A::Output>) -> A::Output + Copy,
+    {
+        let offset_of = |input: &[u8]| start.len() - input.len();
+
+        if depth > self.max_depth {
+            return Err(Either4::D(error::ExpressionTooDeep {
+                offset: operator_offset.unwrap_or_else(|| offset_of(input)),
+            }));
+        }
+
+        let mut lhs = if let Ok(parsed_item) = self.prefix.parse(input) {
+            let offset = offset_of(input);
+            let (remaining_input, Prefix { op, power }) = parsed_item.into_parts();
+            let (remaining_input, operand) = self.parse_from(
+                start,
+                remaining_input,
+                u16::from(power) * 2 + 1,
+                depth + 1,
+                Some(offset),
+            )?;
+            input = remaining_input;
+            (self.fold)(Operation::Prefix(op, operand))
+        } else {
+            match self.atom.parse(input) {
+                Ok(parsed_item) => {
+                    let (remaining_input, value) = parsed_item.into_parts();
+                    input = remaining_input;
+                    value
+                }
+                Err(err) => {
+                    return Err(match operator_offset {
+                        Some(offset) => Either4::B(error::MissingOperand { offset }),
+                        None => Either4::A(err),
+                    });
+                }
+            }
+        };
+
+        // The binding power of the most recently folded operator if it is non-associative.
+        let mut non_associative_power = None;
+
+        loop {
+            if let Ok(parsed_item) = self.postfix.parse(input) {
+                let (remaining_input, Postfix { op, power }) = parsed_item.into_parts();
+                if u16::from(power) * 2 < min_power {
+                    break;
+                }
+                input = remaining_input;
+                lhs = (self.fold)(Operation::Postfix(lhs, op));
+                non_associative_power = None;
+                continue;
+            }
+
+            let Ok(parsed_item) = self.infix.parse(input) else {
+                break;
+            };
+            let offset = offset_of(input);
+            let (
+                remaining_input,
+                Infix {
+                    op,
+                    power,
+                    associativity,
+                },
+            ) = parsed_item.into_parts();
+            let (left_power, right_power) = match associativity {
+                Associativity::Left | Associativity::None => {
+                    (u16::from(power) * 2, u16::from(power) * 2 + 1)
+                }
+                Associativity::Right => (u16::from(power) * 2 + 1, u16::from(power) * 2),
+            };
+            if left_power < min_power {
+                break;
+            }
+            if associativity == Associativity::None && non_associative_power == Some(power) {
+                return Err(Either4::C(error::NonAssociativeOperator { offset }));
+            }
+
+            let (remaining_input, rhs) =
+                self.parse_from(start, remaining_input, right_power, depth + 1, Some(offset))?;
+            input = remaining_input;
+            lhs = (self.fold)(Operation::Infix(lhs, op, rhs));
+            non_associative_power = (associativity == Associativity::None).then_some(power);
+        }
+
+        Ok((input, lhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{any_byte, ascii, byte};
+    use crate::Either;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Add,
+        Sub,
+        Mul,
+        Pow,
+        Neg,
+        Fact,
+        Eq,
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn prefix(
+        input: &[u8],
+    ) -> ParserResult<'_, Prefix<Op>, Either<error::Byte, error::EndOfInput>> {
+        byte(b'-').map(|_| Prefix::new(Op::Neg, 3)).parse(input)
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn infix(input: &[u8]) -> ParserResult<'_, Infix<Op>, error::EndOfInput> {
+        any_byte
+            .filter_map(|b| match b {
+                b'+' => Ok(Infix::left(Op::Add, 1)),
+                b'-' => Ok(Infix::left(Op::Sub, 1)),
+                b'*' => Ok(Infix::left(Op::Mul, 2)),
+                b'^' => Ok(Infix::right(Op::Pow, 4)),
+                b'=' => Ok(Infix::non_associative(Op::Eq, 0)),
+                _ => Err(()),
+            })
+            .map_err(|_| error::EndOfInput)
+            .parse(input)
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn postfix(
+        input: &[u8],
+    ) -> ParserResult<'_, Postfix<Op>, Either<error::Byte, error::EndOfInput>> {
+        byte(b'!').map(|_| Postfix::new(Op::Fact, 5)).parse(input)
+    }
+
+    /// Evaluate the operation.
+    #[cfg_attr(coverage, coverage(off))]
+    fn eval(operation: Operation<Op, i64>) -> i64 {
+        match operation {
+            Operation::Prefix(Op::Neg, x) => -x,
+            Operation::Infix(a, Op::Add, b) => a + b,
+            Operation::Infix(a, Op::Sub, b) => a - b,
+            Operation::Infix(a, Op::Mul, b) => a * b,
+            Operation::Infix(a, Op::Pow, b) => a.pow(b as u32),
+            Operation::Infix(a, Op::Eq, b) => i64::from(a == b),
+            Operation::Postfix(x, Op::Fact) => (1..=x).product(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_expression_precedence() {
+        let parser = expression(ascii::int::<i64>, prefix, infix, postfix, eval, 16);
+
+        assert_eq!(
+            parser.parse(b"1-2-3").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), -4))
+        );
+        assert_eq!(
+            parser.parse(b"2^3^2").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 512))
+        );
+        assert_eq!(
+            parser.parse(b"1+2*3x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), 7))
+        );
+        assert_eq!(
+            parser.parse(b"-2^2").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), -4))
+        );
+        assert_eq!(
+            parser.parse(b"-2*3").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), -6))
+        );
+        assert_eq!(
+            parser.parse(b"2*3!").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 12))
+        );
+        assert_eq!(
+            parser.parse(b"1+1=2").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_expression_no_operators() {
+        let parser = expression(
+            ascii::int::<i64>,
+            no_operators,
+            infix,
+            no_operators,
+            eval,
+            16,
+        );
+
+        assert_eq!(
+            parser.parse(b"1+2!").map(ParsedItem::into_parts),
+            Ok((b"!".as_ref(), 3))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_expression_errors() {
+        let parser = expression(ascii::int::<i64>, prefix, infix, postfix, eval, 16);
+
+        assert_eq!(
+            parser.parse(b"x"),
+            Err(Either4::A(Either::A(error::AsciiInteger)))
+        );
+        assert_eq!(
+            parser.parse(b"1+2*"),
+            Err(Either4::B(error::MissingOperand { offset: 3 }))
+        );
+        assert_eq!(
+            parser.parse(b"1=1=1"),
+            Err(Either4::C(error::NonAssociativeOperator { offset: 3 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_expression_depth() {
+        let parser = expression(ascii::int::<i64>, prefix, infix, postfix, eval, 2);
+
+        assert_eq!(
+            parser.parse(b"--1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 1))
+        );
+        assert_eq!(
+            parser.parse(b"---1"),
+            Err(Either4::D(error::ExpressionTooDeep { offset: 2 }))
+        );
+        // Left-associative chains do not increase the depth.
+        assert_eq!(
+            parser.parse(b"1+1+1+1+1").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 5))
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Indentation-sensitive parsing.
+//!
+//! Parsers are stateless, so the indentation of the enclosing block is passed explicitly. A
+//! [`block`] provides its own indentation to the parser for each of its items, allowing items to
+//! contain nested blocks.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::Either4;
+#[cfg(feature = "alloc")]
+use crate::Parser;
+use crate::{error, ParsedItem, ParserResult};
+
+/// Split the input into its leading spaces and tabs and the remainder of the input.
+fn split_indentation(input: &[u8]) -> (&[u8], &[u8]) {
+    let width = input
+        .iter()
+        .position(|&b| b != b' ' && b != b'\t')
+        .unwrap_or(input.len());
+    input.split_at(width)
+}
+
+/// The position of the first byte of the indentation that is not `expected`. If no byte is
+/// expected, the first byte of the indentation is used.
+fn mixed_at(indentation: &[u8], expected: Option<u8>) -> Option<usize> {
+    let expected = expected.or_else(|| indentation.first().copied())?;
+    indentation.iter().position(|&b| b != expected)
+}
+
+/// Consume the indentation at the start of a line, outputting the indentation.
+///
+/// Indentation may consist of spaces or tabs, but not both.
+pub fn indent(input: &[u8]) -> ParserResult<'_, &[u8], error::MixedIndentation> {
+    let (indentation, remaining_input) = split_indentation(input);
+    if let Some(offset) = mixed_at(indentation, None) {
+        return Err(error::MixedIndentation { offset });
+    }
+    Ok(ParsedItem::from_parts(remaining_input, indentation))
+}
+
+/// Skip any lines that contain only indentation, returning the input at the start of the first
+/// line with content.
+#[cfg(feature = "alloc")]
+fn skip_blank_lines(mut input: &[u8]) -> &[u8] {
+    loop {
+        match split_indentation(input).1 {
+            [b'\n', rest @ ..] | [b'\r', b'\n', rest @ ..] => input = rest,
+            _ => return input,
+        }
+    }
+}
+
+/// Parse a block of one or more items, each on its own line, that is indented further than its
+/// parent.
+///
+/// The indentation of the first line determines the indentation of the block, which is provided
+/// to `item` to construct the parser for each item. Items must consume their line ending. The
+/// block ends at the first line that is indented less than the block or at the end of input. Lines
+/// containing only indentation are skipped.
+///
+/// The indentation of the block must begin with the indentation of its parent, and every item in
+/// the block must have identical indentation. A line that is indented further than the block
+/// without being part of an item is an error.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn block<'input, F, P>(
+    parent: &'input [u8],
+    item: F,
+) -> impl Parser<
+    'input,
+    Output = Vec<P::Output>,
+    Error = Either4<
+        error::MixedIndentation,
+        error::InsufficientIndentation,
+        error::InconsistentIndentation,
+        P::Error,
+    >,
+>
+where
+    F: Fn(&'input [u8]) -> P + Copy,
+    P: Parser<'input>,
+{
+    move |original_input: &'input [u8]| {
+        let line = skip_blank_lines(original_input);
+        let (indentation, remaining_input) = split_indentation(line);
+        // Indentation consists of a single repeated byte, so this also ensures that the
+        // indentation begins with the indentation of the parent.
+        if let Some(position) = mixed_at(indentation, parent.first().copied()) {
+            return Err(Either4::A(error::MixedIndentation {
+                offset: original_input.len() - line.len() + position,
+            }));
+        }
+        if indentation.len() <= parent.len() {
+            return Err(Either4::B(error::InsufficientIndentation));
+        }
+
+        let block_indentation = indentation;
+        let parser = item(block_indentation);
+        let mut output = Vec::new();
+        let (mut input, value) = parser.parse(remaining_input).map_err(Either4::D)?.into_parts();
+        output.push(value);
+
+        loop {
+            let line = skip_blank_lines(input);
+            let (indentation, remaining_input) = split_indentation(line);
+            if remaining_input.is_empty() {
+                break;
+            }
+            if let Some(position) = mixed_at(indentation, block_indentation.first().copied()) {
+                return Err(Either4::A(error::MixedIndentation {
+                    offset: original_input.len() - line.len() + position,
+                }));
+            }
+            if indentation.len() < block_indentation.len() {
+                break;
+            }
+            if indentation.len() > block_indentation.len() {
+                return Err(Either4::C(error::InconsistentIndentation));
+            }
+
+            let (remaining_input, value) =
+                parser.parse(remaining_input).map_err(Either4::D)?.into_parts();
+            input = remaining_input;
+            output.push(value);
+        }
+
+        Ok(ParsedItem::from_parts(input, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use alloc::vec;
+
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::parser::{ascii, byte};
+    #[cfg(feature = "alloc")]
+    use crate::Error;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_indent() {
+        assert_eq!(
+            indent(b"    a").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"    ".as_ref()))
+        );
+        assert_eq!(
+            indent(b"\t\ta").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"\t\t".as_ref()))
+        );
+        assert_eq!(
+            indent(b"a").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"".as_ref()))
+        );
+        assert_eq!(indent(b"  \ta"), Err(error::MixedIndentation { offset: 2 }));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, PartialEq, Eq)]
+    struct Node {
+        name: char,
+        children: Vec<Self>,
+    }
+
+    /// A name on its own line, optionally followed by a block of children.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(coverage, coverage(off))]
+    fn node<'a>(indentation: &'a [u8], input: &'a [u8]) -> ParserResult<'a, Node, Error> {
+        ascii::alphabetic
+            .and(byte(b'\n'))
+            .map_err(Error::from)
+            .and_infallible(
+                block(indentation, |indentation| {
+                    move |input| node(indentation, input)
+                })
+                .optional(),
+            )
+            .map(|((name, _), children)| Node {
+                name,
+                children: children.unwrap_or_default(),
+            })
+            .parse(input)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_block() {
+        let parser = block(b"", |indentation| move |input| node(indentation, input));
+        let leaf = |name| Node {
+            name,
+            children: Vec::new(),
+        };
+
+        assert_eq!(
+            parser
+                .parse(b"  a\n    b\n\n    c\n  d\ne\n")
+                .map(ParsedItem::into_parts),
+            Ok((
+                b"e\n".as_ref(),
+                vec![
+                    Node {
+                        name: 'a',
+                        children: vec![leaf('b'), leaf('c')],
+                    },
+                    leaf('d'),
+                ]
+            ))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_block_invalid() {
+        let parser = block(b"  ", |indentation| move |input| node(indentation, input));
+
+        assert_eq!(
+            parser.parse(b"  a\n"),
+            Err(Either4::B(error::InsufficientIndentation))
+        );
+        assert_eq!(
+            parser.parse(b" a\n"),
+            Err(Either4::B(error::InsufficientIndentation))
+        );
+        assert_eq!(
+            parser.parse(b"\n \ta\n"),
+            Err(Either4::A(error::MixedIndentation { offset: 2 }))
+        );
+        assert_eq!(
+            parser.parse(b"\t\t\ta\n"),
+            Err(Either4::A(error::MixedIndentation { offset: 0 }))
+        );
+        assert_eq!(
+            block(b"", |_| ascii::alphabetic.and(byte(b'\n'))).parse(b"  a\n    b\n"),
+            Err(Either4::C(error::InconsistentIndentation))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_block_tabs_after_spaces() {
+        // Three tabs are wider than two spaces when measured in bytes, but do not begin with the
+        // indentation of the preceding line.
+        let parser = block(b"", |indentation| move |input| node(indentation, input));
+
+        assert_eq!(
+            parser.parse(b"  a\n\t\t\tb\n"),
+            Err(Either4::A(error::MixedIndentation { offset: 4 }))
+        );
+        assert_eq!(
+            parser.parse(b"  a\n  \tb\n"),
+            Err(Either4::A(error::MixedIndentation { offset: 6 }))
+        );
+    }
+}
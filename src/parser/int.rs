@@ -1,3 +1,4 @@
+use core::mem::size_of;
 use core::{mem, ptr};
 
 use crate::parser::n_bytes;
@@ -7,7 +8,7 @@ pub fn int_be<T>(input: &[u8]) -> ParserResult<'_, T, error::EndOfInput>
 where
     T: Integer,
 {
-    n_bytes(mem::size_of::<T>())
+    n_bytes(size_of::<T>())
         .map(|bytes| {
             // Safety: `n_bytes` guarantees that exactly `size_of::<T>()` items are read.
             let bytes = unsafe { *(ptr::from_ref(bytes).cast::<T::Array>()) };
@@ -20,7 +21,7 @@ pub fn int_le<T>(input: &[u8]) -> ParserResult<'_, T, error::EndOfInput>
 where
     T: Integer,
 {
-    n_bytes(mem::size_of::<T>())
+    n_bytes(size_of::<T>())
         .map(|bytes| {
             // Safety: `n_bytes` guarantees that exactly `size_of::<T>()` items are read.
             let bytes = unsafe { *(ptr::from_ref(bytes).cast::<T::Array>()) };
@@ -33,7 +34,7 @@ pub fn int_ne<T>(input: &[u8]) -> ParserResult<'_, T, error::EndOfInput>
 where
     T: Integer,
 {
-    n_bytes(mem::size_of::<T>())
+    n_bytes(size_of::<T>())
         .map(|bytes| {
             // Safety: `n_bytes` guarantees that exactly `size_of::<T>()` items are read.
             let bytes = unsafe { *(ptr::from_ref(bytes).cast::<T::Array>()) };
@@ -0,0 +1,159 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::{error, Either, Parser};
+
+/// An iterator that repeatedly runs a parser, yielding each successful output.
+///
+/// Iteration ends when the parser fails or when it succeeds without consuming any input. Once
+/// iteration has ended, the remaining input and the error that terminated iteration (if any) are
+/// available. A parser that succeeds without consuming input is reported as
+/// [`error::NoProgress`].
+#[derive(Clone)]
+pub struct Iter<'input, P>
+where
+    P: Parser<'input>,
+{
+    parser: P,
+    input: &'input [u8],
+    error: Option<Either<P::Error, error::NoProgress>>,
+    is_finished: bool,
+}
+
+impl<'input, P> Iter<'input, P>
+where
+    P: Parser<'input>,
+{
+    pub(crate) const fn new(parser: P, input: &'input [u8]) -> Self {
+        Self {
+            parser,
+            input,
+            error: None,
+            is_finished: false,
+        }
+    }
+
+    /// The input that has not yet been consumed.
+    pub const fn input(&self) -> &'input [u8] {
+        self.input
+    }
+
+    /// The error that terminated iteration, if any.
+    pub const fn error(&self) -> Option<&Either<P::Error, error::NoProgress>> {
+        self.error.as_ref()
+    }
+
+    /// Obtain the remaining input and the error that terminated iteration, if any.
+    pub fn into_parts(self) -> (&'input [u8], Option<Either<P::Error, error::NoProgress>>) {
+        (self.input, self.error)
+    }
+}
+
+impl<'input, P> Iterator for Iter<'input, P>
+where
+    P: Parser<'input>,
+{
+    type Item = P::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+
+        match self.parser.parse(self.input) {
+            Ok(parsed_item) => {
+                let (remaining_input, value) = parsed_item.into_parts();
+                // A parser that does not consume input would otherwise yield the same value
+                // forever.
+                if remaining_input.len() == self.input.len() {
+                    self.error = Some(Either::B(error::NoProgress));
+                    self.is_finished = true;
+                    return None;
+                }
+                self.input = remaining_input;
+                Some(value)
+            }
+            Err(err) => {
+                self.error = Some(Either::A(err));
+                self.is_finished = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'input, P> FusedIterator for Iter<'input, P> where P: Parser<'input> {}
+
+impl<'input, P> fmt::Debug for Iter<'input, P>
+where
+    P: Parser<'input>,
+    P::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Iter")
+            .field("input", &self.input)
+            .field("error", &self.error)
+            .field("is_finished", &self.is_finished)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ascii, byte, success};
+    use crate::{error, Either, Parser as _};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_iter() {
+        let mut iter = ascii::digit.iter(b"123a");
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.input(), b"23a");
+        assert_eq!(iter.error(), None);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(
+            iter.into_parts(),
+            (b"a".as_ref(), Some(Either::A(Either::A(error::AsciiDigit))))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_iter_early_exit() {
+        let mut iter = byte(b'a').iter(b"aaaa");
+
+        assert_eq!(iter.by_ref().take(2).count(), 2);
+        assert_eq!(iter.input(), b"aa");
+        assert_eq!(iter.error(), None);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_iter_no_progress() {
+        let mut iter = success(()).iter(b"abc");
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.error(), Some(&Either::B(error::NoProgress)));
+        assert_eq!(
+            iter.into_parts(),
+            (b"abc".as_ref(), Some(Either::B(error::NoProgress)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_iter_end_of_input() {
+        let mut iter = byte(b'a').iter(b"a");
+
+        assert_eq!(iter.next(), Some(b'a'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(
+            iter.into_parts(),
+            (b"".as_ref(), Some(Either::A(Either::B(error::EndOfInput))))
+        );
+    }
+}
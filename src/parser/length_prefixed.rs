@@ -0,0 +1,114 @@
+use crate::{error, Either5, ParsedItem, Parser};
+
+/// Parse a length, then run the inner parser on exactly that many bytes.
+///
+/// The inner parser must consume every byte it is given. If the length is negative or cannot be
+/// represented as a `usize`, [`error::InvalidLength`] is returned. If the length exceeds the
+/// remaining input, [`error::EndOfInput`] is returned.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn length_prefixed<'input, L, P>(
+    length: L,
+    inner: P,
+) -> impl Parser<
+    'input,
+    Output = P::Output,
+    Error = Either5<
+        L::Error,
+        error::InvalidLength,
+        error::EndOfInput,
+        P::Error,
+        error::NotEndOfInput,
+    >,
+>
+where
+    L: Parser<'input>,
+    L::Output: TryInto<usize>,
+    P: Parser<'input>,
+{
+    move |original_input: &'input [u8]| {
+        let (input, length) = match length.parse(original_input) {
+            Ok(parsed_item) => parsed_item.into_parts(),
+            Err(err) => return Err(Either5::A(err)),
+        };
+        let length = match length.try_into() {
+            Ok(length) if length <= input.len() => length,
+            Ok(_) => return Err(Either5::C(error::EndOfInput)),
+            Err(_) => {
+                return Err(Either5::B(error::InvalidLength {
+                    offset: original_input.len() - input.len(),
+                }));
+            }
+        };
+
+        let (contents, remaining_input) = input.split_at(length);
+        let (unconsumed, value) = match inner.parse(contents) {
+            Ok(parsed_item) => parsed_item.into_parts(),
+            Err(err) => return Err(Either5::D(err)),
+        };
+        if !unconsumed.is_empty() {
+            return Err(Either5::E(error::NotEndOfInput));
+        }
+
+        Ok(ParsedItem::from_parts(remaining_input, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{ascii, int_be, verbatim};
+    use crate::Either;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_length_prefixed_valid() {
+        let parser = length_prefixed(int_be::<u16>, ascii::int::<u32>);
+
+        assert_eq!(
+            parser.parse(b"\x00\x03123456").map(ParsedItem::into_parts),
+            Ok((b"456".as_ref(), 123))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_length_prefixed_length_error() {
+        let parser = length_prefixed(int_be::<u16>, verbatim(b"ab"));
+
+        assert_eq!(parser.parse(b"\x00"), Err(Either5::A(error::EndOfInput)));
+        assert_eq!(
+            parser.parse(b"\x00\x03ab"),
+            Err(Either5::C(error::EndOfInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_length_prefixed_invalid_length() {
+        assert_eq!(
+            length_prefixed(int_be::<i8>, verbatim(b"ab")).parse(b"\xffab"),
+            Err(Either5::B(error::InvalidLength { offset: 1 }))
+        );
+        assert_eq!(
+            length_prefixed(int_be::<i16>, verbatim(b"ab")).parse(b"\x80\x00ab"),
+            Err(Either5::B(error::InvalidLength { offset: 2 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_length_prefixed_inner_error() {
+        let parser = length_prefixed(int_be::<u8>, verbatim(b"ab"));
+
+        // The inner parser is not permitted to read past the length.
+        assert_eq!(
+            parser.parse(b"\x01ab"),
+            Err(Either5::D(Either::B(error::EndOfInput)))
+        );
+        assert_eq!(
+            parser.parse(b"\x03abc"),
+            Err(Either5::E(error::NotEndOfInput))
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    error, Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6, Either7,
+    Either8, Either9, ParsedItem, Parser,
+};
+
+/// Attempt every parser, returning the output of the one that consumes the most input.
+///
+/// Parsers may be provided as an array or a tuple. For a tuple, the output indicates which parser
+/// succeeded and the error contains the errors from every parser in declaration order. For an
+/// array, the error is that of the first parser. If multiple parsers consume the same amount of
+/// input, the earliest one is chosen.
+pub fn longest<'input, T>(parsers: T) -> impl Parser<'input, Output = T::Output, Error = T::Error>
+where
+    T: Longest<'input>,
+{
+    move |input| Ok(parsers.parse_longest(input)?.0)
+}
+
+/// Attempt every parser, returning the output of the one that consumes the most input.
+///
+/// This behaves as [`longest`], except that it fails if multiple parsers consume the most input.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn longest_unambiguous<'input, T>(
+    parsers: T,
+) -> impl Parser<
+    'input,
+    Output = T::Output,
+    Error = Either<T::Error, error::AmbiguousMatch>,
+>
+where
+    T: Longest<'input>,
+{
+    move |input| match parsers.parse_longest(input).map_err(Either::A)? {
+        (parsed_item, None) => Ok(parsed_item),
+        (_, Some((first, second))) => Err(Either::B(error::AmbiguousMatch { first, second })),
+    }
+}
+
+/// The result of the longest match, along with the indices of the first two parsers that tied for
+/// it, if any.
+type LongestResult<'input, Output, Error> =
+    Result<(ParsedItem<'input, Output>, Option<(usize, usize)>), Error>;
+
+mod sealed {
+    use super::LongestResult;
+
+    pub trait Sealed<'input>: Copy {
+        type Output;
+        type Error;
+
+        fn parse_longest(
+            self,
+            input: &'input [u8],
+        ) -> LongestResult<'input, Self::Output, Self::Error>;
+    }
+}
+
+use self::sealed::Sealed;
+
+/// An array or tuple of parsers that are attempted by [`longest`] and [`longest_unambiguous`].
+pub trait Longest<'input>: Sealed<'input> {}
+
+/// Record a successful parse if it is at least as long as the best so far.
+fn track<'input, T>(
+    best: &mut Option<(usize, ParsedItem<'input, T>)>,
+    tie: &mut Option<(usize, usize)>,
+    index: usize,
+    parsed_item: ParsedItem<'input, T>,
+) {
+    match best {
+        Some((best_index, best_item)) => {
+            let remaining = parsed_item.input().len();
+            let best_remaining = best_item.input().len();
+            if remaining < best_remaining {
+                *best = Some((index, parsed_item));
+                *tie = None;
+            } else if remaining == best_remaining && tie.is_none() {
+                *tie = Some((*best_index, index));
+            }
+        }
+        None => *best = Some((index, parsed_item)),
+    }
+}
+
+impl<'input, const N: usize, P> Sealed<'input> for [P; N]
+where
+    P: Parser<'input>,
+{
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse_longest(self, input: &'input [u8]) -> LongestResult<'input, P::Output, P::Error> {
+        // Prevent compilation if the array is empty, as in `first_success`.
+        struct SizeAssertion<const N: usize>;
+        impl<const N: usize> SizeAssertion<N> {
+            const IS_VALID: () = assert!(N > 0);
+        }
+        #[allow(path_statements, clippy::no_effect)]
+        {
+            SizeAssertion::<N>::IS_VALID;
+        }
+
+        let mut best = None;
+        let mut tie = None;
+        let mut first_error = None;
+
+        for (index, parser) in self.into_iter().enumerate() {
+            match parser.parse(input) {
+                Ok(parsed_item) => track(&mut best, &mut tie, index, parsed_item),
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        match (best, first_error) {
+            (Some((_, parsed_item)), _) => Ok((parsed_item, tie)),
+            (None, Some(err)) => Err(err),
+            (None, None) => unreachable!("the array contains at least one parser"),
+        }
+    }
+}
+
+impl<'input, const N: usize, P> Longest<'input> for [P; N] where P: Parser<'input> {}
+
+macro_rules! impl_longest {
+    ($either:ident; $($parser:ident $err:ident $variant:ident $index:tt),+) => {
+        impl<'input, $($parser),+> Sealed<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {
+            type Output = $either<$($parser::Output),+>;
+            type Error = ($($parser::Error,)+);
+
+            fn parse_longest(
+                self,
+                input: &'input [u8],
+            ) -> LongestResult<'input, Self::Output, Self::Error> {
+                let mut best = None;
+                let mut tie = None;
+
+                $(let $err = match self.$index.parse(input) {
+                    Ok(parsed_item) => {
+                        let parsed_item = parsed_item.map_value($either::$variant);
+                        track(&mut best, &mut tie, $index, parsed_item);
+                        None
+                    }
+                    Err(err) => Some(err),
+                };)+
+
+                match (best, ($($err,)+)) {
+                    (Some((_, parsed_item)), _) => Ok((parsed_item, tie)),
+                    (None, ($(Some($err),)+)) => Err(($($err,)+)),
+                    (None, _) => unreachable!("a parser failed without an error"),
+                }
+            }
+        }
+
+        impl<'input, $($parser),+> Longest<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {}
+    };
+}
+
+impl_longest!(Either; P0 e0 A 0, P1 e1 B 1);
+impl_longest!(Either3; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2);
+impl_longest!(Either4; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3);
+impl_longest!(Either5; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4);
+impl_longest!(Either6; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5);
+impl_longest!(Either7; P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6);
+impl_longest!(
+    Either8;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7
+);
+impl_longest!(
+    Either9;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8
+);
+impl_longest!(
+    Either10;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9
+);
+impl_longest!(
+    Either11;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9, P10 e10 K 10
+);
+impl_longest!(
+    Either12;
+    P0 e0 A 0, P1 e1 B 1, P2 e2 C 2, P3 e3 D 3, P4 e4 E 4, P5 e5 F 5, P6 e6 G 6, P7 e7 H 7,
+    P8 e8 I 8, P9 e9 J 9, P10 e10 K 10, P11 e11 L 11
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{ascii, byte, verbatim};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_longest_array() {
+        let parser = longest([verbatim(b"a"), verbatim(b"b"), verbatim(b"ab")]);
+
+        assert_eq!(
+            parser.parse(b"abc").map(ParsedItem::into_parts),
+            Ok((b"c".as_ref(), b"ab".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"ac").map(ParsedItem::into_parts),
+            Ok((b"c".as_ref(), b"a".as_ref()))
+        );
+        assert_eq!(parser.parse(b"c"), Err(Either::A(error::NonMatchingInput)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_longest_tuple() {
+        let parser = longest((byte(b'1'), ascii::digit, verbatim(b"12")));
+
+        assert_eq!(
+            parser.parse(b"12").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), Either3::C(b"12".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"13").map(ParsedItem::into_parts),
+            Ok((b"3".as_ref(), Either3::A(b'1')))
+        );
+        assert_eq!(
+            parser.parse(b"xy"),
+            Err((
+                Either::A(error::Byte),
+                Either::A(error::AsciiDigit),
+                Either::A(error::NonMatchingInput)
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_longest_unambiguous() {
+        let parser = longest_unambiguous([verbatim(b"a"), verbatim(b"b"), verbatim(b"ab")]);
+
+        assert_eq!(
+            parser.parse(b"ab").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"ab".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"c"),
+            Err(Either::A(Either::A(error::NonMatchingInput)))
+        );
+
+        let parser = longest_unambiguous((byte(b'1'), ascii::digit, verbatim(b"12")));
+        assert_eq!(
+            parser.parse(b"13"),
+            Err(Either::B(error::AmbiguousMatch {
+                first: 0,
+                second: 1
+            }))
+        );
+        assert_eq!(
+            parser.parse(b"12").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), Either3::C(b"12".as_ref())))
+        );
+    }
+}
@@ -1,25 +1,93 @@
+mod alt;
 mod any_byte;
+mod any_utf8_char;
 pub mod ascii;
 mod byte;
+mod byte_range;
+mod byte_set;
+mod case;
+#[cfg(feature = "alloc")]
+mod count_prefixed;
 mod end_of_input;
+mod escaped;
+#[cfg(feature = "alloc")]
+mod escaped_transform;
+pub mod expression;
 mod first_success;
+pub mod indentation;
 mod int;
+mod iter;
+mod length_prefixed;
+mod longest;
 mod n_bytes;
+mod none_of;
+mod one_of;
+mod permutation;
+mod sequence;
 mod success;
+mod take_till;
+mod take_until;
+mod take_until_bytes;
+mod take_while;
+mod take_while1;
+mod take_while_in;
+mod take_while_n;
 mod trait_def;
+mod trivia;
+pub mod unicode;
+mod utf8;
 mod utf8_char;
+mod utf8_char_where;
+mod utf8_str;
+mod utf8_str_ignore_case;
+mod utf8_take_while;
 mod verbatim;
+mod verbatim_ignore_ascii_case;
+mod verbatim_with_case;
 
 pub use utf8_char::utf8_char;
 
+pub use self::alt::{alt, choice, Alt, Choice};
 pub use self::any_byte::any_byte;
+pub use self::any_utf8_char::any_utf8_char;
 pub use self::byte::byte;
+pub use self::byte_range::byte_range;
+pub use self::byte_set::ByteSet;
+pub use self::case::Case;
+#[cfg(feature = "alloc")]
+pub use self::count_prefixed::count_prefixed;
 pub use self::end_of_input::end_of_input;
+pub use self::escaped::escaped;
+#[cfg(feature = "alloc")]
+pub use self::escaped_transform::{escaped_transform, escaped_transform_str, Unescaped};
+pub use self::expression::expression;
 pub use self::first_success::first_success;
 pub use self::int::{int_be, int_le, int_ne};
+pub use self::iter::Iter;
+pub use self::length_prefixed::length_prefixed;
+pub use self::longest::{longest, longest_unambiguous, Longest};
 pub use self::n_bytes::n_bytes;
+pub use self::none_of::none_of;
+pub use self::one_of::one_of;
+pub use self::permutation::{permutation, permutation_optional, Optional, Permutation};
+pub use self::sequence::{sequence, Sequence};
 pub use self::success::success;
+pub use self::take_till::take_till;
+pub use self::take_until::take_until;
+pub use self::take_until_bytes::take_until_bytes;
+pub use self::take_while::take_while;
+pub use self::take_while1::take_while1;
+pub use self::take_while_in::take_while_in;
+pub use self::take_while_n::take_while_n;
 pub use self::trait_def::Parser;
+pub use self::trivia::{trivia, Trivia};
+pub use self::utf8::Utf8;
+pub use self::utf8_char_where::utf8_char_where;
+pub use self::utf8_str::utf8_str;
+pub use self::utf8_str_ignore_case::utf8_str_ignore_case;
+pub use self::utf8_take_while::utf8_take_while;
 pub use self::verbatim::verbatim;
+pub use self::verbatim_ignore_ascii_case::verbatim_ignore_ascii_case;
+pub use self::verbatim_with_case::verbatim_with_case;
 
 pub type ParserResult<'input, Output, Error> = Result<crate::ParsedItem<'input, Output>, Error>;
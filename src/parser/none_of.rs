@@ -0,0 +1,34 @@
+use crate::parser::{one_of, ByteSet};
+use crate::{error, Either, Parser};
+
+/// Consume a byte that is not in the set.
+pub fn none_of(
+    set: impl Into<ByteSet>,
+) -> impl for<'input> Parser<'input, Output = u8, Error = Either<error::Byte, error::EndOfInput>> {
+    one_of(set.into().complement())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_none_of_valid() {
+        assert_eq!(
+            none_of(b"\"\\").parse(b"a\"").map(ParsedItem::into_parts),
+            Ok((b"\"".as_ref(), b'a'))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_none_of_invalid() {
+        assert_eq!(none_of(b"\"\\").parse(b"\\"), Err(Either::A(error::Byte)));
+        assert_eq!(
+            none_of(b"\"\\").parse(b""),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
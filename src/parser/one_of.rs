@@ -0,0 +1,39 @@
+use crate::parser::{any_byte, ByteSet};
+use crate::{error, hrtb_hack, Either, Parser};
+
+/// Consume a byte that is in the set.
+pub fn one_of(
+    set: impl Into<ByteSet>,
+) -> impl for<'input> Parser<'input, Output = u8, Error = Either<error::Byte, error::EndOfInput>> {
+    let set = set.into();
+    hrtb_hack(move |input| {
+        any_byte
+            .filter_map(|b| set.contains(b).then_some(b).ok_or(error::Byte))
+            .parse(input)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_one_of_valid() {
+        assert_eq!(
+            one_of(b"+-*/").parse(b"*1").map(ParsedItem::into_parts),
+            Ok((b"1".as_ref(), b'*'))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_one_of_invalid() {
+        assert_eq!(one_of(b"+-*/").parse(b"1"), Err(Either::A(error::Byte)));
+        assert_eq!(
+            one_of(b"+-*/").parse(b""),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{error, Either, ParsedItem, Parser, ParserResult};
+
+/// Parse every member of the tuple exactly once, in any order.
+///
+/// At each position, members that have not yet matched are attempted in declaration order. Parsing
+/// stops once every member has matched or no remaining member matches. The output contains the
+/// value of each member in declaration order, regardless of the order in which they appeared in the
+/// input.
+///
+/// Members wrapped in [`permutation_optional`] may be absent, with their value being `None`. If a
+/// required member is absent, the error indicates its index. If a member that has already matched
+/// appears again while a required member is still absent, the error indicates the index of that
+/// member.
+pub fn permutation<'input, T>(
+    members: T,
+) -> impl Parser<
+    'input,
+    Output = T::Output,
+    Error = Either<error::PermutationMissing, error::PermutationDuplicate>,
+>
+where
+    T: Permutation<'input>,
+{
+    move |input| members.parse_permutation(input)
+}
+
+/// Mark a member of a [`permutation`] as optional.
+pub const fn permutation_optional<'input, P>(parser: P) -> Optional<P>
+where
+    P: Parser<'input>,
+{
+    Optional(parser)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Optional<P>(P);
+
+mod sealed {
+    use super::*;
+
+    pub trait Member<'input>: Copy {
+        type Value;
+        type Output;
+
+        /// Whether the member must be present.
+        const REQUIRED: bool;
+
+        fn parse_member(self, input: &'input [u8]) -> Option<ParsedItem<'input, Self::Value>>;
+        fn finish(value: Option<Self::Value>) -> Option<Self::Output>;
+    }
+
+    impl<'input, P> Member<'input> for P
+    where
+        P: Parser<'input>,
+    {
+        type Value = P::Output;
+        type Output = P::Output;
+
+        const REQUIRED: bool = true;
+
+        fn parse_member(self, input: &'input [u8]) -> Option<ParsedItem<'input, Self::Value>> {
+            self.parse(input).ok()
+        }
+
+        fn finish(value: Option<Self::Value>) -> Option<Self::Output> {
+            value
+        }
+    }
+
+    impl<'input, P> Member<'input> for Optional<P>
+    where
+        P: Parser<'input>,
+    {
+        type Value = P::Output;
+        type Output = Option<P::Output>;
+
+        const REQUIRED: bool = false;
+
+        fn parse_member(self, input: &'input [u8]) -> Option<ParsedItem<'input, Self::Value>> {
+            self.0.parse(input).ok()
+        }
+
+        fn finish(value: Option<Self::Value>) -> Option<Self::Output> {
+            Some(value)
+        }
+    }
+
+    pub trait Sealed<'input>: Copy {
+        type Output;
+
+        #[allow(clippy::type_complexity)]
+        fn parse_permutation(
+            self,
+            input: &'input [u8],
+        ) -> ParserResult<
+            'input,
+            Self::Output,
+            Either<error::PermutationMissing, error::PermutationDuplicate>,
+        >;
+    }
+}
+
+use self::sealed::{Member, Sealed};
+
+pub trait Permutation<'input>: Sealed<'input> {}
+
+macro_rules! impl_permutation {
+    ($($member:ident $value:ident $index:tt),+) => {
+        impl<'input, $($member),+> Sealed<'input> for ($($member,)+)
+        where
+            $($member: Member<'input>),+
+        {
+            type Output = ($($member::Output,)+);
+
+            fn parse_permutation(
+                self,
+                mut input: &'input [u8],
+            ) -> ParserResult<
+                'input,
+                Self::Output,
+                Either<error::PermutationMissing, error::PermutationDuplicate>,
+            > {
+                $(let mut $value = None;)+
+
+                'outer: loop {
+                    $(if $value.is_none() {
+                        if let Some(parsed_item) = self.$index.parse_member(input) {
+                            let (remaining_input, value) = parsed_item.into_parts();
+                            input = remaining_input;
+                            $value = Some(value);
+                            continue 'outer;
+                        }
+                    })+
+                    break;
+                }
+
+                // If a required member is missing, a member that has already matched may be the
+                // reason parsing stopped. Otherwise the remaining input is left for the next parser.
+                if $(($member::REQUIRED && $value.is_none()))||+ {
+                    $(if $value.is_some() && self.$index.parse_member(input).is_some() {
+                        return Err(Either::B(error::PermutationDuplicate { index: $index }));
+                    })+
+                }
+
+                Ok(ParsedItem::from_parts(input, ($(
+                    $member::finish($value)
+                        .ok_or(Either::A(error::PermutationMissing { index: $index }))?,
+                )+)))
+            }
+        }
+
+        impl<'input, $($member),+> Permutation<'input> for ($($member,)+)
+        where
+            $($member: Member<'input>),+
+        {}
+    };
+}
+
+impl_permutation!(P0 v0 0, P1 v1 1);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2, P3 v3 3);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2, P3 v3 3, P4 v4 4);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2, P3 v3 3, P4 v4 4, P5 v5 5);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2, P3 v3 3, P4 v4 4, P5 v5 5, P6 v6 6);
+impl_permutation!(P0 v0 0, P1 v1 1, P2 v2 2, P3 v3 3, P4 v4 4, P5 v5 5, P6 v6 6, P7 v7 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{byte, verbatim};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_permutation_valid() {
+        let parser = permutation((byte(b'a'), byte(b'b'), verbatim(b"cd")));
+
+        assert_eq!(
+            parser.parse(b"abcd").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (b'a', b'b', b"cd".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"cdbax").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), (b'a', b'b', b"cd".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"bcda").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (b'a', b'b', b"cd".as_ref())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_permutation_optional() {
+        let parser = permutation((byte(b'a'), permutation_optional(byte(b'b'))));
+
+        assert_eq!(
+            parser.parse(b"bax").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), (b'a', Some(b'b'))))
+        );
+        assert_eq!(
+            parser.parse(b"ax").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), (b'a', None)))
+        );
+        assert_eq!(
+            parser.parse(b"b"),
+            Err(Either::A(error::PermutationMissing { index: 0 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_permutation_missing() {
+        let parser = permutation((byte(b'a'), byte(b'b'), byte(b'c')));
+
+        assert_eq!(
+            parser.parse(b"cb"),
+            Err(Either::A(error::PermutationMissing { index: 0 }))
+        );
+        assert_eq!(
+            parser.parse(b"ab"),
+            Err(Either::A(error::PermutationMissing { index: 2 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_permutation_duplicate() {
+        let parser = permutation((byte(b'a'), byte(b'b'), byte(b'c')));
+
+        assert_eq!(
+            parser.parse(b"abb"),
+            Err(Either::B(error::PermutationDuplicate { index: 1 }))
+        );
+        assert_eq!(
+            permutation((byte(b'a'), permutation_optional(byte(b'b')), byte(b'c'))).parse(b"aa"),
+            Err(Either::B(error::PermutationDuplicate { index: 0 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_permutation_optional_absent_before_repeat() {
+        let parser = permutation((byte(b'a'), permutation_optional(byte(b'b'))));
+
+        assert_eq!(
+            parser.parse(b"aa").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), (b'a', None)))
+        );
+        assert_eq!(
+            parser
+                .and(byte(b'a'))
+                .parse(b"aa")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), ((b'a', None), b'a')))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    Either, Either10, Either11, Either12, Either3, Either4, Either5, Either6, Either7, Either8,
+    Either9, ParsedItem, Parser, ParserResult,
+};
+
+/// Run each parser in the tuple in order, outputting a tuple of their values.
+///
+/// If any parser fails, its error is returned, with the variant indicating which parser failed.
+pub fn sequence<'input, T>(parsers: T) -> impl Parser<'input, Output = T::Output, Error = T::Error>
+where
+    T: Sequence<'input>,
+{
+    move |input| parsers.parse_sequence(input)
+}
+
+mod sealed {
+    use super::*;
+
+    pub trait Sealed<'input>: Copy {
+        type Output;
+        type Error;
+
+        fn parse_sequence(
+            self,
+            input: &'input [u8],
+        ) -> ParserResult<'input, Self::Output, Self::Error>;
+    }
+}
+
+use self::sealed::Sealed;
+
+/// A tuple of parsers that are run in order by [`sequence`].
+pub trait Sequence<'input>: Sealed<'input> {}
+
+macro_rules! impl_sequence {
+    ($either:ident; $($parser:ident $value:ident $variant:ident $index:tt),+) => {
+        impl<'input, $($parser),+> Sealed<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {
+            type Output = ($($parser::Output,)+);
+            type Error = $either<$($parser::Error),+>;
+
+            fn parse_sequence(
+                self,
+                input: &'input [u8],
+            ) -> ParserResult<'input, Self::Output, Self::Error> {
+                $(let (input, $value) = match self.$index.parse(input) {
+                    Ok(parsed_item) => parsed_item.into_parts(),
+                    Err(err) => return Err($either::$variant(err)),
+                };)+
+                Ok(ParsedItem::from_parts(input, ($($value,)+)))
+            }
+        }
+
+        impl<'input, $($parser),+> Sequence<'input> for ($($parser,)+)
+        where
+            $($parser: Parser<'input>),+
+        {}
+    };
+}
+
+impl_sequence!(Either; P0 v0 A 0, P1 v1 B 1);
+impl_sequence!(Either3; P0 v0 A 0, P1 v1 B 1, P2 v2 C 2);
+impl_sequence!(Either4; P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3);
+impl_sequence!(Either5; P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4);
+impl_sequence!(Either6; P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5);
+impl_sequence!(
+    Either7;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6
+);
+impl_sequence!(
+    Either8;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6, P7 v7 H 7
+);
+impl_sequence!(
+    Either9;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6, P7 v7 H 7,
+    P8 v8 I 8
+);
+impl_sequence!(
+    Either10;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6, P7 v7 H 7,
+    P8 v8 I 8, P9 v9 J 9
+);
+impl_sequence!(
+    Either11;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6, P7 v7 H 7,
+    P8 v8 I 8, P9 v9 J 9, P10 v10 K 10
+);
+impl_sequence!(
+    Either12;
+    P0 v0 A 0, P1 v1 B 1, P2 v2 C 2, P3 v3 D 3, P4 v4 E 4, P5 v5 F 5, P6 v6 G 6, P7 v7 H 7,
+    P8 v8 I 8, P9 v9 J 9, P10 v10 K 10, P11 v11 L 11
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error;
+    use crate::parser::{ascii, byte, int_be, int_le, verbatim};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_sequence_valid() {
+        let parser = sequence((byte(b'a'), verbatim(b"bc"), ascii::digit));
+
+        assert_eq!(
+            parser.parse(b"abc1x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), (b'a', b"bc".as_ref(), 1)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_sequence_binary_header() {
+        let parser = sequence((
+            verbatim(b"HDR"),
+            int_be::<u16>,
+            int_le::<u16>,
+            int_be::<u32>,
+        ));
+
+        assert_eq!(
+            parser
+                .parse(b"HDR\x00\x01\x02\x00\x00\x00\x00\x03")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (b"HDR".as_ref(), 1, 2, 3)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_sequence_invalid() {
+        let parser = sequence((byte(b'a'), verbatim(b"bc"), ascii::digit));
+
+        assert_eq!(parser.parse(b"x"), Err(Either3::A(Either::A(error::Byte))));
+        assert_eq!(
+            parser.parse(b"ab"),
+            Err(Either3::B(Either::B(error::EndOfInput)))
+        );
+        assert_eq!(
+            parser.parse(b"abcd"),
+            Err(Either3::C(Either::A(error::AsciiDigit)))
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use core::convert::Infallible;
+
+use crate::parser::take_while;
+use crate::Parser;
+
+/// Consume bytes until the predicate holds, outputting the consumed bytes. The output may be
+/// empty.
+pub fn take_till<'input, F>(
+    predicate: F,
+) -> impl Parser<'input, Output = &'input [u8], Error = Infallible>
+where
+    F: Fn(u8) -> bool + Copy,
+{
+    take_while(move |b| !predicate(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_till() {
+        let parser = take_till(
+            #[cfg_attr(coverage, coverage(off))]
+            |b| b == b'\n',
+        );
+
+        assert_eq!(
+            parser.parse(b"line\nnext").map(ParsedItem::into_parts),
+            Ok((b"\nnext".as_ref(), b"line".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"line").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"line".as_ref()))
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{ParsedItem, Parser};
+
+/// Consume bytes until the terminator parser succeeds.
+///
+/// The terminator is attempted at every position in the input, including at the end of input. The
+/// output is a tuple containing the bytes preceding the terminator and the output of the
+/// terminator. If the terminator never succeeds, the error from the final attempt is returned.
+pub fn take_until<'input, T>(
+    terminator: T,
+) -> impl Parser<'input, Output = (&'input [u8], T::Output), Error = T::Error>
+where
+    T: Parser<'input>,
+{
+    move |input: &'input [u8]| {
+        let mut offset = 0;
+
+        loop {
+            match terminator.parse(&input[offset..]) {
+                Ok(parsed_item) => {
+                    let (remaining_input, value) = parsed_item.into_parts();
+                    return Ok(ParsedItem::from_parts(
+                        remaining_input,
+                        (&input[..offset], value),
+                    ));
+                }
+                Err(err) if offset == input.len() => return Err(err),
+                Err(_) => offset += 1,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{end_of_input, verbatim};
+    use crate::{error, Either};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_until_success() {
+        let parser = take_until(verbatim(b"*/"));
+
+        assert_eq!(
+            parser.parse(b" comment */x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), (b" comment ".as_ref(), b"*/".as_ref())))
+        );
+        assert_eq!(
+            parser.parse(b"*/").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (b"".as_ref(), b"*/".as_ref())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_until_end_of_input() {
+        assert_eq!(
+            take_until(end_of_input)
+                .parse(b"abc")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), (b"abc".as_ref(), ())))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_until_error() {
+        assert_eq!(
+            take_until(verbatim(b"*/")).parse(b"abc*"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
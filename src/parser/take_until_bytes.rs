@@ -0,0 +1,71 @@
+use crate::{error, ParsedItem, Parser};
+
+/// Consume bytes until the needle is found, outputting the bytes preceding it. The needle is not
+/// consumed.
+///
+/// If the needle is not present in the input, an error is returned.
+pub fn take_until_bytes<'input>(
+    needle: &[u8],
+) -> impl Parser<'input, Output = &'input [u8], Error = error::EndOfInput> + '_ {
+    move |input: &'input [u8]| {
+        let offset = match needle {
+            [] => Some(0),
+            [first, rest @ ..] => {
+                let mut offset = 0;
+                loop {
+                    let Some(candidate) = input[offset..].iter().position(|b| b == first) else {
+                        break None;
+                    };
+                    let start = offset + candidate;
+                    if input.len() - start < needle.len() {
+                        break None;
+                    }
+                    if input[start + 1..start + needle.len()] == *rest {
+                        break Some(start);
+                    }
+                    offset = start + 1;
+                }
+            }
+        };
+
+        match offset {
+            Some(offset) => {
+                let (output, remaining_input) = input.split_at(offset);
+                Ok(ParsedItem::from_parts(remaining_input, output))
+            }
+            None => Err(error::EndOfInput),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_until_bytes_found() {
+        assert_eq!(
+            take_until_bytes(b"\r\n")
+                .parse(b"a\rb\r\nc")
+                .map(ParsedItem::into_parts),
+            Ok((b"\r\nc".as_ref(), b"a\rb".as_ref()))
+        );
+        assert_eq!(
+            take_until_bytes(b"")
+                .parse(b"abc")
+                .map(ParsedItem::into_parts),
+            Ok((b"abc".as_ref(), b"".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_until_bytes_missing() {
+        assert_eq!(
+            take_until_bytes(b"\r\n").parse(b"abc\r"),
+            Err(error::EndOfInput)
+        );
+        assert_eq!(take_until_bytes(b"\r\n").parse(b""), Err(error::EndOfInput));
+    }
+}
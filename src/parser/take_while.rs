@@ -0,0 +1,48 @@
+use core::convert::Infallible;
+
+use crate::{ParsedItem, Parser};
+
+/// Consume bytes while the predicate holds, outputting the consumed bytes. The output may be
+/// empty.
+pub fn take_while<'input, F>(
+    predicate: F,
+) -> impl Parser<'input, Output = &'input [u8], Error = Infallible>
+where
+    F: Fn(u8) -> bool + Copy,
+{
+    move |input: &'input [u8]| {
+        let len = input
+            .iter()
+            .position(|&b| !predicate(b))
+            .unwrap_or(input.len());
+        let (output, remaining_input) = input.split_at(len);
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while() {
+        let parser = take_while(
+            #[cfg_attr(coverage, coverage(off))]
+            |b| b.is_ascii_digit(),
+        );
+
+        assert_eq!(
+            parser.parse(b"123a").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"123".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"a").map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"123").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"123".as_ref()))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::parser::take_while_n;
+use crate::{error, Either, Parser};
+
+/// Consume at least one byte while the predicate holds, outputting the consumed bytes.
+pub fn take_while1<'input, F>(
+    predicate: F,
+) -> impl Parser<'input, Output = &'input [u8], Error = Either<error::Filter, error::EndOfInput>>
+where
+    F: Fn(u8) -> bool + Copy,
+{
+    take_while_n(1.., predicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn is_digit(b: u8) -> bool {
+        b.is_ascii_digit()
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while1() {
+        assert_eq!(
+            take_while1(is_digit)
+                .parse(b"12a")
+                .map(ParsedItem::into_parts),
+            Ok((b"a".as_ref(), b"12".as_ref()))
+        );
+        assert_eq!(
+            take_while1(is_digit).parse(b"a"),
+            Err(Either::A(error::Filter))
+        );
+        assert_eq!(
+            take_while1(is_digit).parse(b""),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
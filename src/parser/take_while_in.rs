@@ -0,0 +1,37 @@
+use core::convert::Infallible;
+
+use crate::parser::{take_while, ByteSet};
+use crate::Parser;
+
+/// Consume bytes while they are in the set, outputting the consumed bytes. The output may be empty.
+pub fn take_while_in<'input>(
+    set: impl Into<ByteSet>,
+) -> impl Parser<'input, Output = &'input [u8], Error = Infallible> {
+    let set = set.into();
+    take_while(move |b| set.contains(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while_in() {
+        let parser = take_while_in(ByteSet::range(b'0', b'9').with(b'_'));
+
+        assert_eq!(
+            parser.parse(b"1_000x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"1_000".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"".as_ref()))
+        );
+        assert_eq!(
+            parser.parse(b"123").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"123".as_ref()))
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::combinator::RepeatCount;
+use crate::{error, Either, ParsedItem, Parser};
+
+/// Consume bytes while the predicate holds, outputting the consumed bytes.
+///
+/// The number of bytes may be a `usize` or any range of `usize`. At most the maximum number of
+/// bytes are consumed. If fewer than the minimum number of bytes satisfy the predicate, an error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if the range of bytes is empty.
+pub fn take_while_n<'input, R, F>(
+    count: R,
+    predicate: F,
+) -> impl Parser<'input, Output = &'input [u8], Error = Either<error::Filter, error::EndOfInput>>
+where
+    R: RepeatCount,
+    F: Fn(u8) -> bool + Copy,
+{
+    let (min, max) = count.bounds();
+
+    move |input: &'input [u8]| {
+        let limit = max.map_or(input.len(), |max| max.min(input.len()));
+        let len = input[..limit]
+            .iter()
+            .position(|&b| !predicate(b))
+            .unwrap_or(limit);
+
+        if len < min {
+            return Err(if len == input.len() {
+                Either::B(error::EndOfInput)
+            } else {
+                Either::A(error::Filter)
+            });
+        }
+
+        let (output, remaining_input) = input.split_at(len);
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(coverage, coverage(off))]
+    fn is_hex(b: u8) -> bool {
+        b.is_ascii_hexdigit()
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while_n_success() {
+        assert_eq!(
+            take_while_n(2..=4, is_hex)
+                .parse(b"abcdef")
+                .map(ParsedItem::into_parts),
+            Ok((b"ef".as_ref(), b"abcd".as_ref()))
+        );
+        assert_eq!(
+            take_while_n(2.., is_hex)
+                .parse(b"abx")
+                .map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"ab".as_ref()))
+        );
+        assert_eq!(
+            take_while_n(..3, is_hex)
+                .parse(b"x")
+                .map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while_n_error() {
+        assert_eq!(
+            take_while_n(2, is_hex).parse(b"ax"),
+            Err(Either::A(error::Filter))
+        );
+        assert_eq!(
+            take_while_n(2, is_hex).parse(b"a"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_take_while_n_exact_range() {
+        assert_eq!(
+            take_while_n(2..=2, is_hex)
+                .parse(b"abc")
+                .map(ParsedItem::into_parts),
+            Ok((b"c".as_ref(), b"ab".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "repetition range must not be empty"]
+    fn test_take_while_n_empty_range() {
+        let _ = take_while_n(2..2, is_hex);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "repetition range must not be empty"]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_take_while_n_reversed_range() {
+        let _ = take_while_n(3..1, is_hex);
+    }
+}
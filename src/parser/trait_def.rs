@@ -3,13 +3,20 @@ use alloc::vec::Vec;
 use core::convert::Infallible;
 
 use crate::combinator::{
-    and, and_infallible, at_least_n_raw, at_most_n_raw, discard, discard_at_least_n, discard_while,
-    exactly_n_raw, filter, filter_map, inspect, inspect_err, map, map_err, n_to_m_raw, optional,
-    or, Combinator,
+    all_consuming, and, and_infallible, and_then, as_str, at_least_n_raw, at_most_n_raw,
+    chain_left, discard, discard_at_least_n, discard_while, exactly_n_raw, filter, filter_map,
+    inspect, inspect_err, lexeme, lexeme_with_trivia, map, map_err, n_to_m_raw, optional, or,
+    recognize, repeat_count, repeat_fold, repeat_raw, with_consumed, Combinator, RepeatCount,
 };
 #[cfg(feature = "alloc")]
-use crate::combinator::{at_least_n, at_most_n, consume_while, exactly_n, n_to_m};
-use crate::error::Filter;
+use crate::combinator::{
+    at_least_n, at_most_n, chain_right, consume_while, exactly_n, many_till, memoize,
+    memoize_left_recursive, n_to_m, repeat, Memo,
+};
+use crate::error::{Filter, InvalidUtf8, TrailingInput, UnterminatedComment};
+#[cfg(feature = "alloc")]
+use crate::error::{LeftRecursion, NoProgress};
+use crate::parser::{Iter, Trivia};
 use crate::{Either, ParserResult};
 
 /// A type that is capable of consuming input and producing a parsed item.
@@ -28,6 +35,13 @@ pub trait Parser<'input>: Copy {
 
     // Helper methods for parcom-provided combinators.
 
+    fn all_consuming(
+        self,
+    ) -> impl Parser<'input, Output = Self::Output, Error = Either<Self::Error, TrailingInput>>
+    {
+        move |input| self.with(&all_consuming).parse(input)
+    }
+
     #[rustfmt::skip] // rust-lang/rustfmt#3599
     fn and<P2>(
         self,
@@ -53,6 +67,26 @@ pub trait Parser<'input>: Copy {
         move |input| self.with(&and_infallible(other)).parse(input)
     }
 
+    fn and_then<P2, F>(
+        self,
+        f: F,
+    ) -> impl Parser<'input, Output = P2::Output, Error = Either<Self::Error, P2::Error>>
+    where
+        P2: Parser<'input>,
+        F: Fn(Self::Output) -> P2 + Copy,
+    {
+        move |input| self.with(&and_then(f)).parse(input)
+    }
+
+    fn as_str(
+        self,
+    ) -> impl Parser<'input, Output = &'input str, Error = Either<Self::Error, InvalidUtf8>>
+    where
+        Self: Parser<'input, Output = &'input [u8]>,
+    {
+        move |input| self.with(&as_str).parse(input)
+    }
+
     fn at_least_n_raw(
         self,
         n: usize,
@@ -83,6 +117,31 @@ pub trait Parser<'input>: Copy {
         move |input| self.with(&at_most_n(n)).parse(input)
     }
 
+    fn chain_left<O, F>(
+        self,
+        operator: O,
+        f: F,
+    ) -> impl Parser<'input, Output = Self::Output, Error = Self::Error>
+    where
+        O: Parser<'input>,
+        F: Fn(Self::Output, O::Output, Self::Output) -> Self::Output + Copy,
+    {
+        move |input| self.with(&chain_left(operator, f)).parse(input)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn chain_right<O, F>(
+        self,
+        operator: O,
+        f: F,
+    ) -> impl Parser<'input, Output = Self::Output, Error = Self::Error>
+    where
+        O: Parser<'input>,
+        F: Fn(Self::Output, O::Output, Self::Output) -> Self::Output + Copy,
+    {
+        move |input| self.with(&chain_right(operator, f)).parse(input)
+    }
+
     #[cfg(feature = "alloc")]
     fn consume_while<F>(
         self,
@@ -161,6 +220,52 @@ pub trait Parser<'input>: Copy {
         move |input| self.with(&inspect(f)).parse(input)
     }
 
+    fn iter(self, input: &'input [u8]) -> Iter<'input, Self> {
+        Iter::new(self, input)
+    }
+
+    #[rustfmt::skip] // rust-lang/rustfmt#3599
+    fn lexeme(
+        self,
+        trivia: Trivia<'_>,
+    ) -> impl Parser<
+        'input,
+        Output = Self::Output,
+        Error = Either<Self::Error, UnterminatedComment>,
+    > {
+        move |input| self.with(&lexeme(trivia)).parse(input)
+    }
+
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip] // rust-lang/rustfmt#3599
+    fn lexeme_with_trivia(
+        self,
+        trivia: Trivia<'_>,
+    ) -> impl Parser<
+        'input,
+        Output = (Self::Output, &'input [u8]),
+        Error = Either<Self::Error, UnterminatedComment>,
+    > {
+        move |input| self.with(&lexeme_with_trivia(trivia)).parse(input)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip] // rust-lang/rustfmt#3599
+    fn many_till<T>(
+        self,
+        terminator: T,
+    ) -> impl Parser<
+        'input,
+        Output = (Vec<Self::Output>, T::Output),
+        Error = (Either<Self::Error, NoProgress>, T::Error),
+    >
+    where
+        T: Parser<'input>,
+    {
+        move |input| self.with(&many_till(terminator)).parse(input)
+    }
+
     fn map<F, NewOutput>(self, f: F) -> impl Parser<'input, Output = NewOutput, Error = Self::Error>
     where
         F: Fn(Self::Output) -> NewOutput + Copy,
@@ -178,6 +283,32 @@ pub trait Parser<'input>: Copy {
         move |input| self.with(&map_err(f)).parse(input)
     }
 
+    #[cfg(feature = "alloc")]
+    fn memoize(
+        self,
+        memo: &Memo<'input, Self::Output, Self::Error>,
+        rule: usize,
+    ) -> impl Parser<'input, Output = Self::Output, Error = Self::Error>
+    where
+        Self::Output: Clone,
+        Self::Error: Clone,
+    {
+        move |input| self.with(&memoize(memo, rule)).parse(input)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn memoize_left_recursive(
+        self,
+        memo: &Memo<'input, Self::Output, Self::Error>,
+        rule: usize,
+    ) -> impl Parser<'input, Output = Self::Output, Error = Self::Error>
+    where
+        Self::Output: Clone,
+        Self::Error: Clone + From<LeftRecursion>,
+    {
+        move |input| self.with(&memoize_left_recursive(memo, rule)).parse(input)
+    }
+
     fn n_to_m_raw(
         self,
         n: usize,
@@ -213,6 +344,62 @@ pub trait Parser<'input>: Copy {
     {
         move |input| self.with(&or(other)).parse(input)
     }
+
+    fn recognize(self) -> impl Parser<'input, Output = &'input [u8], Error = Self::Error> {
+        move |input| self.with(&recognize).parse(input)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn repeat<R>(
+        self,
+        count: R,
+    ) -> impl Parser<'input, Output = Vec<Self::Output>, Error = Self::Error>
+    where
+        R: RepeatCount,
+    {
+        let combinator = repeat(count);
+        move |input| self.with(&combinator).parse(input)
+    }
+
+    fn repeat_count<R>(self, count: R) -> impl Parser<'input, Output = usize, Error = Self::Error>
+    where
+        R: RepeatCount,
+    {
+        let combinator = repeat_count(count);
+        move |input| self.with(&combinator).parse(input)
+    }
+
+    fn repeat_fold<R, T, I, F>(
+        self,
+        count: R,
+        init: I,
+        f: F,
+    ) -> impl Parser<'input, Output = T, Error = Self::Error>
+    where
+        R: RepeatCount,
+        I: Fn() -> T + Copy,
+        F: Fn(T, Self::Output) -> T + Copy,
+    {
+        let combinator = repeat_fold(count, init, f);
+        move |input| self.with(&combinator).parse(input)
+    }
+
+    fn repeat_raw<R>(
+        self,
+        count: R,
+    ) -> impl Parser<'input, Output = &'input [u8], Error = Self::Error>
+    where
+        R: RepeatCount,
+    {
+        let combinator = repeat_raw(count);
+        move |input| self.with(&combinator).parse(input)
+    }
+
+    fn with_consumed(
+        self,
+    ) -> impl Parser<'input, Output = (&'input [u8], Self::Output), Error = Self::Error> {
+        move |input| self.with(&with_consumed).parse(input)
+    }
 }
 
 impl<'input, F, Output, Error> Parser<'input> for F
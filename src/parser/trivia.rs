@@ -0,0 +1,205 @@
+use crate::{error, ParsedItem, Parser};
+
+/// A description of the input that is insignificant between tokens, such as whitespace and
+/// comments.
+///
+/// By default, nothing is considered trivia.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Trivia<'a> {
+    whitespace: bool,
+    line_comment: Option<&'a [u8]>,
+    block_comment: Option<(&'a [u8], &'a [u8])>,
+}
+
+impl<'a> Trivia<'a> {
+    pub const fn new() -> Self {
+        Self {
+            whitespace: false,
+            line_comment: None,
+            block_comment: None,
+        }
+    }
+
+    /// Treat ASCII whitespace as trivia.
+    #[must_use]
+    pub const fn whitespace(mut self) -> Self {
+        self.whitespace = true;
+        self
+    }
+
+    /// Treat comments beginning with `start` and continuing through the end of the line as trivia.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is empty.
+    #[must_use]
+    pub const fn line_comment(mut self, start: &'a [u8]) -> Self {
+        assert!(!start.is_empty(), "line comment start must not be empty");
+        self.line_comment = Some(start);
+        self
+    }
+
+    /// Treat comments beginning with `start` and continuing through `end` as trivia. Block comments
+    /// do not nest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `end` is empty.
+    #[must_use]
+    pub const fn block_comment(mut self, start: &'a [u8], end: &'a [u8]) -> Self {
+        assert!(!start.is_empty(), "block comment start must not be empty");
+        assert!(!end.is_empty(), "block comment end must not be empty");
+        self.block_comment = Some((start, end));
+        self
+    }
+}
+
+/// Consume a single piece of trivia, returning the remaining input. If the input does not begin
+/// with trivia, `None` is returned.
+fn skip_one<'input>(
+    trivia: Trivia<'_>,
+    input: &'input [u8],
+) -> Result<Option<&'input [u8]>, error::UnterminatedComment> {
+    if trivia.whitespace {
+        if let [first, rest @ ..] = input {
+            if first.is_ascii_whitespace() {
+                return Ok(Some(rest));
+            }
+        }
+    }
+
+    if let Some(start) = trivia.line_comment {
+        if let Some(comment) = input.strip_prefix(start) {
+            return Ok(Some(match comment.iter().position(|&b| b == b'\n') {
+                Some(index) => &comment[(index + 1)..],
+                None => &comment[comment.len()..],
+            }));
+        }
+    }
+
+    if let Some((start, end)) = trivia.block_comment {
+        if let Some(comment) = input.strip_prefix(start) {
+            let index = comment
+                .windows(end.len())
+                .position(|window| window == end)
+                .ok_or(error::UnterminatedComment)?;
+            return Ok(Some(&comment[(index + end.len())..]));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Consume any amount of trivia, outputting the consumed bytes.
+///
+/// If a block comment is not terminated, an error is returned.
+pub fn trivia<'input>(
+    trivia: Trivia<'_>,
+) -> impl Parser<'input, Output = &'input [u8], Error = error::UnterminatedComment> + '_ {
+    move |input: &'input [u8]| {
+        let mut remaining_input = input;
+
+        // Stop if nothing was consumed, as the same trivia would be matched indefinitely.
+        while let Some(new_remaining_input) = skip_one(trivia, remaining_input)? {
+            if new_remaining_input.len() == remaining_input.len() {
+                break;
+            }
+            remaining_input = new_remaining_input;
+        }
+
+        let consumed = &input[..(input.len() - remaining_input.len())];
+        Ok(ParsedItem::from_parts(remaining_input, consumed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIVIA: Trivia<'static> = Trivia::new()
+        .whitespace()
+        .line_comment(b"//")
+        .block_comment(b"/*", b"*/");
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_trivia_valid() {
+        assert_eq!(
+            trivia(TRIVIA)
+                .parse(b" // a\n\t/* b\n */ x // c")
+                .map(ParsedItem::into_parts),
+            Ok((b"x // c".as_ref(), b" // a\n\t/* b\n */ ".as_ref()))
+        );
+        assert_eq!(
+            trivia(TRIVIA).parse(b"// a").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"// a".as_ref()))
+        );
+        assert_eq!(
+            trivia(TRIVIA).parse(b"x").map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), b"".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_trivia_disabled() {
+        assert_eq!(
+            trivia(Trivia::new())
+                .parse(b" // a")
+                .map(ParsedItem::into_parts),
+            Ok((b" // a".as_ref(), b"".as_ref()))
+        );
+        assert_eq!(
+            trivia(Trivia::new().line_comment(b"#"))
+                .parse(b"#a\n b")
+                .map(ParsedItem::into_parts),
+            Ok((b" b".as_ref(), b"#a\n".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_trivia_unterminated() {
+        assert_eq!(
+            trivia(TRIVIA).parse(b" /* a *"),
+            Err(error::UnterminatedComment)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "line comment start must not be empty"]
+    fn test_trivia_empty_line_comment() {
+        let _ = Trivia::new().line_comment(b"");
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "block comment start must not be empty"]
+    fn test_trivia_empty_block_comment_start() {
+        let _ = Trivia::new().block_comment(b"", b"*/");
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    #[should_panic = "block comment end must not be empty"]
+    fn test_trivia_empty_block_comment_end() {
+        let _ = Trivia::new().block_comment(b"/*", b"");
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_trivia_no_progress() {
+        // Constructed directly, as the builder rejects empty delimiters.
+        let trivia_config = Trivia {
+            whitespace: false,
+            line_comment: Some(b""),
+            block_comment: None,
+        };
+
+        assert_eq!(
+            trivia(trivia_config).parse(b"").map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), b"".as_ref()))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::parser::unicode::{is_xid_continue, is_xid_start};
+use crate::parser::{any_utf8_char, Utf8};
+use crate::{error, Either, Either3, ParsedItem, Parser};
+
+/// The characters permitted in an identifier beyond those given by UAX #31.
+///
+/// By default, an identifier is an `XID_Start` character followed by any number of
+/// `XID_Continue` characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IdentifierProfile {
+    underscore: bool,
+    dollar: bool,
+}
+
+impl IdentifierProfile {
+    pub const fn new() -> Self {
+        Self {
+            underscore: false,
+            dollar: false,
+        }
+    }
+
+    /// Permit `_` as the first character.
+    #[must_use]
+    pub const fn underscore(mut self) -> Self {
+        self.underscore = true;
+        self
+    }
+
+    /// Permit `$` as the first character.
+    #[must_use]
+    pub const fn dollar(mut self) -> Self {
+        self.dollar = true;
+        self
+    }
+
+    const fn is_start(self, c: char) -> bool {
+        (self.underscore && c == '_') || (self.dollar && c == '$')
+    }
+}
+
+/// Consume an identifier as defined by UAX #31: an `XID_Start` character followed by any number
+/// of `XID_Continue` characters.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn identifier<'input>() -> Utf8<
+    impl Parser<
+        'input,
+        Output = &'input str,
+        Error = Either3<error::Filter, error::InvalidUtf8, error::EndOfInput>,
+    >,
+> {
+    identifier_with(IdentifierProfile::new())
+}
+
+/// Consume an identifier as defined by UAX #31, additionally permitting the characters specified
+/// by the profile.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn identifier_with<'input>(
+    profile: IdentifierProfile,
+) -> Utf8<
+    impl Parser<
+        'input,
+        Output = &'input str,
+        Error = Either3<error::Filter, error::InvalidUtf8, error::EndOfInput>,
+    >,
+> {
+    let parser = move |input: &'input [u8]| {
+        let mut remaining_input = match any_utf8_char(input) {
+            Ok(parsed_item) => {
+                let c = *parsed_item.value();
+                if !is_xid_start(c) && !profile.is_start(c) {
+                    return Err(Either3::A(error::Filter));
+                }
+                parsed_item.input()
+            }
+            Err(Either::A(err)) => return Err(Either3::B(err)),
+            Err(Either::B(err)) => return Err(Either3::C(err)),
+        };
+
+        while let Ok(parsed_item) = any_utf8_char(remaining_input) {
+            let c = *parsed_item.value();
+            if !is_xid_continue(c) {
+                break;
+            }
+            remaining_input = parsed_item.input();
+        }
+
+        let output = &input[..(input.len() - remaining_input.len())];
+        // Safety: Every character in the output was decoded from valid UTF-8.
+        let output = unsafe { core::str::from_utf8_unchecked(output) };
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    };
+    // Safety: The parser only consumes input that was decoded as characters.
+    unsafe { Utf8::new_unchecked(parser) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Utf8ErrorKind;
+    use crate::parser::utf8_char;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_identifier_valid() {
+        assert_eq!(
+            identifier().parse(b"abc_1 = 2").map(ParsedItem::into_parts),
+            Ok((b" = 2".as_ref(), "abc_1"))
+        );
+        assert_eq!(
+            identifier()
+                .parse("переменная+1".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"+1".as_ref(), "переменная"))
+        );
+        assert_eq!(
+            identifier()
+                .parse("变量".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), "变量"))
+        );
+        assert_eq!(
+            identifier()
+                .parse("e\u{301}te\u{301}".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), "e\u{301}te\u{301}"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_identifier_invalid() {
+        assert_eq!(identifier().parse(b"_a"), Err(Either3::A(error::Filter)));
+        assert_eq!(identifier().parse(b"1a"), Err(Either3::A(error::Filter)));
+        assert_eq!(identifier().parse(b"$a"), Err(Either3::A(error::Filter)));
+        assert_eq!(
+            identifier().parse(b"\xFF"),
+            Err(Either3::B(error::InvalidUtf8 {
+                kind: Utf8ErrorKind::InvalidStartByte
+            }))
+        );
+        assert_eq!(identifier().parse(b""), Err(Either3::C(error::EndOfInput)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_identifier_with_profile() {
+        let parser = identifier_with(IdentifierProfile::new().underscore());
+        assert_eq!(
+            parser.parse(b"_a$").map(ParsedItem::into_parts),
+            Ok((b"$".as_ref(), "_a"))
+        );
+        assert_eq!(parser.parse(b"$a"), Err(Either3::A(error::Filter)));
+
+        let parser = identifier_with(IdentifierProfile::new().dollar());
+        assert_eq!(
+            parser.parse(b"$a_b$").map(ParsedItem::into_parts),
+            Ok((b"$".as_ref(), "$a_b"))
+        );
+        assert_eq!(parser.parse(b"_a"), Err(Either3::A(error::Filter)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_identifier_recognize_str() {
+        let parser = identifier()
+            .and_utf8(utf8_char('.').and_utf8(identifier()).repeat_raw(..))
+            .recognize_str();
+        assert_eq!(
+            parser
+                .parse("a.б.c1 ".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b" ".as_ref(), "a.б.c1"))
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Unicode character data and parsers that depend on it.
+//!
+//! The data is generated from the version of Unicode given by [`UNICODE_VERSION`] using
+//! `scripts/generate-unicode-tables.py`.
+
+use core::cmp::Ordering;
+
+mod identifier;
+#[rustfmt::skip]
+mod tables;
+
+pub use self::identifier::{identifier, identifier_with, IdentifierProfile};
+pub use self::tables::Script;
+
+/// The version of Unicode that the character data is derived from, as (major, minor, update).
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+/// The simple case folding of the character.
+///
+/// Characters whose case folding requires multiple characters, such as `ß`, are unchanged.
+pub fn simple_fold(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+
+    match tables::CASE_FOLDING.binary_search_by_key(&c, |&(c, _)| c) {
+        Ok(index) => tables::CASE_FOLDING[index].1,
+        Err(_) => c,
+    }
+}
+
+/// A Unicode general category.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// `Lu`
+    UppercaseLetter,
+    /// `Ll`
+    LowercaseLetter,
+    /// `Lt`
+    TitlecaseLetter,
+    /// `Lm`
+    ModifierLetter,
+    /// `Lo`
+    OtherLetter,
+    /// `Mn`
+    NonspacingMark,
+    /// `Mc`
+    SpacingMark,
+    /// `Me`
+    EnclosingMark,
+    /// `Nd`
+    DecimalNumber,
+    /// `Nl`
+    LetterNumber,
+    /// `No`
+    OtherNumber,
+    /// `Pc`
+    ConnectorPunctuation,
+    /// `Pd`
+    DashPunctuation,
+    /// `Ps`
+    OpenPunctuation,
+    /// `Pe`
+    ClosePunctuation,
+    /// `Pi`
+    InitialPunctuation,
+    /// `Pf`
+    FinalPunctuation,
+    /// `Po`
+    OtherPunctuation,
+    /// `Sm`
+    MathSymbol,
+    /// `Sc`
+    CurrencySymbol,
+    /// `Sk`
+    ModifierSymbol,
+    /// `So`
+    OtherSymbol,
+    /// `Zs`
+    SpaceSeparator,
+    /// `Zl`
+    LineSeparator,
+    /// `Zp`
+    ParagraphSeparator,
+    /// `Cc`
+    Control,
+    /// `Cf`
+    Format,
+    /// `Cs`. No `char` has this category.
+    Surrogate,
+    /// `Co`
+    PrivateUse,
+    /// `Cn`
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Whether the category is a letter (`L`).
+    pub const fn is_letter(self) -> bool {
+        matches!(
+            self,
+            Self::UppercaseLetter
+                | Self::LowercaseLetter
+                | Self::TitlecaseLetter
+                | Self::ModifierLetter
+                | Self::OtherLetter
+        )
+    }
+
+    /// Whether the category is a mark (`M`).
+    pub const fn is_mark(self) -> bool {
+        matches!(
+            self,
+            Self::NonspacingMark | Self::SpacingMark | Self::EnclosingMark
+        )
+    }
+
+    /// Whether the category is a number (`N`).
+    pub const fn is_number(self) -> bool {
+        matches!(
+            self,
+            Self::DecimalNumber | Self::LetterNumber | Self::OtherNumber
+        )
+    }
+
+    /// Whether the category is punctuation (`P`).
+    pub const fn is_punctuation(self) -> bool {
+        matches!(
+            self,
+            Self::ConnectorPunctuation
+                | Self::DashPunctuation
+                | Self::OpenPunctuation
+                | Self::ClosePunctuation
+                | Self::InitialPunctuation
+                | Self::FinalPunctuation
+                | Self::OtherPunctuation
+        )
+    }
+
+    /// Whether the category is a symbol (`S`).
+    pub const fn is_symbol(self) -> bool {
+        matches!(
+            self,
+            Self::MathSymbol | Self::CurrencySymbol | Self::ModifierSymbol | Self::OtherSymbol
+        )
+    }
+
+    /// Whether the category is a separator (`Z`).
+    pub const fn is_separator(self) -> bool {
+        matches!(
+            self,
+            Self::SpaceSeparator | Self::LineSeparator | Self::ParagraphSeparator
+        )
+    }
+
+    /// Whether the category is other (`C`).
+    pub const fn is_other(self) -> bool {
+        matches!(
+            self,
+            Self::Control | Self::Format | Self::Surrogate | Self::PrivateUse | Self::Unassigned
+        )
+    }
+}
+
+/// Compare the range from `start` through `end` to the character.
+const fn compare_range(start: char, end: char, c: char) -> Ordering {
+    if end < c {
+        Ordering::Less
+    } else if start > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Find the value of the range containing the character.
+fn lookup<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// Whether any range contains the character.
+fn contains(table: &[(char, char)], c: char) -> bool {
+    table
+        .binary_search_by(|&(start, end)| compare_range(start, end, c))
+        .is_ok()
+}
+
+/// The general category of the character.
+pub fn general_category(c: char) -> GeneralCategory {
+    lookup(tables::GENERAL_CATEGORY, c).unwrap_or(GeneralCategory::Unassigned)
+}
+
+/// The script of the character.
+pub fn script(c: char) -> Script {
+    lookup(tables::SCRIPT, c).unwrap_or(Script::Unknown)
+}
+
+/// Whether the character has the `XID_Start` property.
+pub fn is_xid_start(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphabetic();
+    }
+    contains(tables::XID_START, c)
+}
+
+/// Whether the character has the `XID_Continue` property.
+pub fn is_xid_continue(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+    contains(tables::XID_CONTINUE, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_simple_fold() {
+        assert_eq!(simple_fold('A'), 'a');
+        assert_eq!(simple_fold('a'), 'a');
+        assert_eq!(simple_fold('Ä'), 'ä');
+        assert_eq!(simple_fold('Σ'), 'σ');
+        assert_eq!(simple_fold('ς'), 'σ');
+        assert_eq!(simple_fold('\u{212A}'), 'k'); // KELVIN SIGN
+        assert_eq!(simple_fold('ẞ'), 'ß');
+        assert_eq!(simple_fold('ß'), 'ß');
+        assert_eq!(simple_fold('🦀'), '🦀');
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_general_category() {
+        assert_eq!(general_category('A'), GeneralCategory::UppercaseLetter);
+        assert_eq!(general_category('ǅ'), GeneralCategory::TitlecaseLetter);
+        assert_eq!(general_category('\u{301}'), GeneralCategory::NonspacingMark);
+        assert_eq!(general_category('٣'), GeneralCategory::DecimalNumber);
+        assert_eq!(general_category('«'), GeneralCategory::InitialPunctuation);
+        assert_eq!(general_category('€'), GeneralCategory::CurrencySymbol);
+        assert_eq!(
+            general_category('\u{2029}'),
+            GeneralCategory::ParagraphSeparator
+        );
+        assert_eq!(general_category('\u{E000}'), GeneralCategory::PrivateUse);
+        assert_eq!(general_category('\u{378}'), GeneralCategory::Unassigned);
+
+        assert!(general_category('ж').is_letter());
+        assert!(general_category('\u{20DD}').is_mark());
+        assert!(general_category('Ⅻ').is_number());
+        assert!(general_category('-').is_punctuation());
+        assert!(general_category('+').is_symbol());
+        assert!(general_category(' ').is_separator());
+        assert!(general_category('\0').is_other());
+        assert!(!general_category('a').is_other());
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_script() {
+        assert_eq!(script('a'), Script::Latin);
+        assert_eq!(script('λ'), Script::Greek);
+        assert_eq!(script('ж'), Script::Cyrillic);
+        assert_eq!(script('字'), Script::Han);
+        assert_eq!(script('ا'), Script::Arabic);
+        assert_eq!(script('1'), Script::Common);
+        assert_eq!(script('\u{301}'), Script::Inherited);
+        assert_eq!(script('\u{378}'), Script::Unknown);
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_xid() {
+        assert!(is_xid_start('a'));
+        assert!(is_xid_start('ж'));
+        assert!(is_xid_start('字'));
+        assert!(!is_xid_start('_'));
+        assert!(!is_xid_start('1'));
+        assert!(!is_xid_start('\u{301}'));
+        assert!(!is_xid_start('🦀'));
+
+        assert!(is_xid_continue('_'));
+        assert!(is_xid_continue('1'));
+        assert!(is_xid_continue('\u{301}'));
+        assert!(is_xid_continue('٣'));
+        assert!(!is_xid_continue('-'));
+        assert!(!is_xid_continue('$'));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_range_tables_sorted() {
+        assert!(tables::GENERAL_CATEGORY
+            .windows(2)
+            .all(|pair| pair[0].1 < pair[1].0));
+        assert!(tables::SCRIPT.windows(2).all(|pair| pair[0].1 < pair[1].0));
+        assert!(tables::XID_START
+            .windows(2)
+            .all(|pair| pair[0].1 < pair[1].0));
+        assert!(tables::XID_CONTINUE
+            .windows(2)
+            .all(|pair| pair[0].1 < pair[1].0));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_case_folding_table_sorted() {
+        assert!(tables::CASE_FOLDING
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,7272 @@
+// This file is generated by `scripts/generate-unicode-tables.py`. Do not edit it manually.
+
+use super::GeneralCategory::{self, *};
+use self::Script::*;
+
+/// A Unicode script.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
+    Arabic,
+    Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
+    Bengali,
+    Bhaiksuki,
+    Bopomofo,
+    Brahmi,
+    Braille,
+    Buginese,
+    Buhid,
+    CanadianAboriginal,
+    Carian,
+    CaucasianAlbanian,
+    Chakma,
+    Cham,
+    Cherokee,
+    Chorasmian,
+    Common,
+    Coptic,
+    Cuneiform,
+    Cypriot,
+    CyproMinoan,
+    Cyrillic,
+    Deseret,
+    Devanagari,
+    DivesAkuru,
+    Dogra,
+    Duployan,
+    EgyptianHieroglyphs,
+    Elbasan,
+    Elymaic,
+    Ethiopic,
+    Georgian,
+    Glagolitic,
+    Gothic,
+    Grantha,
+    Greek,
+    Gujarati,
+    GunjalaGondi,
+    Gurmukhi,
+    Han,
+    Hangul,
+    HanifiRohingya,
+    Hanunoo,
+    Hatran,
+    Hebrew,
+    Hiragana,
+    ImperialAramaic,
+    Inherited,
+    InscriptionalPahlavi,
+    InscriptionalParthian,
+    Javanese,
+    Kaithi,
+    Kannada,
+    Katakana,
+    KayahLi,
+    Kharoshthi,
+    KhitanSmallScript,
+    Khmer,
+    Khojki,
+    Khudawadi,
+    Lao,
+    Latin,
+    Lepcha,
+    Limbu,
+    LinearA,
+    LinearB,
+    Lisu,
+    Lycian,
+    Lydian,
+    Mahajani,
+    Makasar,
+    Malayalam,
+    Mandaic,
+    Manichaean,
+    Marchen,
+    MasaramGondi,
+    Medefaidrin,
+    MeeteiMayek,
+    MendeKikakui,
+    MeroiticCursive,
+    MeroiticHieroglyphs,
+    Miao,
+    Modi,
+    Mongolian,
+    Mro,
+    Multani,
+    Myanmar,
+    Nabataean,
+    Nandinagari,
+    NewTaiLue,
+    Newa,
+    Nko,
+    Nushu,
+    NyiakengPuachueHmong,
+    Ogham,
+    OlChiki,
+    OldHungarian,
+    OldItalic,
+    OldNorthArabian,
+    OldPermic,
+    OldPersian,
+    OldSogdian,
+    OldSouthArabian,
+    OldTurkic,
+    OldUyghur,
+    Oriya,
+    Osage,
+    Osmanya,
+    PahawhHmong,
+    Palmyrene,
+    PauCinHau,
+    PhagsPa,
+    Phoenician,
+    PsalterPahlavi,
+    Rejang,
+    Runic,
+    Samaritan,
+    Saurashtra,
+    Sharada,
+    Shavian,
+    Siddham,
+    SignWriting,
+    Sinhala,
+    Sogdian,
+    SoraSompeng,
+    Soyombo,
+    Sundanese,
+    SylotiNagri,
+    Syriac,
+    Tagalog,
+    Tagbanwa,
+    TaiLe,
+    TaiTham,
+    TaiViet,
+    Takri,
+    Tamil,
+    Tangsa,
+    Tangut,
+    Telugu,
+    Thaana,
+    Thai,
+    Tibetan,
+    Tifinagh,
+    Tirhuta,
+    Toto,
+    Ugaritic,
+    Unknown,
+    Vai,
+    Vithkuqi,
+    Wancho,
+    WarangCiti,
+    Yezidi,
+    Yi,
+    ZanabazarSquare,
+}
+
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+pub(super) static CASE_FOLDING: &[(char, char)] = &[
+    ('\u{0041}', '\u{0061}'),
+    ('\u{0042}', '\u{0062}'),
+    ('\u{0043}', '\u{0063}'),
+    ('\u{0044}', '\u{0064}'),
+    ('\u{0045}', '\u{0065}'),
+    ('\u{0046}', '\u{0066}'),
+    ('\u{0047}', '\u{0067}'),
+    ('\u{0048}', '\u{0068}'),
+    ('\u{0049}', '\u{0069}'),
+    ('\u{004A}', '\u{006A}'),
+    ('\u{004B}', '\u{006B}'),
+    ('\u{004C}', '\u{006C}'),
+    ('\u{004D}', '\u{006D}'),
+    ('\u{004E}', '\u{006E}'),
+    ('\u{004F}', '\u{006F}'),
+    ('\u{0050}', '\u{0070}'),
+    ('\u{0051}', '\u{0071}'),
+    ('\u{0052}', '\u{0072}'),
+    ('\u{0053}', '\u{0073}'),
+    ('\u{0054}', '\u{0074}'),
+    ('\u{0055}', '\u{0075}'),
+    ('\u{0056}', '\u{0076}'),
+    ('\u{0057}', '\u{0077}'),
+    ('\u{0058}', '\u{0078}'),
+    ('\u{0059}', '\u{0079}'),
+    ('\u{005A}', '\u{007A}'),
+    ('\u{00B5}', '\u{03BC}'),
+    ('\u{00C0}', '\u{00E0}'),
+    ('\u{00C1}', '\u{00E1}'),
+    ('\u{00C2}', '\u{00E2}'),
+    ('\u{00C3}', '\u{00E3}'),
+    ('\u{00C4}', '\u{00E4}'),
+    ('\u{00C5}', '\u{00E5}'),
+    ('\u{00C6}', '\u{00E6}'),
+    ('\u{00C7}', '\u{00E7}'),
+    ('\u{00C8}', '\u{00E8}'),
+    ('\u{00C9}', '\u{00E9}'),
+    ('\u{00CA}', '\u{00EA}'),
+    ('\u{00CB}', '\u{00EB}'),
+    ('\u{00CC}', '\u{00EC}'),
+    ('\u{00CD}', '\u{00ED}'),
+    ('\u{00CE}', '\u{00EE}'),
+    ('\u{00CF}', '\u{00EF}'),
+    ('\u{00D0}', '\u{00F0}'),
+    ('\u{00D1}', '\u{00F1}'),
+    ('\u{00D2}', '\u{00F2}'),
+    ('\u{00D3}', '\u{00F3}'),
+    ('\u{00D4}', '\u{00F4}'),
+    ('\u{00D5}', '\u{00F5}'),
+    ('\u{00D6}', '\u{00F6}'),
+    ('\u{00D8}', '\u{00F8}'),
+    ('\u{00D9}', '\u{00F9}'),
+    ('\u{00DA}', '\u{00FA}'),
+    ('\u{00DB}', '\u{00FB}'),
+    ('\u{00DC}', '\u{00FC}'),
+    ('\u{00DD}', '\u{00FD}'),
+    ('\u{00DE}', '\u{00FE}'),
+    ('\u{0100}', '\u{0101}'),
+    ('\u{0102}', '\u{0103}'),
+    ('\u{0104}', '\u{0105}'),
+    ('\u{0106}', '\u{0107}'),
+    ('\u{0108}', '\u{0109}'),
+    ('\u{010A}', '\u{010B}'),
+    ('\u{010C}', '\u{010D}'),
+    ('\u{010E}', '\u{010F}'),
+    ('\u{0110}', '\u{0111}'),
+    ('\u{0112}', '\u{0113}'),
+    ('\u{0114}', '\u{0115}'),
+    ('\u{0116}', '\u{0117}'),
+    ('\u{0118}', '\u{0119}'),
+    ('\u{011A}', '\u{011B}'),
+    ('\u{011C}', '\u{011D}'),
+    ('\u{011E}', '\u{011F}'),
+    ('\u{0120}', '\u{0121}'),
+    ('\u{0122}', '\u{0123}'),
+    ('\u{0124}', '\u{0125}'),
+    ('\u{0126}', '\u{0127}'),
+    ('\u{0128}', '\u{0129}'),
+    ('\u{012A}', '\u{012B}'),
+    ('\u{012C}', '\u{012D}'),
+    ('\u{012E}', '\u{012F}'),
+    ('\u{0132}', '\u{0133}'),
+    ('\u{0134}', '\u{0135}'),
+    ('\u{0136}', '\u{0137}'),
+    ('\u{0139}', '\u{013A}'),
+    ('\u{013B}', '\u{013C}'),
+    ('\u{013D}', '\u{013E}'),
+    ('\u{013F}', '\u{0140}'),
+    ('\u{0141}', '\u{0142}'),
+    ('\u{0143}', '\u{0144}'),
+    ('\u{0145}', '\u{0146}'),
+    ('\u{0147}', '\u{0148}'),
+    ('\u{014A}', '\u{014B}'),
+    ('\u{014C}', '\u{014D}'),
+    ('\u{014E}', '\u{014F}'),
+    ('\u{0150}', '\u{0151}'),
+    ('\u{0152}', '\u{0153}'),
+    ('\u{0154}', '\u{0155}'),
+    ('\u{0156}', '\u{0157}'),
+    ('\u{0158}', '\u{0159}'),
+    ('\u{015A}', '\u{015B}'),
+    ('\u{015C}', '\u{015D}'),
+    ('\u{015E}', '\u{015F}'),
+    ('\u{0160}', '\u{0161}'),
+    ('\u{0162}', '\u{0163}'),
+    ('\u{0164}', '\u{0165}'),
+    ('\u{0166}', '\u{0167}'),
+    ('\u{0168}', '\u{0169}'),
+    ('\u{016A}', '\u{016B}'),
+    ('\u{016C}', '\u{016D}'),
+    ('\u{016E}', '\u{016F}'),
+    ('\u{0170}', '\u{0171}'),
+    ('\u{0172}', '\u{0173}'),
+    ('\u{0174}', '\u{0175}'),
+    ('\u{0176}', '\u{0177}'),
+    ('\u{0178}', '\u{00FF}'),
+    ('\u{0179}', '\u{017A}'),
+    ('\u{017B}', '\u{017C}'),
+    ('\u{017D}', '\u{017E}'),
+    ('\u{017F}', '\u{0073}'),
+    ('\u{0181}', '\u{0253}'),
+    ('\u{0182}', '\u{0183}'),
+    ('\u{0184}', '\u{0185}'),
+    ('\u{0186}', '\u{0254}'),
+    ('\u{0187}', '\u{0188}'),
+    ('\u{0189}', '\u{0256}'),
+    ('\u{018A}', '\u{0257}'),
+    ('\u{018B}', '\u{018C}'),
+    ('\u{018E}', '\u{01DD}'),
+    ('\u{018F}', '\u{0259}'),
+    ('\u{0190}', '\u{025B}'),
+    ('\u{0191}', '\u{0192}'),
+    ('\u{0193}', '\u{0260}'),
+    ('\u{0194}', '\u{0263}'),
+    ('\u{0196}', '\u{0269}'),
+    ('\u{0197}', '\u{0268}'),
+    ('\u{0198}', '\u{0199}'),
+    ('\u{019C}', '\u{026F}'),
+    ('\u{019D}', '\u{0272}'),
+    ('\u{019F}', '\u{0275}'),
+    ('\u{01A0}', '\u{01A1}'),
+    ('\u{01A2}', '\u{01A3}'),
+    ('\u{01A4}', '\u{01A5}'),
+    ('\u{01A6}', '\u{0280}'),
+    ('\u{01A7}', '\u{01A8}'),
+    ('\u{01A9}', '\u{0283}'),
+    ('\u{01AC}', '\u{01AD}'),
+    ('\u{01AE}', '\u{0288}'),
+    ('\u{01AF}', '\u{01B0}'),
+    ('\u{01B1}', '\u{028A}'),
+    ('\u{01B2}', '\u{028B}'),
+    ('\u{01B3}', '\u{01B4}'),
+    ('\u{01B5}', '\u{01B6}'),
+    ('\u{01B7}', '\u{0292}'),
+    ('\u{01B8}', '\u{01B9}'),
+    ('\u{01BC}', '\u{01BD}'),
+    ('\u{01C4}', '\u{01C6}'),
+    ('\u{01C5}', '\u{01C6}'),
+    ('\u{01C7}', '\u{01C9}'),
+    ('\u{01C8}', '\u{01C9}'),
+    ('\u{01CA}', '\u{01CC}'),
+    ('\u{01CB}', '\u{01CC}'),
+    ('\u{01CD}', '\u{01CE}'),
+    ('\u{01CF}', '\u{01D0}'),
+    ('\u{01D1}', '\u{01D2}'),
+    ('\u{01D3}', '\u{01D4}'),
+    ('\u{01D5}', '\u{01D6}'),
+    ('\u{01D7}', '\u{01D8}'),
+    ('\u{01D9}', '\u{01DA}'),
+    ('\u{01DB}', '\u{01DC}'),
+    ('\u{01DE}', '\u{01DF}'),
+    ('\u{01E0}', '\u{01E1}'),
+    ('\u{01E2}', '\u{01E3}'),
+    ('\u{01E4}', '\u{01E5}'),
+    ('\u{01E6}', '\u{01E7}'),
+    ('\u{01E8}', '\u{01E9}'),
+    ('\u{01EA}', '\u{01EB}'),
+    ('\u{01EC}', '\u{01ED}'),
+    ('\u{01EE}', '\u{01EF}'),
+    ('\u{01F1}', '\u{01F3}'),
+    ('\u{01F2}', '\u{01F3}'),
+    ('\u{01F4}', '\u{01F5}'),
+    ('\u{01F6}', '\u{0195}'),
+    ('\u{01F7}', '\u{01BF}'),
+    ('\u{01F8}', '\u{01F9}'),
+    ('\u{01FA}', '\u{01FB}'),
+    ('\u{01FC}', '\u{01FD}'),
+    ('\u{01FE}', '\u{01FF}'),
+    ('\u{0200}', '\u{0201}'),
+    ('\u{0202}', '\u{0203}'),
+    ('\u{0204}', '\u{0205}'),
+    ('\u{0206}', '\u{0207}'),
+    ('\u{0208}', '\u{0209}'),
+    ('\u{020A}', '\u{020B}'),
+    ('\u{020C}', '\u{020D}'),
+    ('\u{020E}', '\u{020F}'),
+    ('\u{0210}', '\u{0211}'),
+    ('\u{0212}', '\u{0213}'),
+    ('\u{0214}', '\u{0215}'),
+    ('\u{0216}', '\u{0217}'),
+    ('\u{0218}', '\u{0219}'),
+    ('\u{021A}', '\u{021B}'),
+    ('\u{021C}', '\u{021D}'),
+    ('\u{021E}', '\u{021F}'),
+    ('\u{0220}', '\u{019E}'),
+    ('\u{0222}', '\u{0223}'),
+    ('\u{0224}', '\u{0225}'),
+    ('\u{0226}', '\u{0227}'),
+    ('\u{0228}', '\u{0229}'),
+    ('\u{022A}', '\u{022B}'),
+    ('\u{022C}', '\u{022D}'),
+    ('\u{022E}', '\u{022F}'),
+    ('\u{0230}', '\u{0231}'),
+    ('\u{0232}', '\u{0233}'),
+    ('\u{023A}', '\u{2C65}'),
+    ('\u{023B}', '\u{023C}'),
+    ('\u{023D}', '\u{019A}'),
+    ('\u{023E}', '\u{2C66}'),
+    ('\u{0241}', '\u{0242}'),
+    ('\u{0243}', '\u{0180}'),
+    ('\u{0244}', '\u{0289}'),
+    ('\u{0245}', '\u{028C}'),
+    ('\u{0246}', '\u{0247}'),
+    ('\u{0248}', '\u{0249}'),
+    ('\u{024A}', '\u{024B}'),
+    ('\u{024C}', '\u{024D}'),
+    ('\u{024E}', '\u{024F}'),
+    ('\u{0345}', '\u{03B9}'),
+    ('\u{0370}', '\u{0371}'),
+    ('\u{0372}', '\u{0373}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037F}', '\u{03F3}'),
+    ('\u{0386}', '\u{03AC}'),
+    ('\u{0388}', '\u{03AD}'),
+    ('\u{0389}', '\u{03AE}'),
+    ('\u{038A}', '\u{03AF}'),
+    ('\u{038C}', '\u{03CC}'),
+    ('\u{038E}', '\u{03CD}'),
+    ('\u{038F}', '\u{03CE}'),
+    ('\u{0391}', '\u{03B1}'),
+    ('\u{0392}', '\u{03B2}'),
+    ('\u{0393}', '\u{03B3}'),
+    ('\u{0394}', '\u{03B4}'),
+    ('\u{0395}', '\u{03B5}'),
+    ('\u{0396}', '\u{03B6}'),
+    ('\u{0397}', '\u{03B7}'),
+    ('\u{0398}', '\u{03B8}'),
+    ('\u{0399}', '\u{03B9}'),
+    ('\u{039A}', '\u{03BA}'),
+    ('\u{039B}', '\u{03BB}'),
+    ('\u{039C}', '\u{03BC}'),
+    ('\u{039D}', '\u{03BD}'),
+    ('\u{039E}', '\u{03BE}'),
+    ('\u{039F}', '\u{03BF}'),
+    ('\u{03A0}', '\u{03C0}'),
+    ('\u{03A1}', '\u{03C1}'),
+    ('\u{03A3}', '\u{03C3}'),
+    ('\u{03A4}', '\u{03C4}'),
+    ('\u{03A5}', '\u{03C5}'),
+    ('\u{03A6}', '\u{03C6}'),
+    ('\u{03A7}', '\u{03C7}'),
+    ('\u{03A8}', '\u{03C8}'),
+    ('\u{03A9}', '\u{03C9}'),
+    ('\u{03AA}', '\u{03CA}'),
+    ('\u{03AB}', '\u{03CB}'),
+    ('\u{03C2}', '\u{03C3}'),
+    ('\u{03CF}', '\u{03D7}'),
+    ('\u{03D0}', '\u{03B2}'),
+    ('\u{03D1}', '\u{03B8}'),
+    ('\u{03D5}', '\u{03C6}'),
+    ('\u{03D6}', '\u{03C0}'),
+    ('\u{03D8}', '\u{03D9}'),
+    ('\u{03DA}', '\u{03DB}'),
+    ('\u{03DC}', '\u{03DD}'),
+    ('\u{03DE}', '\u{03DF}'),
+    ('\u{03E0}', '\u{03E1}'),
+    ('\u{03E2}', '\u{03E3}'),
+    ('\u{03E4}', '\u{03E5}'),
+    ('\u{03E6}', '\u{03E7}'),
+    ('\u{03E8}', '\u{03E9}'),
+    ('\u{03EA}', '\u{03EB}'),
+    ('\u{03EC}', '\u{03ED}'),
+    ('\u{03EE}', '\u{03EF}'),
+    ('\u{03F0}', '\u{03BA}'),
+    ('\u{03F1}', '\u{03C1}'),
+    ('\u{03F4}', '\u{03B8}'),
+    ('\u{03F5}', '\u{03B5}'),
+    ('\u{03F7}', '\u{03F8}'),
+    ('\u{03F9}', '\u{03F2}'),
+    ('\u{03FA}', '\u{03FB}'),
+    ('\u{03FD}', '\u{037B}'),
+    ('\u{03FE}', '\u{037C}'),
+    ('\u{03FF}', '\u{037D}'),
+    ('\u{0400}', '\u{0450}'),
+    ('\u{0401}', '\u{0451}'),
+    ('\u{0402}', '\u{0452}'),
+    ('\u{0403}', '\u{0453}'),
+    ('\u{0404}', '\u{0454}'),
+    ('\u{0405}', '\u{0455}'),
+    ('\u{0406}', '\u{0456}'),
+    ('\u{0407}', '\u{0457}'),
+    ('\u{0408}', '\u{0458}'),
+    ('\u{0409}', '\u{0459}'),
+    ('\u{040A}', '\u{045A}'),
+    ('\u{040B}', '\u{045B}'),
+    ('\u{040C}', '\u{045C}'),
+    ('\u{040D}', '\u{045D}'),
+    ('\u{040E}', '\u{045E}'),
+    ('\u{040F}', '\u{045F}'),
+    ('\u{0410}', '\u{0430}'),
+    ('\u{0411}', '\u{0431}'),
+    ('\u{0412}', '\u{0432}'),
+    ('\u{0413}', '\u{0433}'),
+    ('\u{0414}', '\u{0434}'),
+    ('\u{0415}', '\u{0435}'),
+    ('\u{0416}', '\u{0436}'),
+    ('\u{0417}', '\u{0437}'),
+    ('\u{0418}', '\u{0438}'),
+    ('\u{0419}', '\u{0439}'),
+    ('\u{041A}', '\u{043A}'),
+    ('\u{041B}', '\u{043B}'),
+    ('\u{041C}', '\u{043C}'),
+    ('\u{041D}', '\u{043D}'),
+    ('\u{041E}', '\u{043E}'),
+    ('\u{041F}', '\u{043F}'),
+    ('\u{0420}', '\u{0440}'),
+    ('\u{0421}', '\u{0441}'),
+    ('\u{0422}', '\u{0442}'),
+    ('\u{0423}', '\u{0443}'),
+    ('\u{0424}', '\u{0444}'),
+    ('\u{0425}', '\u{0445}'),
+    ('\u{0426}', '\u{0446}'),
+    ('\u{0427}', '\u{0447}'),
+    ('\u{0428}', '\u{0448}'),
+    ('\u{0429}', '\u{0449}'),
+    ('\u{042A}', '\u{044A}'),
+    ('\u{042B}', '\u{044B}'),
+    ('\u{042C}', '\u{044C}'),
+    ('\u{042D}', '\u{044D}'),
+    ('\u{042E}', '\u{044E}'),
+    ('\u{042F}', '\u{044F}'),
+    ('\u{0460}', '\u{0461}'),
+    ('\u{0462}', '\u{0463}'),
+    ('\u{0464}', '\u{0465}'),
+    ('\u{0466}', '\u{0467}'),
+    ('\u{0468}', '\u{0469}'),
+    ('\u{046A}', '\u{046B}'),
+    ('\u{046C}', '\u{046D}'),
+    ('\u{046E}', '\u{046F}'),
+    ('\u{0470}', '\u{0471}'),
+    ('\u{0472}', '\u{0473}'),
+    ('\u{0474}', '\u{0475}'),
+    ('\u{0476}', '\u{0477}'),
+    ('\u{0478}', '\u{0479}'),
+    ('\u{047A}', '\u{047B}'),
+    ('\u{047C}', '\u{047D}'),
+    ('\u{047E}', '\u{047F}'),
+    ('\u{0480}', '\u{0481}'),
+    ('\u{048A}', '\u{048B}'),
+    ('\u{048C}', '\u{048D}'),
+    ('\u{048E}', '\u{048F}'),
+    ('\u{0490}', '\u{0491}'),
+    ('\u{0492}', '\u{0493}'),
+    ('\u{0494}', '\u{0495}'),
+    ('\u{0496}', '\u{0497}'),
+    ('\u{0498}', '\u{0499}'),
+    ('\u{049A}', '\u{049B}'),
+    ('\u{049C}', '\u{049D}'),
+    ('\u{049E}', '\u{049F}'),
+    ('\u{04A0}', '\u{04A1}'),
+    ('\u{04A2}', '\u{04A3}'),
+    ('\u{04A4}', '\u{04A5}'),
+    ('\u{04A6}', '\u{04A7}'),
+    ('\u{04A8}', '\u{04A9}'),
+    ('\u{04AA}', '\u{04AB}'),
+    ('\u{04AC}', '\u{04AD}'),
+    ('\u{04AE}', '\u{04AF}'),
+    ('\u{04B0}', '\u{04B1}'),
+    ('\u{04B2}', '\u{04B3}'),
+    ('\u{04B4}', '\u{04B5}'),
+    ('\u{04B6}', '\u{04B7}'),
+    ('\u{04B8}', '\u{04B9}'),
+    ('\u{04BA}', '\u{04BB}'),
+    ('\u{04BC}', '\u{04BD}'),
+    ('\u{04BE}', '\u{04BF}'),
+    ('\u{04C0}', '\u{04CF}'),
+    ('\u{04C1}', '\u{04C2}'),
+    ('\u{04C3}', '\u{04C4}'),
+    ('\u{04C5}', '\u{04C6}'),
+    ('\u{04C7}', '\u{04C8}'),
+    ('\u{04C9}', '\u{04CA}'),
+    ('\u{04CB}', '\u{04CC}'),
+    ('\u{04CD}', '\u{04CE}'),
+    ('\u{04D0}', '\u{04D1}'),
+    ('\u{04D2}', '\u{04D3}'),
+    ('\u{04D4}', '\u{04D5}'),
+    ('\u{04D6}', '\u{04D7}'),
+    ('\u{04D8}', '\u{04D9}'),
+    ('\u{04DA}', '\u{04DB}'),
+    ('\u{04DC}', '\u{04DD}'),
+    ('\u{04DE}', '\u{04DF}'),
+    ('\u{04E0}', '\u{04E1}'),
+    ('\u{04E2}', '\u{04E3}'),
+    ('\u{04E4}', '\u{04E5}'),
+    ('\u{04E6}', '\u{04E7}'),
+    ('\u{04E8}', '\u{04E9}'),
+    ('\u{04EA}', '\u{04EB}'),
+    ('\u{04EC}', '\u{04ED}'),
+    ('\u{04EE}', '\u{04EF}'),
+    ('\u{04F0}', '\u{04F1}'),
+    ('\u{04F2}', '\u{04F3}'),
+    ('\u{04F4}', '\u{04F5}'),
+    ('\u{04F6}', '\u{04F7}'),
+    ('\u{04F8}', '\u{04F9}'),
+    ('\u{04FA}', '\u{04FB}'),
+    ('\u{04FC}', '\u{04FD}'),
+    ('\u{04FE}', '\u{04FF}'),
+    ('\u{0500}', '\u{0501}'),
+    ('\u{0502}', '\u{0503}'),
+    ('\u{0504}', '\u{0505}'),
+    ('\u{0506}', '\u{0507}'),
+    ('\u{0508}', '\u{0509}'),
+    ('\u{050A}', '\u{050B}'),
+    ('\u{050C}', '\u{050D}'),
+    ('\u{050E}', '\u{050F}'),
+    ('\u{0510}', '\u{0511}'),
+    ('\u{0512}', '\u{0513}'),
+    ('\u{0514}', '\u{0515}'),
+    ('\u{0516}', '\u{0517}'),
+    ('\u{0518}', '\u{0519}'),
+    ('\u{051A}', '\u{051B}'),
+    ('\u{051C}', '\u{051D}'),
+    ('\u{051E}', '\u{051F}'),
+    ('\u{0520}', '\u{0521}'),
+    ('\u{0522}', '\u{0523}'),
+    ('\u{0524}', '\u{0525}'),
+    ('\u{0526}', '\u{0527}'),
+    ('\u{0528}', '\u{0529}'),
+    ('\u{052A}', '\u{052B}'),
+    ('\u{052C}', '\u{052D}'),
+    ('\u{052E}', '\u{052F}'),
+    ('\u{0531}', '\u{0561}'),
+    ('\u{0532}', '\u{0562}'),
+    ('\u{0533}', '\u{0563}'),
+    ('\u{0534}', '\u{0564}'),
+    ('\u{0535}', '\u{0565}'),
+    ('\u{0536}', '\u{0566}'),
+    ('\u{0537}', '\u{0567}'),
+    ('\u{0538}', '\u{0568}'),
+    ('\u{0539}', '\u{0569}'),
+    ('\u{053A}', '\u{056A}'),
+    ('\u{053B}', '\u{056B}'),
+    ('\u{053C}', '\u{056C}'),
+    ('\u{053D}', '\u{056D}'),
+    ('\u{053E}', '\u{056E}'),
+    ('\u{053F}', '\u{056F}'),
+    ('\u{0540}', '\u{0570}'),
+    ('\u{0541}', '\u{0571}'),
+    ('\u{0542}', '\u{0572}'),
+    ('\u{0543}', '\u{0573}'),
+    ('\u{0544}', '\u{0574}'),
+    ('\u{0545}', '\u{0575}'),
+    ('\u{0546}', '\u{0576}'),
+    ('\u{0547}', '\u{0577}'),
+    ('\u{0548}', '\u{0578}'),
+    ('\u{0549}', '\u{0579}'),
+    ('\u{054A}', '\u{057A}'),
+    ('\u{054B}', '\u{057B}'),
+    ('\u{054C}', '\u{057C}'),
+    ('\u{054D}', '\u{057D}'),
+    ('\u{054E}', '\u{057E}'),
+    ('\u{054F}', '\u{057F}'),
+    ('\u{0550}', '\u{0580}'),
+    ('\u{0551}', '\u{0581}'),
+    ('\u{0552}', '\u{0582}'),
+    ('\u{0553}', '\u{0583}'),
+    ('\u{0554}', '\u{0584}'),
+    ('\u{0555}', '\u{0585}'),
+    ('\u{0556}', '\u{0586}'),
+    ('\u{10A0}', '\u{2D00}'),
+    ('\u{10A1}', '\u{2D01}'),
+    ('\u{10A2}', '\u{2D02}'),
+    ('\u{10A3}', '\u{2D03}'),
+    ('\u{10A4}', '\u{2D04}'),
+    ('\u{10A5}', '\u{2D05}'),
+    ('\u{10A6}', '\u{2D06}'),
+    ('\u{10A7}', '\u{2D07}'),
+    ('\u{10A8}', '\u{2D08}'),
+    ('\u{10A9}', '\u{2D09}'),
+    ('\u{10AA}', '\u{2D0A}'),
+    ('\u{10AB}', '\u{2D0B}'),
+    ('\u{10AC}', '\u{2D0C}'),
+    ('\u{10AD}', '\u{2D0D}'),
+    ('\u{10AE}', '\u{2D0E}'),
+    ('\u{10AF}', '\u{2D0F}'),
+    ('\u{10B0}', '\u{2D10}'),
+    ('\u{10B1}', '\u{2D11}'),
+    ('\u{10B2}', '\u{2D12}'),
+    ('\u{10B3}', '\u{2D13}'),
+    ('\u{10B4}', '\u{2D14}'),
+    ('\u{10B5}', '\u{2D15}'),
+    ('\u{10B6}', '\u{2D16}'),
+    ('\u{10B7}', '\u{2D17}'),
+    ('\u{10B8}', '\u{2D18}'),
+    ('\u{10B9}', '\u{2D19}'),
+    ('\u{10BA}', '\u{2D1A}'),
+    ('\u{10BB}', '\u{2D1B}'),
+    ('\u{10BC}', '\u{2D1C}'),
+    ('\u{10BD}', '\u{2D1D}'),
+    ('\u{10BE}', '\u{2D1E}'),
+    ('\u{10BF}', '\u{2D1F}'),
+    ('\u{10C0}', '\u{2D20}'),
+    ('\u{10C1}', '\u{2D21}'),
+    ('\u{10C2}', '\u{2D22}'),
+    ('\u{10C3}', '\u{2D23}'),
+    ('\u{10C4}', '\u{2D24}'),
+    ('\u{10C5}', '\u{2D25}'),
+    ('\u{10C7}', '\u{2D27}'),
+    ('\u{10CD}', '\u{2D2D}'),
+    ('\u{13F8}', '\u{13F0}'),
+    ('\u{13F9}', '\u{13F1}'),
+    ('\u{13FA}', '\u{13F2}'),
+    ('\u{13FB}', '\u{13F3}'),
+    ('\u{13FC}', '\u{13F4}'),
+    ('\u{13FD}', '\u{13F5}'),
+    ('\u{1C80}', '\u{0432}'),
+    ('\u{1C81}', '\u{0434}'),
+    ('\u{1C82}', '\u{043E}'),
+    ('\u{1C83}', '\u{0441}'),
+    ('\u{1C84}', '\u{0442}'),
+    ('\u{1C85}', '\u{0442}'),
+    ('\u{1C86}', '\u{044A}'),
+    ('\u{1C87}', '\u{0463}'),
+    ('\u{1C88}', '\u{A64B}'),
+    ('\u{1C90}', '\u{10D0}'),
+    ('\u{1C91}', '\u{10D1}'),
+    ('\u{1C92}', '\u{10D2}'),
+    ('\u{1C93}', '\u{10D3}'),
+    ('\u{1C94}', '\u{10D4}'),
+    ('\u{1C95}', '\u{10D5}'),
+    ('\u{1C96}', '\u{10D6}'),
+    ('\u{1C97}', '\u{10D7}'),
+    ('\u{1C98}', '\u{10D8}'),
+    ('\u{1C99}', '\u{10D9}'),
+    ('\u{1C9A}', '\u{10DA}'),
+    ('\u{1C9B}', '\u{10DB}'),
+    ('\u{1C9C}', '\u{10DC}'),
+    ('\u{1C9D}', '\u{10DD}'),
+    ('\u{1C9E}', '\u{10DE}'),
+    ('\u{1C9F}', '\u{10DF}'),
+    ('\u{1CA0}', '\u{10E0}'),
+    ('\u{1CA1}', '\u{10E1}'),
+    ('\u{1CA2}', '\u{10E2}'),
+    ('\u{1CA3}', '\u{10E3}'),
+    ('\u{1CA4}', '\u{10E4}'),
+    ('\u{1CA5}', '\u{10E5}'),
+    ('\u{1CA6}', '\u{10E6}'),
+    ('\u{1CA7}', '\u{10E7}'),
+    ('\u{1CA8}', '\u{10E8}'),
+    ('\u{1CA9}', '\u{10E9}'),
+    ('\u{1CAA}', '\u{10EA}'),
+    ('\u{1CAB}', '\u{10EB}'),
+    ('\u{1CAC}', '\u{10EC}'),
+    ('\u{1CAD}', '\u{10ED}'),
+    ('\u{1CAE}', '\u{10EE}'),
+    ('\u{1CAF}', '\u{10EF}'),
+    ('\u{1CB0}', '\u{10F0}'),
+    ('\u{1CB1}', '\u{10F1}'),
+    ('\u{1CB2}', '\u{10F2}'),
+    ('\u{1CB3}', '\u{10F3}'),
+    ('\u{1CB4}', '\u{10F4}'),
+    ('\u{1CB5}', '\u{10F5}'),
+    ('\u{1CB6}', '\u{10F6}'),
+    ('\u{1CB7}', '\u{10F7}'),
+    ('\u{1CB8}', '\u{10F8}'),
+    ('\u{1CB9}', '\u{10F9}'),
+    ('\u{1CBA}', '\u{10FA}'),
+    ('\u{1CBD}', '\u{10FD}'),
+    ('\u{1CBE}', '\u{10FE}'),
+    ('\u{1CBF}', '\u{10FF}'),
+    ('\u{1E00}', '\u{1E01}'),
+    ('\u{1E02}', '\u{1E03}'),
+    ('\u{1E04}', '\u{1E05}'),
+    ('\u{1E06}', '\u{1E07}'),
+    ('\u{1E08}', '\u{1E09}'),
+    ('\u{1E0A}', '\u{1E0B}'),
+    ('\u{1E0C}', '\u{1E0D}'),
+    ('\u{1E0E}', '\u{1E0F}'),
+    ('\u{1E10}', '\u{1E11}'),
+    ('\u{1E12}', '\u{1E13}'),
+    ('\u{1E14}', '\u{1E15}'),
+    ('\u{1E16}', '\u{1E17}'),
+    ('\u{1E18}', '\u{1E19}'),
+    ('\u{1E1A}', '\u{1E1B}'),
+    ('\u{1E1C}', '\u{1E1D}'),
+    ('\u{1E1E}', '\u{1E1F}'),
+    ('\u{1E20}', '\u{1E21}'),
+    ('\u{1E22}', '\u{1E23}'),
+    ('\u{1E24}', '\u{1E25}'),
+    ('\u{1E26}', '\u{1E27}'),
+    ('\u{1E28}', '\u{1E29}'),
+    ('\u{1E2A}', '\u{1E2B}'),
+    ('\u{1E2C}', '\u{1E2D}'),
+    ('\u{1E2E}', '\u{1E2F}'),
+    ('\u{1E30}', '\u{1E31}'),
+    ('\u{1E32}', '\u{1E33}'),
+    ('\u{1E34}', '\u{1E35}'),
+    ('\u{1E36}', '\u{1E37}'),
+    ('\u{1E38}', '\u{1E39}'),
+    ('\u{1E3A}', '\u{1E3B}'),
+    ('\u{1E3C}', '\u{1E3D}'),
+    ('\u{1E3E}', '\u{1E3F}'),
+    ('\u{1E40}', '\u{1E41}'),
+    ('\u{1E42}', '\u{1E43}'),
+    ('\u{1E44}', '\u{1E45}'),
+    ('\u{1E46}', '\u{1E47}'),
+    ('\u{1E48}', '\u{1E49}'),
+    ('\u{1E4A}', '\u{1E4B}'),
+    ('\u{1E4C}', '\u{1E4D}'),
+    ('\u{1E4E}', '\u{1E4F}'),
+    ('\u{1E50}', '\u{1E51}'),
+    ('\u{1E52}', '\u{1E53}'),
+    ('\u{1E54}', '\u{1E55}'),
+    ('\u{1E56}', '\u{1E57}'),
+    ('\u{1E58}', '\u{1E59}'),
+    ('\u{1E5A}', '\u{1E5B}'),
+    ('\u{1E5C}', '\u{1E5D}'),
+    ('\u{1E5E}', '\u{1E5F}'),
+    ('\u{1E60}', '\u{1E61}'),
+    ('\u{1E62}', '\u{1E63}'),
+    ('\u{1E64}', '\u{1E65}'),
+    ('\u{1E66}', '\u{1E67}'),
+    ('\u{1E68}', '\u{1E69}'),
+    ('\u{1E6A}', '\u{1E6B}'),
+    ('\u{1E6C}', '\u{1E6D}'),
+    ('\u{1E6E}', '\u{1E6F}'),
+    ('\u{1E70}', '\u{1E71}'),
+    ('\u{1E72}', '\u{1E73}'),
+    ('\u{1E74}', '\u{1E75}'),
+    ('\u{1E76}', '\u{1E77}'),
+    ('\u{1E78}', '\u{1E79}'),
+    ('\u{1E7A}', '\u{1E7B}'),
+    ('\u{1E7C}', '\u{1E7D}'),
+    ('\u{1E7E}', '\u{1E7F}'),
+    ('\u{1E80}', '\u{1E81}'),
+    ('\u{1E82}', '\u{1E83}'),
+    ('\u{1E84}', '\u{1E85}'),
+    ('\u{1E86}', '\u{1E87}'),
+    ('\u{1E88}', '\u{1E89}'),
+    ('\u{1E8A}', '\u{1E8B}'),
+    ('\u{1E8C}', '\u{1E8D}'),
+    ('\u{1E8E}', '\u{1E8F}'),
+    ('\u{1E90}', '\u{1E91}'),
+    ('\u{1E92}', '\u{1E93}'),
+    ('\u{1E94}', '\u{1E95}'),
+    ('\u{1E9B}', '\u{1E61}'),
+    ('\u{1E9E}', '\u{00DF}'),
+    ('\u{1EA0}', '\u{1EA1}'),
+    ('\u{1EA2}', '\u{1EA3}'),
+    ('\u{1EA4}', '\u{1EA5}'),
+    ('\u{1EA6}', '\u{1EA7}'),
+    ('\u{1EA8}', '\u{1EA9}'),
+    ('\u{1EAA}', '\u{1EAB}'),
+    ('\u{1EAC}', '\u{1EAD}'),
+    ('\u{1EAE}', '\u{1EAF}'),
+    ('\u{1EB0}', '\u{1EB1}'),
+    ('\u{1EB2}', '\u{1EB3}'),
+    ('\u{1EB4}', '\u{1EB5}'),
+    ('\u{1EB6}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{1EB9}'),
+    ('\u{1EBA}', '\u{1EBB}'),
+    ('\u{1EBC}', '\u{1EBD}'),
+    ('\u{1EBE}', '\u{1EBF}'),
+    ('\u{1EC0}', '\u{1EC1}'),
+    ('\u{1EC2}', '\u{1EC3}'),
+    ('\u{1EC4}', '\u{1EC5}'),
+    ('\u{1EC6}', '\u{1EC7}'),
+    ('\u{1EC8}', '\u{1EC9}'),
+    ('\u{1ECA}', '\u{1ECB}'),
+    ('\u{1ECC}', '\u{1ECD}'),
+    ('\u{1ECE}', '\u{1ECF}'),
+    ('\u{1ED0}', '\u{1ED1}'),
+    ('\u{1ED2}', '\u{1ED3}'),
+    ('\u{1ED4}', '\u{1ED5}'),
+    ('\u{1ED6}', '\u{1ED7}'),
+    ('\u{1ED8}', '\u{1ED9}'),
+    ('\u{1EDA}', '\u{1EDB}'),
+    ('\u{1EDC}', '\u{1EDD}'),
+    ('\u{1EDE}', '\u{1EDF}'),
+    ('\u{1EE0}', '\u{1EE1}'),
+    ('\u{1EE2}', '\u{1EE3}'),
+    ('\u{1EE4}', '\u{1EE5}'),
+    ('\u{1EE6}', '\u{1EE7}'),
+    ('\u{1EE8}', '\u{1EE9}'),
+    ('\u{1EEA}', '\u{1EEB}'),
+    ('\u{1EEC}', '\u{1EED}'),
+    ('\u{1EEE}', '\u{1EEF}'),
+    ('\u{1EF0}', '\u{1EF1}'),
+    ('\u{1EF2}', '\u{1EF3}'),
+    ('\u{1EF4}', '\u{1EF5}'),
+    ('\u{1EF6}', '\u{1EF7}'),
+    ('\u{1EF8}', '\u{1EF9}'),
+    ('\u{1EFA}', '\u{1EFB}'),
+    ('\u{1EFC}', '\u{1EFD}'),
+    ('\u{1EFE}', '\u{1EFF}'),
+    ('\u{1F08}', '\u{1F00}'),
+    ('\u{1F09}', '\u{1F01}'),
+    ('\u{1F0A}', '\u{1F02}'),
+    ('\u{1F0B}', '\u{1F03}'),
+    ('\u{1F0C}', '\u{1F04}'),
+    ('\u{1F0D}', '\u{1F05}'),
+    ('\u{1F0E}', '\u{1F06}'),
+    ('\u{1F0F}', '\u{1F07}'),
+    ('\u{1F18}', '\u{1F10}'),
+    ('\u{1F19}', '\u{1F11}'),
+    ('\u{1F1A}', '\u{1F12}'),
+    ('\u{1F1B}', '\u{1F13}'),
+    ('\u{1F1C}', '\u{1F14}'),
+    ('\u{1F1D}', '\u{1F15}'),
+    ('\u{1F28}', '\u{1F20}'),
+    ('\u{1F29}', '\u{1F21}'),
+    ('\u{1F2A}', '\u{1F22}'),
+    ('\u{1F2B}', '\u{1F23}'),
+    ('\u{1F2C}', '\u{1F24}'),
+    ('\u{1F2D}', '\u{1F25}'),
+    ('\u{1F2E}', '\u{1F26}'),
+    ('\u{1F2F}', '\u{1F27}'),
+    ('\u{1F38}', '\u{1F30}'),
+    ('\u{1F39}', '\u{1F31}'),
+    ('\u{1F3A}', '\u{1F32}'),
+    ('\u{1F3B}', '\u{1F33}'),
+    ('\u{1F3C}', '\u{1F34}'),
+    ('\u{1F3D}', '\u{1F35}'),
+    ('\u{1F3E}', '\u{1F36}'),
+    ('\u{1F3F}', '\u{1F37}'),
+    ('\u{1F48}', '\u{1F40}'),
+    ('\u{1F49}', '\u{1F41}'),
+    ('\u{1F4A}', '\u{1F42}'),
+    ('\u{1F4B}', '\u{1F43}'),
+    ('\u{1F4C}', '\u{1F44}'),
+    ('\u{1F4D}', '\u{1F45}'),
+    ('\u{1F59}', '\u{1F51}'),
+    ('\u{1F5B}', '\u{1F53}'),
+    ('\u{1F5D}', '\u{1F55}'),
+    ('\u{1F5F}', '\u{1F57}'),
+    ('\u{1F68}', '\u{1F60}'),
+    ('\u{1F69}', '\u{1F61}'),
+    ('\u{1F6A}', '\u{1F62}'),
+    ('\u{1F6B}', '\u{1F63}'),
+    ('\u{1F6C}', '\u{1F64}'),
+    ('\u{1F6D}', '\u{1F65}'),
+    ('\u{1F6E}', '\u{1F66}'),
+    ('\u{1F6F}', '\u{1F67}'),
+    ('\u{1F88}', '\u{1F80}'),
+    ('\u{1F89}', '\u{1F81}'),
+    ('\u{1F8A}', '\u{1F82}'),
+    ('\u{1F8B}', '\u{1F83}'),
+    ('\u{1F8C}', '\u{1F84}'),
+    ('\u{1F8D}', '\u{1F85}'),
+    ('\u{1F8E}', '\u{1F86}'),
+    ('\u{1F8F}', '\u{1F87}'),
+    ('\u{1F98}', '\u{1F90}'),
+    ('\u{1F99}', '\u{1F91}'),
+    ('\u{1F9A}', '\u{1F92}'),
+    ('\u{1F9B}', '\u{1F93}'),
+    ('\u{1F9C}', '\u{1F94}'),
+    ('\u{1F9D}', '\u{1F95}'),
+    ('\u{1F9E}', '\u{1F96}'),
+    ('\u{1F9F}', '\u{1F97}'),
+    ('\u{1FA8}', '\u{1FA0}'),
+    ('\u{1FA9}', '\u{1FA1}'),
+    ('\u{1FAA}', '\u{1FA2}'),
+    ('\u{1FAB}', '\u{1FA3}'),
+    ('\u{1FAC}', '\u{1FA4}'),
+    ('\u{1FAD}', '\u{1FA5}'),
+    ('\u{1FAE}', '\u{1FA6}'),
+    ('\u{1FAF}', '\u{1FA7}'),
+    ('\u{1FB8}', '\u{1FB0}'),
+    ('\u{1FB9}', '\u{1FB1}'),
+    ('\u{1FBA}', '\u{1F70}'),
+    ('\u{1FBB}', '\u{1F71}'),
+    ('\u{1FBC}', '\u{1FB3}'),
+    ('\u{1FBE}', '\u{03B9}'),
+    ('\u{1FC8}', '\u{1F72}'),
+    ('\u{1FC9}', '\u{1F73}'),
+    ('\u{1FCA}', '\u{1F74}'),
+    ('\u{1FCB}', '\u{1F75}'),
+    ('\u{1FCC}', '\u{1FC3}'),
+    ('\u{1FD8}', '\u{1FD0}'),
+    ('\u{1FD9}', '\u{1FD1}'),
+    ('\u{1FDA}', '\u{1F76}'),
+    ('\u{1FDB}', '\u{1F77}'),
+    ('\u{1FE8}', '\u{1FE0}'),
+    ('\u{1FE9}', '\u{1FE1}'),
+    ('\u{1FEA}', '\u{1F7A}'),
+    ('\u{1FEB}', '\u{1F7B}'),
+    ('\u{1FEC}', '\u{1FE5}'),
+    ('\u{1FF8}', '\u{1F78}'),
+    ('\u{1FF9}', '\u{1F79}'),
+    ('\u{1FFA}', '\u{1F7C}'),
+    ('\u{1FFB}', '\u{1F7D}'),
+    ('\u{1FFC}', '\u{1FF3}'),
+    ('\u{2126}', '\u{03C9}'),
+    ('\u{212A}', '\u{006B}'),
+    ('\u{212B}', '\u{00E5}'),
+    ('\u{2132}', '\u{214E}'),
+    ('\u{2160}', '\u{2170}'),
+    ('\u{2161}', '\u{2171}'),
+    ('\u{2162}', '\u{2172}'),
+    ('\u{2163}', '\u{2173}'),
+    ('\u{2164}', '\u{2174}'),
+    ('\u{2165}', '\u{2175}'),
+    ('\u{2166}', '\u{2176}'),
+    ('\u{2167}', '\u{2177}'),
+    ('\u{2168}', '\u{2178}'),
+    ('\u{2169}', '\u{2179}'),
+    ('\u{216A}', '\u{217A}'),
+    ('\u{216B}', '\u{217B}'),
+    ('\u{216C}', '\u{217C}'),
+    ('\u{216D}', '\u{217D}'),
+    ('\u{216E}', '\u{217E}'),
+    ('\u{216F}', '\u{217F}'),
+    ('\u{2183}', '\u{2184}'),
+    ('\u{24B6}', '\u{24D0}'),
+    ('\u{24B7}', '\u{24D1}'),
+    ('\u{24B8}', '\u{24D2}'),
+    ('\u{24B9}', '\u{24D3}'),
+    ('\u{24BA}', '\u{24D4}'),
+    ('\u{24BB}', '\u{24D5}'),
+    ('\u{24BC}', '\u{24D6}'),
+    ('\u{24BD}', '\u{24D7}'),
+    ('\u{24BE}', '\u{24D8}'),
+    ('\u{24BF}', '\u{24D9}'),
+    ('\u{24C0}', '\u{24DA}'),
+    ('\u{24C1}', '\u{24DB}'),
+    ('\u{24C2}', '\u{24DC}'),
+    ('\u{24C3}', '\u{24DD}'),
+    ('\u{24C4}', '\u{24DE}'),
+    ('\u{24C5}', '\u{24DF}'),
+    ('\u{24C6}', '\u{24E0}'),
+    ('\u{24C7}', '\u{24E1}'),
+    ('\u{24C8}', '\u{24E2}'),
+    ('\u{24C9}', '\u{24E3}'),
+    ('\u{24CA}', '\u{24E4}'),
+    ('\u{24CB}', '\u{24E5}'),
+    ('\u{24CC}', '\u{24E6}'),
+    ('\u{24CD}', '\u{24E7}'),
+    ('\u{24CE}', '\u{24E8}'),
+    ('\u{24CF}', '\u{24E9}'),
+    ('\u{2C00}', '\u{2C30}'),
+    ('\u{2C01}', '\u{2C31}'),
+    ('\u{2C02}', '\u{2C32}'),
+    ('\u{2C03}', '\u{2C33}'),
+    ('\u{2C04}', '\u{2C34}'),
+    ('\u{2C05}', '\u{2C35}'),
+    ('\u{2C06}', '\u{2C36}'),
+    ('\u{2C07}', '\u{2C37}'),
+    ('\u{2C08}', '\u{2C38}'),
+    ('\u{2C09}', '\u{2C39}'),
+    ('\u{2C0A}', '\u{2C3A}'),
+    ('\u{2C0B}', '\u{2C3B}'),
+    ('\u{2C0C}', '\u{2C3C}'),
+    ('\u{2C0D}', '\u{2C3D}'),
+    ('\u{2C0E}', '\u{2C3E}'),
+    ('\u{2C0F}', '\u{2C3F}'),
+    ('\u{2C10}', '\u{2C40}'),
+    ('\u{2C11}', '\u{2C41}'),
+    ('\u{2C12}', '\u{2C42}'),
+    ('\u{2C13}', '\u{2C43}'),
+    ('\u{2C14}', '\u{2C44}'),
+    ('\u{2C15}', '\u{2C45}'),
+    ('\u{2C16}', '\u{2C46}'),
+    ('\u{2C17}', '\u{2C47}'),
+    ('\u{2C18}', '\u{2C48}'),
+    ('\u{2C19}', '\u{2C49}'),
+    ('\u{2C1A}', '\u{2C4A}'),
+    ('\u{2C1B}', '\u{2C4B}'),
+    ('\u{2C1C}', '\u{2C4C}'),
+    ('\u{2C1D}', '\u{2C4D}'),
+    ('\u{2C1E}', '\u{2C4E}'),
+    ('\u{2C1F}', '\u{2C4F}'),
+    ('\u{2C20}', '\u{2C50}'),
+    ('\u{2C21}', '\u{2C51}'),
+    ('\u{2C22}', '\u{2C52}'),
+    ('\u{2C23}', '\u{2C53}'),
+    ('\u{2C24}', '\u{2C54}'),
+    ('\u{2C25}', '\u{2C55}'),
+    ('\u{2C26}', '\u{2C56}'),
+    ('\u{2C27}', '\u{2C57}'),
+    ('\u{2C28}', '\u{2C58}'),
+    ('\u{2C29}', '\u{2C59}'),
+    ('\u{2C2A}', '\u{2C5A}'),
+    ('\u{2C2B}', '\u{2C5B}'),
+    ('\u{2C2C}', '\u{2C5C}'),
+    ('\u{2C2D}', '\u{2C5D}'),
+    ('\u{2C2E}', '\u{2C5E}'),
+    ('\u{2C2F}', '\u{2C5F}'),
+    ('\u{2C60}', '\u{2C61}'),
+    ('\u{2C62}', '\u{026B}'),
+    ('\u{2C63}', '\u{1D7D}'),
+    ('\u{2C64}', '\u{027D}'),
+    ('\u{2C67}', '\u{2C68}'),
+    ('\u{2C69}', '\u{2C6A}'),
+    ('\u{2C6B}', '\u{2C6C}'),
+    ('\u{2C6D}', '\u{0251}'),
+    ('\u{2C6E}', '\u{0271}'),
+    ('\u{2C6F}', '\u{0250}'),
+    ('\u{2C70}', '\u{0252}'),
+    ('\u{2C72}', '\u{2C73}'),
+    ('\u{2C75}', '\u{2C76}'),
+    ('\u{2C7E}', '\u{023F}'),
+    ('\u{2C7F}', '\u{0240}'),
+    ('\u{2C80}', '\u{2C81}'),
+    ('\u{2C82}', '\u{2C83}'),
+    ('\u{2C84}', '\u{2C85}'),
+    ('\u{2C86}', '\u{2C87}'),
+    ('\u{2C88}', '\u{2C89}'),
+    ('\u{2C8A}', '\u{2C8B}'),
+    ('\u{2C8C}', '\u{2C8D}'),
+    ('\u{2C8E}', '\u{2C8F}'),
+    ('\u{2C90}', '\u{2C91}'),
+    ('\u{2C92}', '\u{2C93}'),
+    ('\u{2C94}', '\u{2C95}'),
+    ('\u{2C96}', '\u{2C97}'),
+    ('\u{2C98}', '\u{2C99}'),
+    ('\u{2C9A}', '\u{2C9B}'),
+    ('\u{2C9C}', '\u{2C9D}'),
+    ('\u{2C9E}', '\u{2C9F}'),
+    ('\u{2CA0}', '\u{2CA1}'),
+    ('\u{2CA2}', '\u{2CA3}'),
+    ('\u{2CA4}', '\u{2CA5}'),
+    ('\u{2CA6}', '\u{2CA7}'),
+    ('\u{2CA8}', '\u{2CA9}'),
+    ('\u{2CAA}', '\u{2CAB}'),
+    ('\u{2CAC}', '\u{2CAD}'),
+    ('\u{2CAE}', '\u{2CAF}'),
+    ('\u{2CB0}', '\u{2CB1}'),
+    ('\u{2CB2}', '\u{2CB3}'),
+    ('\u{2CB4}', '\u{2CB5}'),
+    ('\u{2CB6}', '\u{2CB7}'),
+    ('\u{2CB8}', '\u{2CB9}'),
+    ('\u{2CBA}', '\u{2CBB}'),
+    ('\u{2CBC}', '\u{2CBD}'),
+    ('\u{2CBE}', '\u{2CBF}'),
+    ('\u{2CC0}', '\u{2CC1}'),
+    ('\u{2CC2}', '\u{2CC3}'),
+    ('\u{2CC4}', '\u{2CC5}'),
+    ('\u{2CC6}', '\u{2CC7}'),
+    ('\u{2CC8}', '\u{2CC9}'),
+    ('\u{2CCA}', '\u{2CCB}'),
+    ('\u{2CCC}', '\u{2CCD}'),
+    ('\u{2CCE}', '\u{2CCF}'),
+    ('\u{2CD0}', '\u{2CD1}'),
+    ('\u{2CD2}', '\u{2CD3}'),
+    ('\u{2CD4}', '\u{2CD5}'),
+    ('\u{2CD6}', '\u{2CD7}'),
+    ('\u{2CD8}', '\u{2CD9}'),
+    ('\u{2CDA}', '\u{2CDB}'),
+    ('\u{2CDC}', '\u{2CDD}'),
+    ('\u{2CDE}', '\u{2CDF}'),
+    ('\u{2CE0}', '\u{2CE1}'),
+    ('\u{2CE2}', '\u{2CE3}'),
+    ('\u{2CEB}', '\u{2CEC}'),
+    ('\u{2CED}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{A640}', '\u{A641}'),
+    ('\u{A642}', '\u{A643}'),
+    ('\u{A644}', '\u{A645}'),
+    ('\u{A646}', '\u{A647}'),
+    ('\u{A648}', '\u{A649}'),
+    ('\u{A64A}', '\u{A64B}'),
+    ('\u{A64C}', '\u{A64D}'),
+    ('\u{A64E}', '\u{A64F}'),
+    ('\u{A650}', '\u{A651}'),
+    ('\u{A652}', '\u{A653}'),
+    ('\u{A654}', '\u{A655}'),
+    ('\u{A656}', '\u{A657}'),
+    ('\u{A658}', '\u{A659}'),
+    ('\u{A65A}', '\u{A65B}'),
+    ('\u{A65C}', '\u{A65D}'),
+    ('\u{A65E}', '\u{A65F}'),
+    ('\u{A660}', '\u{A661}'),
+    ('\u{A662}', '\u{A663}'),
+    ('\u{A664}', '\u{A665}'),
+    ('\u{A666}', '\u{A667}'),
+    ('\u{A668}', '\u{A669}'),
+    ('\u{A66A}', '\u{A66B}'),
+    ('\u{A66C}', '\u{A66D}'),
+    ('\u{A680}', '\u{A681}'),
+    ('\u{A682}', '\u{A683}'),
+    ('\u{A684}', '\u{A685}'),
+    ('\u{A686}', '\u{A687}'),
+    ('\u{A688}', '\u{A689}'),
+    ('\u{A68A}', '\u{A68B}'),
+    ('\u{A68C}', '\u{A68D}'),
+    ('\u{A68E}', '\u{A68F}'),
+    ('\u{A690}', '\u{A691}'),
+    ('\u{A692}', '\u{A693}'),
+    ('\u{A694}', '\u{A695}'),
+    ('\u{A696}', '\u{A697}'),
+    ('\u{A698}', '\u{A699}'),
+    ('\u{A69A}', '\u{A69B}'),
+    ('\u{A722}', '\u{A723}'),
+    ('\u{A724}', '\u{A725}'),
+    ('\u{A726}', '\u{A727}'),
+    ('\u{A728}', '\u{A729}'),
+    ('\u{A72A}', '\u{A72B}'),
+    ('\u{A72C}', '\u{A72D}'),
+    ('\u{A72E}', '\u{A72F}'),
+    ('\u{A732}', '\u{A733}'),
+    ('\u{A734}', '\u{A735}'),
+    ('\u{A736}', '\u{A737}'),
+    ('\u{A738}', '\u{A739}'),
+    ('\u{A73A}', '\u{A73B}'),
+    ('\u{A73C}', '\u{A73D}'),
+    ('\u{A73E}', '\u{A73F}'),
+    ('\u{A740}', '\u{A741}'),
+    ('\u{A742}', '\u{A743}'),
+    ('\u{A744}', '\u{A745}'),
+    ('\u{A746}', '\u{A747}'),
+    ('\u{A748}', '\u{A749}'),
+    ('\u{A74A}', '\u{A74B}'),
+    ('\u{A74C}', '\u{A74D}'),
+    ('\u{A74E}', '\u{A74F}'),
+    ('\u{A750}', '\u{A751}'),
+    ('\u{A752}', '\u{A753}'),
+    ('\u{A754}', '\u{A755}'),
+    ('\u{A756}', '\u{A757}'),
+    ('\u{A758}', '\u{A759}'),
+    ('\u{A75A}', '\u{A75B}'),
+    ('\u{A75C}', '\u{A75D}'),
+    ('\u{A75E}', '\u{A75F}'),
+    ('\u{A760}', '\u{A761}'),
+    ('\u{A762}', '\u{A763}'),
+    ('\u{A764}', '\u{A765}'),
+    ('\u{A766}', '\u{A767}'),
+    ('\u{A768}', '\u{A769}'),
+    ('\u{A76A}', '\u{A76B}'),
+    ('\u{A76C}', '\u{A76D}'),
+    ('\u{A76E}', '\u{A76F}'),
+    ('\u{A779}', '\u{A77A}'),
+    ('\u{A77B}', '\u{A77C}'),
+    ('\u{A77D}', '\u{1D79}'),
+    ('\u{A77E}', '\u{A77F}'),
+    ('\u{A780}', '\u{A781}'),
+    ('\u{A782}', '\u{A783}'),
+    ('\u{A784}', '\u{A785}'),
+    ('\u{A786}', '\u{A787}'),
+    ('\u{A78B}', '\u{A78C}'),
+    ('\u{A78D}', '\u{0265}'),
+    ('\u{A790}', '\u{A791}'),
+    ('\u{A792}', '\u{A793}'),
+    ('\u{A796}', '\u{A797}'),
+    ('\u{A798}', '\u{A799}'),
+    ('\u{A79A}', '\u{A79B}'),
+    ('\u{A79C}', '\u{A79D}'),
+    ('\u{A79E}', '\u{A79F}'),
+    ('\u{A7A0}', '\u{A7A1}'),
+    ('\u{A7A2}', '\u{A7A3}'),
+    ('\u{A7A4}', '\u{A7A5}'),
+    ('\u{A7A6}', '\u{A7A7}'),
+    ('\u{A7A8}', '\u{A7A9}'),
+    ('\u{A7AA}', '\u{0266}'),
+    ('\u{A7AB}', '\u{025C}'),
+    ('\u{A7AC}', '\u{0261}'),
+    ('\u{A7AD}', '\u{026C}'),
+    ('\u{A7AE}', '\u{026A}'),
+    ('\u{A7B0}', '\u{029E}'),
+    ('\u{A7B1}', '\u{0287}'),
+    ('\u{A7B2}', '\u{029D}'),
+    ('\u{A7B3}', '\u{AB53}'),
+    ('\u{A7B4}', '\u{A7B5}'),
+    ('\u{A7B6}', '\u{A7B7}'),
+    ('\u{A7B8}', '\u{A7B9}'),
+    ('\u{A7BA}', '\u{A7BB}'),
+    ('\u{A7BC}', '\u{A7BD}'),
+    ('\u{A7BE}', '\u{A7BF}'),
+    ('\u{A7C0}', '\u{A7C1}'),
+    ('\u{A7C2}', '\u{A7C3}'),
+    ('\u{A7C4}', '\u{A794}'),
+    ('\u{A7C5}', '\u{0282}'),
+    ('\u{A7C6}', '\u{1D8E}'),
+    ('\u{A7C7}', '\u{A7C8}'),
+    ('\u{A7C9}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D6}', '\u{A7D7}'),
+    ('\u{A7D8}', '\u{A7D9}'),
+    ('\u{A7F5}', '\u{A7F6}'),
+    ('\u{AB70}', '\u{13A0}'),
+    ('\u{AB71}', '\u{13A1}'),
+    ('\u{AB72}', '\u{13A2}'),
+    ('\u{AB73}', '\u{13A3}'),
+    ('\u{AB74}', '\u{13A4}'),
+    ('\u{AB75}', '\u{13A5}'),
+    ('\u{AB76}', '\u{13A6}'),
+    ('\u{AB77}', '\u{13A7}'),
+    ('\u{AB78}', '\u{13A8}'),
+    ('\u{AB79}', '\u{13A9}'),
+    ('\u{AB7A}', '\u{13AA}'),
+    ('\u{AB7B}', '\u{13AB}'),
+    ('\u{AB7C}', '\u{13AC}'),
+    ('\u{AB7D}', '\u{13AD}'),
+    ('\u{AB7E}', '\u{13AE}'),
+    ('\u{AB7F}', '\u{13AF}'),
+    ('\u{AB80}', '\u{13B0}'),
+    ('\u{AB81}', '\u{13B1}'),
+    ('\u{AB82}', '\u{13B2}'),
+    ('\u{AB83}', '\u{13B3}'),
+    ('\u{AB84}', '\u{13B4}'),
+    ('\u{AB85}', '\u{13B5}'),
+    ('\u{AB86}', '\u{13B6}'),
+    ('\u{AB87}', '\u{13B7}'),
+    ('\u{AB88}', '\u{13B8}'),
+    ('\u{AB89}', '\u{13B9}'),
+    ('\u{AB8A}', '\u{13BA}'),
+    ('\u{AB8B}', '\u{13BB}'),
+    ('\u{AB8C}', '\u{13BC}'),
+    ('\u{AB8D}', '\u{13BD}'),
+    ('\u{AB8E}', '\u{13BE}'),
+    ('\u{AB8F}', '\u{13BF}'),
+    ('\u{AB90}', '\u{13C0}'),
+    ('\u{AB91}', '\u{13C1}'),
+    ('\u{AB92}', '\u{13C2}'),
+    ('\u{AB93}', '\u{13C3}'),
+    ('\u{AB94}', '\u{13C4}'),
+    ('\u{AB95}', '\u{13C5}'),
+    ('\u{AB96}', '\u{13C6}'),
+    ('\u{AB97}', '\u{13C7}'),
+    ('\u{AB98}', '\u{13C8}'),
+    ('\u{AB99}', '\u{13C9}'),
+    ('\u{AB9A}', '\u{13CA}'),
+    ('\u{AB9B}', '\u{13CB}'),
+    ('\u{AB9C}', '\u{13CC}'),
+    ('\u{AB9D}', '\u{13CD}'),
+    ('\u{AB9E}', '\u{13CE}'),
+    ('\u{AB9F}', '\u{13CF}'),
+    ('\u{ABA0}', '\u{13D0}'),
+    ('\u{ABA1}', '\u{13D1}'),
+    ('\u{ABA2}', '\u{13D2}'),
+    ('\u{ABA3}', '\u{13D3}'),
+    ('\u{ABA4}', '\u{13D4}'),
+    ('\u{ABA5}', '\u{13D5}'),
+    ('\u{ABA6}', '\u{13D6}'),
+    ('\u{ABA7}', '\u{13D7}'),
+    ('\u{ABA8}', '\u{13D8}'),
+    ('\u{ABA9}', '\u{13D9}'),
+    ('\u{ABAA}', '\u{13DA}'),
+    ('\u{ABAB}', '\u{13DB}'),
+    ('\u{ABAC}', '\u{13DC}'),
+    ('\u{ABAD}', '\u{13DD}'),
+    ('\u{ABAE}', '\u{13DE}'),
+    ('\u{ABAF}', '\u{13DF}'),
+    ('\u{ABB0}', '\u{13E0}'),
+    ('\u{ABB1}', '\u{13E1}'),
+    ('\u{ABB2}', '\u{13E2}'),
+    ('\u{ABB3}', '\u{13E3}'),
+    ('\u{ABB4}', '\u{13E4}'),
+    ('\u{ABB5}', '\u{13E5}'),
+    ('\u{ABB6}', '\u{13E6}'),
+    ('\u{ABB7}', '\u{13E7}'),
+    ('\u{ABB8}', '\u{13E8}'),
+    ('\u{ABB9}', '\u{13E9}'),
+    ('\u{ABBA}', '\u{13EA}'),
+    ('\u{ABBB}', '\u{13EB}'),
+    ('\u{ABBC}', '\u{13EC}'),
+    ('\u{ABBD}', '\u{13ED}'),
+    ('\u{ABBE}', '\u{13EE}'),
+    ('\u{ABBF}', '\u{13EF}'),
+    ('\u{FF21}', '\u{FF41}'),
+    ('\u{FF22}', '\u{FF42}'),
+    ('\u{FF23}', '\u{FF43}'),
+    ('\u{FF24}', '\u{FF44}'),
+    ('\u{FF25}', '\u{FF45}'),
+    ('\u{FF26}', '\u{FF46}'),
+    ('\u{FF27}', '\u{FF47}'),
+    ('\u{FF28}', '\u{FF48}'),
+    ('\u{FF29}', '\u{FF49}'),
+    ('\u{FF2A}', '\u{FF4A}'),
+    ('\u{FF2B}', '\u{FF4B}'),
+    ('\u{FF2C}', '\u{FF4C}'),
+    ('\u{FF2D}', '\u{FF4D}'),
+    ('\u{FF2E}', '\u{FF4E}'),
+    ('\u{FF2F}', '\u{FF4F}'),
+    ('\u{FF30}', '\u{FF50}'),
+    ('\u{FF31}', '\u{FF51}'),
+    ('\u{FF32}', '\u{FF52}'),
+    ('\u{FF33}', '\u{FF53}'),
+    ('\u{FF34}', '\u{FF54}'),
+    ('\u{FF35}', '\u{FF55}'),
+    ('\u{FF36}', '\u{FF56}'),
+    ('\u{FF37}', '\u{FF57}'),
+    ('\u{FF38}', '\u{FF58}'),
+    ('\u{FF39}', '\u{FF59}'),
+    ('\u{FF3A}', '\u{FF5A}'),
+    ('\u{10400}', '\u{10428}'),
+    ('\u{10401}', '\u{10429}'),
+    ('\u{10402}', '\u{1042A}'),
+    ('\u{10403}', '\u{1042B}'),
+    ('\u{10404}', '\u{1042C}'),
+    ('\u{10405}', '\u{1042D}'),
+    ('\u{10406}', '\u{1042E}'),
+    ('\u{10407}', '\u{1042F}'),
+    ('\u{10408}', '\u{10430}'),
+    ('\u{10409}', '\u{10431}'),
+    ('\u{1040A}', '\u{10432}'),
+    ('\u{1040B}', '\u{10433}'),
+    ('\u{1040C}', '\u{10434}'),
+    ('\u{1040D}', '\u{10435}'),
+    ('\u{1040E}', '\u{10436}'),
+    ('\u{1040F}', '\u{10437}'),
+    ('\u{10410}', '\u{10438}'),
+    ('\u{10411}', '\u{10439}'),
+    ('\u{10412}', '\u{1043A}'),
+    ('\u{10413}', '\u{1043B}'),
+    ('\u{10414}', '\u{1043C}'),
+    ('\u{10415}', '\u{1043D}'),
+    ('\u{10416}', '\u{1043E}'),
+    ('\u{10417}', '\u{1043F}'),
+    ('\u{10418}', '\u{10440}'),
+    ('\u{10419}', '\u{10441}'),
+    ('\u{1041A}', '\u{10442}'),
+    ('\u{1041B}', '\u{10443}'),
+    ('\u{1041C}', '\u{10444}'),
+    ('\u{1041D}', '\u{10445}'),
+    ('\u{1041E}', '\u{10446}'),
+    ('\u{1041F}', '\u{10447}'),
+    ('\u{10420}', '\u{10448}'),
+    ('\u{10421}', '\u{10449}'),
+    ('\u{10422}', '\u{1044A}'),
+    ('\u{10423}', '\u{1044B}'),
+    ('\u{10424}', '\u{1044C}'),
+    ('\u{10425}', '\u{1044D}'),
+    ('\u{10426}', '\u{1044E}'),
+    ('\u{10427}', '\u{1044F}'),
+    ('\u{104B0}', '\u{104D8}'),
+    ('\u{104B1}', '\u{104D9}'),
+    ('\u{104B2}', '\u{104DA}'),
+    ('\u{104B3}', '\u{104DB}'),
+    ('\u{104B4}', '\u{104DC}'),
+    ('\u{104B5}', '\u{104DD}'),
+    ('\u{104B6}', '\u{104DE}'),
+    ('\u{104B7}', '\u{104DF}'),
+    ('\u{104B8}', '\u{104E0}'),
+    ('\u{104B9}', '\u{104E1}'),
+    ('\u{104BA}', '\u{104E2}'),
+    ('\u{104BB}', '\u{104E3}'),
+    ('\u{104BC}', '\u{104E4}'),
+    ('\u{104BD}', '\u{104E5}'),
+    ('\u{104BE}', '\u{104E6}'),
+    ('\u{104BF}', '\u{104E7}'),
+    ('\u{104C0}', '\u{104E8}'),
+    ('\u{104C1}', '\u{104E9}'),
+    ('\u{104C2}', '\u{104EA}'),
+    ('\u{104C3}', '\u{104EB}'),
+    ('\u{104C4}', '\u{104EC}'),
+    ('\u{104C5}', '\u{104ED}'),
+    ('\u{104C6}', '\u{104EE}'),
+    ('\u{104C7}', '\u{104EF}'),
+    ('\u{104C8}', '\u{104F0}'),
+    ('\u{104C9}', '\u{104F1}'),
+    ('\u{104CA}', '\u{104F2}'),
+    ('\u{104CB}', '\u{104F3}'),
+    ('\u{104CC}', '\u{104F4}'),
+    ('\u{104CD}', '\u{104F5}'),
+    ('\u{104CE}', '\u{104F6}'),
+    ('\u{104CF}', '\u{104F7}'),
+    ('\u{104D0}', '\u{104F8}'),
+    ('\u{104D1}', '\u{104F9}'),
+    ('\u{104D2}', '\u{104FA}'),
+    ('\u{104D3}', '\u{104FB}'),
+    ('\u{10570}', '\u{10597}'),
+    ('\u{10571}', '\u{10598}'),
+    ('\u{10572}', '\u{10599}'),
+    ('\u{10573}', '\u{1059A}'),
+    ('\u{10574}', '\u{1059B}'),
+    ('\u{10575}', '\u{1059C}'),
+    ('\u{10576}', '\u{1059D}'),
+    ('\u{10577}', '\u{1059E}'),
+    ('\u{10578}', '\u{1059F}'),
+    ('\u{10579}', '\u{105A0}'),
+    ('\u{1057A}', '\u{105A1}'),
+    ('\u{1057C}', '\u{105A3}'),
+    ('\u{1057D}', '\u{105A4}'),
+    ('\u{1057E}', '\u{105A5}'),
+    ('\u{1057F}', '\u{105A6}'),
+    ('\u{10580}', '\u{105A7}'),
+    ('\u{10581}', '\u{105A8}'),
+    ('\u{10582}', '\u{105A9}'),
+    ('\u{10583}', '\u{105AA}'),
+    ('\u{10584}', '\u{105AB}'),
+    ('\u{10585}', '\u{105AC}'),
+    ('\u{10586}', '\u{105AD}'),
+    ('\u{10587}', '\u{105AE}'),
+    ('\u{10588}', '\u{105AF}'),
+    ('\u{10589}', '\u{105B0}'),
+    ('\u{1058A}', '\u{105B1}'),
+    ('\u{1058C}', '\u{105B3}'),
+    ('\u{1058D}', '\u{105B4}'),
+    ('\u{1058E}', '\u{105B5}'),
+    ('\u{1058F}', '\u{105B6}'),
+    ('\u{10590}', '\u{105B7}'),
+    ('\u{10591}', '\u{105B8}'),
+    ('\u{10592}', '\u{105B9}'),
+    ('\u{10594}', '\u{105BB}'),
+    ('\u{10595}', '\u{105BC}'),
+    ('\u{10C80}', '\u{10CC0}'),
+    ('\u{10C81}', '\u{10CC1}'),
+    ('\u{10C82}', '\u{10CC2}'),
+    ('\u{10C83}', '\u{10CC3}'),
+    ('\u{10C84}', '\u{10CC4}'),
+    ('\u{10C85}', '\u{10CC5}'),
+    ('\u{10C86}', '\u{10CC6}'),
+    ('\u{10C87}', '\u{10CC7}'),
+    ('\u{10C88}', '\u{10CC8}'),
+    ('\u{10C89}', '\u{10CC9}'),
+    ('\u{10C8A}', '\u{10CCA}'),
+    ('\u{10C8B}', '\u{10CCB}'),
+    ('\u{10C8C}', '\u{10CCC}'),
+    ('\u{10C8D}', '\u{10CCD}'),
+    ('\u{10C8E}', '\u{10CCE}'),
+    ('\u{10C8F}', '\u{10CCF}'),
+    ('\u{10C90}', '\u{10CD0}'),
+    ('\u{10C91}', '\u{10CD1}'),
+    ('\u{10C92}', '\u{10CD2}'),
+    ('\u{10C93}', '\u{10CD3}'),
+    ('\u{10C94}', '\u{10CD4}'),
+    ('\u{10C95}', '\u{10CD5}'),
+    ('\u{10C96}', '\u{10CD6}'),
+    ('\u{10C97}', '\u{10CD7}'),
+    ('\u{10C98}', '\u{10CD8}'),
+    ('\u{10C99}', '\u{10CD9}'),
+    ('\u{10C9A}', '\u{10CDA}'),
+    ('\u{10C9B}', '\u{10CDB}'),
+    ('\u{10C9C}', '\u{10CDC}'),
+    ('\u{10C9D}', '\u{10CDD}'),
+    ('\u{10C9E}', '\u{10CDE}'),
+    ('\u{10C9F}', '\u{10CDF}'),
+    ('\u{10CA0}', '\u{10CE0}'),
+    ('\u{10CA1}', '\u{10CE1}'),
+    ('\u{10CA2}', '\u{10CE2}'),
+    ('\u{10CA3}', '\u{10CE3}'),
+    ('\u{10CA4}', '\u{10CE4}'),
+    ('\u{10CA5}', '\u{10CE5}'),
+    ('\u{10CA6}', '\u{10CE6}'),
+    ('\u{10CA7}', '\u{10CE7}'),
+    ('\u{10CA8}', '\u{10CE8}'),
+    ('\u{10CA9}', '\u{10CE9}'),
+    ('\u{10CAA}', '\u{10CEA}'),
+    ('\u{10CAB}', '\u{10CEB}'),
+    ('\u{10CAC}', '\u{10CEC}'),
+    ('\u{10CAD}', '\u{10CED}'),
+    ('\u{10CAE}', '\u{10CEE}'),
+    ('\u{10CAF}', '\u{10CEF}'),
+    ('\u{10CB0}', '\u{10CF0}'),
+    ('\u{10CB1}', '\u{10CF1}'),
+    ('\u{10CB2}', '\u{10CF2}'),
+    ('\u{118A0}', '\u{118C0}'),
+    ('\u{118A1}', '\u{118C1}'),
+    ('\u{118A2}', '\u{118C2}'),
+    ('\u{118A3}', '\u{118C3}'),
+    ('\u{118A4}', '\u{118C4}'),
+    ('\u{118A5}', '\u{118C5}'),
+    ('\u{118A6}', '\u{118C6}'),
+    ('\u{118A7}', '\u{118C7}'),
+    ('\u{118A8}', '\u{118C8}'),
+    ('\u{118A9}', '\u{118C9}'),
+    ('\u{118AA}', '\u{118CA}'),
+    ('\u{118AB}', '\u{118CB}'),
+    ('\u{118AC}', '\u{118CC}'),
+    ('\u{118AD}', '\u{118CD}'),
+    ('\u{118AE}', '\u{118CE}'),
+    ('\u{118AF}', '\u{118CF}'),
+    ('\u{118B0}', '\u{118D0}'),
+    ('\u{118B1}', '\u{118D1}'),
+    ('\u{118B2}', '\u{118D2}'),
+    ('\u{118B3}', '\u{118D3}'),
+    ('\u{118B4}', '\u{118D4}'),
+    ('\u{118B5}', '\u{118D5}'),
+    ('\u{118B6}', '\u{118D6}'),
+    ('\u{118B7}', '\u{118D7}'),
+    ('\u{118B8}', '\u{118D8}'),
+    ('\u{118B9}', '\u{118D9}'),
+    ('\u{118BA}', '\u{118DA}'),
+    ('\u{118BB}', '\u{118DB}'),
+    ('\u{118BC}', '\u{118DC}'),
+    ('\u{118BD}', '\u{118DD}'),
+    ('\u{118BE}', '\u{118DE}'),
+    ('\u{118BF}', '\u{118DF}'),
+    ('\u{16E40}', '\u{16E60}'),
+    ('\u{16E41}', '\u{16E61}'),
+    ('\u{16E42}', '\u{16E62}'),
+    ('\u{16E43}', '\u{16E63}'),
+    ('\u{16E44}', '\u{16E64}'),
+    ('\u{16E45}', '\u{16E65}'),
+    ('\u{16E46}', '\u{16E66}'),
+    ('\u{16E47}', '\u{16E67}'),
+    ('\u{16E48}', '\u{16E68}'),
+    ('\u{16E49}', '\u{16E69}'),
+    ('\u{16E4A}', '\u{16E6A}'),
+    ('\u{16E4B}', '\u{16E6B}'),
+    ('\u{16E4C}', '\u{16E6C}'),
+    ('\u{16E4D}', '\u{16E6D}'),
+    ('\u{16E4E}', '\u{16E6E}'),
+    ('\u{16E4F}', '\u{16E6F}'),
+    ('\u{16E50}', '\u{16E70}'),
+    ('\u{16E51}', '\u{16E71}'),
+    ('\u{16E52}', '\u{16E72}'),
+    ('\u{16E53}', '\u{16E73}'),
+    ('\u{16E54}', '\u{16E74}'),
+    ('\u{16E55}', '\u{16E75}'),
+    ('\u{16E56}', '\u{16E76}'),
+    ('\u{16E57}', '\u{16E77}'),
+    ('\u{16E58}', '\u{16E78}'),
+    ('\u{16E59}', '\u{16E79}'),
+    ('\u{16E5A}', '\u{16E7A}'),
+    ('\u{16E5B}', '\u{16E7B}'),
+    ('\u{16E5C}', '\u{16E7C}'),
+    ('\u{16E5D}', '\u{16E7D}'),
+    ('\u{16E5E}', '\u{16E7E}'),
+    ('\u{16E5F}', '\u{16E7F}'),
+    ('\u{1E900}', '\u{1E922}'),
+    ('\u{1E901}', '\u{1E923}'),
+    ('\u{1E902}', '\u{1E924}'),
+    ('\u{1E903}', '\u{1E925}'),
+    ('\u{1E904}', '\u{1E926}'),
+    ('\u{1E905}', '\u{1E927}'),
+    ('\u{1E906}', '\u{1E928}'),
+    ('\u{1E907}', '\u{1E929}'),
+    ('\u{1E908}', '\u{1E92A}'),
+    ('\u{1E909}', '\u{1E92B}'),
+    ('\u{1E90A}', '\u{1E92C}'),
+    ('\u{1E90B}', '\u{1E92D}'),
+    ('\u{1E90C}', '\u{1E92E}'),
+    ('\u{1E90D}', '\u{1E92F}'),
+    ('\u{1E90E}', '\u{1E930}'),
+    ('\u{1E90F}', '\u{1E931}'),
+    ('\u{1E910}', '\u{1E932}'),
+    ('\u{1E911}', '\u{1E933}'),
+    ('\u{1E912}', '\u{1E934}'),
+    ('\u{1E913}', '\u{1E935}'),
+    ('\u{1E914}', '\u{1E936}'),
+    ('\u{1E915}', '\u{1E937}'),
+    ('\u{1E916}', '\u{1E938}'),
+    ('\u{1E917}', '\u{1E939}'),
+    ('\u{1E918}', '\u{1E93A}'),
+    ('\u{1E919}', '\u{1E93B}'),
+    ('\u{1E91A}', '\u{1E93C}'),
+    ('\u{1E91B}', '\u{1E93D}'),
+    ('\u{1E91C}', '\u{1E93E}'),
+    ('\u{1E91D}', '\u{1E93F}'),
+    ('\u{1E91E}', '\u{1E940}'),
+    ('\u{1E91F}', '\u{1E941}'),
+    ('\u{1E920}', '\u{1E942}'),
+    ('\u{1E921}', '\u{1E943}'),
+];
+
+pub(super) static GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[
+    ('\u{0000}', '\u{001F}', Control),
+    ('\u{0020}', '\u{0020}', SpaceSeparator),
+    ('\u{0021}', '\u{0023}', OtherPunctuation),
+    ('\u{0024}', '\u{0024}', CurrencySymbol),
+    ('\u{0025}', '\u{0027}', OtherPunctuation),
+    ('\u{0028}', '\u{0028}', OpenPunctuation),
+    ('\u{0029}', '\u{0029}', ClosePunctuation),
+    ('\u{002A}', '\u{002A}', OtherPunctuation),
+    ('\u{002B}', '\u{002B}', MathSymbol),
+    ('\u{002C}', '\u{002C}', OtherPunctuation),
+    ('\u{002D}', '\u{002D}', DashPunctuation),
+    ('\u{002E}', '\u{002F}', OtherPunctuation),
+    ('\u{0030}', '\u{0039}', DecimalNumber),
+    ('\u{003A}', '\u{003B}', OtherPunctuation),
+    ('\u{003C}', '\u{003E}', MathSymbol),
+    ('\u{003F}', '\u{0040}', OtherPunctuation),
+    ('\u{0041}', '\u{005A}', UppercaseLetter),
+    ('\u{005B}', '\u{005B}', OpenPunctuation),
+    ('\u{005C}', '\u{005C}', OtherPunctuation),
+    ('\u{005D}', '\u{005D}', ClosePunctuation),
+    ('\u{005E}', '\u{005E}', ModifierSymbol),
+    ('\u{005F}', '\u{005F}', ConnectorPunctuation),
+    ('\u{0060}', '\u{0060}', ModifierSymbol),
+    ('\u{0061}', '\u{007A}', LowercaseLetter),
+    ('\u{007B}', '\u{007B}', OpenPunctuation),
+    ('\u{007C}', '\u{007C}', MathSymbol),
+    ('\u{007D}', '\u{007D}', ClosePunctuation),
+    ('\u{007E}', '\u{007E}', MathSymbol),
+    ('\u{007F}', '\u{009F}', Control),
+    ('\u{00A0}', '\u{00A0}', SpaceSeparator),
+    ('\u{00A1}', '\u{00A1}', OtherPunctuation),
+    ('\u{00A2}', '\u{00A5}', CurrencySymbol),
+    ('\u{00A6}', '\u{00A6}', OtherSymbol),
+    ('\u{00A7}', '\u{00A7}', OtherPunctuation),
+    ('\u{00A8}', '\u{00A8}', ModifierSymbol),
+    ('\u{00A9}', '\u{00A9}', OtherSymbol),
+    ('\u{00AA}', '\u{00AA}', OtherLetter),
+    ('\u{00AB}', '\u{00AB}', InitialPunctuation),
+    ('\u{00AC}', '\u{00AC}', MathSymbol),
+    ('\u{00AD}', '\u{00AD}', Format),
+    ('\u{00AE}', '\u{00AE}', OtherSymbol),
+    ('\u{00AF}', '\u{00AF}', ModifierSymbol),
+    ('\u{00B0}', '\u{00B0}', OtherSymbol),
+    ('\u{00B1}', '\u{00B1}', MathSymbol),
+    ('\u{00B2}', '\u{00B3}', OtherNumber),
+    ('\u{00B4}', '\u{00B4}', ModifierSymbol),
+    ('\u{00B5}', '\u{00B5}', LowercaseLetter),
+    ('\u{00B6}', '\u{00B7}', OtherPunctuation),
+    ('\u{00B8}', '\u{00B8}', ModifierSymbol),
+    ('\u{00B9}', '\u{00B9}', OtherNumber),
+    ('\u{00BA}', '\u{00BA}', OtherLetter),
+    ('\u{00BB}', '\u{00BB}', FinalPunctuation),
+    ('\u{00BC}', '\u{00BE}', OtherNumber),
+    ('\u{00BF}', '\u{00BF}', OtherPunctuation),
+    ('\u{00C0}', '\u{00D6}', UppercaseLetter),
+    ('\u{00D7}', '\u{00D7}', MathSymbol),
+    ('\u{00D8}', '\u{00DE}', UppercaseLetter),
+    ('\u{00DF}', '\u{00F6}', LowercaseLetter),
+    ('\u{00F7}', '\u{00F7}', MathSymbol),
+    ('\u{00F8}', '\u{00FF}', LowercaseLetter),
+    ('\u{0100}', '\u{0100}', UppercaseLetter),
+    ('\u{0101}', '\u{0101}', LowercaseLetter),
+    ('\u{0102}', '\u{0102}', UppercaseLetter),
+    ('\u{0103}', '\u{0103}', LowercaseLetter),
+    ('\u{0104}', '\u{0104}', UppercaseLetter),
+    ('\u{0105}', '\u{0105}', LowercaseLetter),
+    ('\u{0106}', '\u{0106}', UppercaseLetter),
+    ('\u{0107}', '\u{0107}', LowercaseLetter),
+    ('\u{0108}', '\u{0108}', UppercaseLetter),
+    ('\u{0109}', '\u{0109}', LowercaseLetter),
+    ('\u{010A}', '\u{010A}', UppercaseLetter),
+    ('\u{010B}', '\u{010B}', LowercaseLetter),
+    ('\u{010C}', '\u{010C}', UppercaseLetter),
+    ('\u{010D}', '\u{010D}', LowercaseLetter),
+    ('\u{010E}', '\u{010E}', UppercaseLetter),
+    ('\u{010F}', '\u{010F}', LowercaseLetter),
+    ('\u{0110}', '\u{0110}', UppercaseLetter),
+    ('\u{0111}', '\u{0111}', LowercaseLetter),
+    ('\u{0112}', '\u{0112}', UppercaseLetter),
+    ('\u{0113}', '\u{0113}', LowercaseLetter),
+    ('\u{0114}', '\u{0114}', UppercaseLetter),
+    ('\u{0115}', '\u{0115}', LowercaseLetter),
+    ('\u{0116}', '\u{0116}', UppercaseLetter),
+    ('\u{0117}', '\u{0117}', LowercaseLetter),
+    ('\u{0118}', '\u{0118}', UppercaseLetter),
+    ('\u{0119}', '\u{0119}', LowercaseLetter),
+    ('\u{011A}', '\u{011A}', UppercaseLetter),
+    ('\u{011B}', '\u{011B}', LowercaseLetter),
+    ('\u{011C}', '\u{011C}', UppercaseLetter),
+    ('\u{011D}', '\u{011D}', LowercaseLetter),
+    ('\u{011E}', '\u{011E}', UppercaseLetter),
+    ('\u{011F}', '\u{011F}', LowercaseLetter),
+    ('\u{0120}', '\u{0120}', UppercaseLetter),
+    ('\u{0121}', '\u{0121}', LowercaseLetter),
+    ('\u{0122}', '\u{0122}', UppercaseLetter),
+    ('\u{0123}', '\u{0123}', LowercaseLetter),
+    ('\u{0124}', '\u{0124}', UppercaseLetter),
+    ('\u{0125}', '\u{0125}', LowercaseLetter),
+    ('\u{0126}', '\u{0126}', UppercaseLetter),
+    ('\u{0127}', '\u{0127}', LowercaseLetter),
+    ('\u{0128}', '\u{0128}', UppercaseLetter),
+    ('\u{0129}', '\u{0129}', LowercaseLetter),
+    ('\u{012A}', '\u{012A}', UppercaseLetter),
+    ('\u{012B}', '\u{012B}', LowercaseLetter),
+    ('\u{012C}', '\u{012C}', UppercaseLetter),
+    ('\u{012D}', '\u{012D}', LowercaseLetter),
+    ('\u{012E}', '\u{012E}', UppercaseLetter),
+    ('\u{012F}', '\u{012F}', LowercaseLetter),
+    ('\u{0130}', '\u{0130}', UppercaseLetter),
+    ('\u{0131}', '\u{0131}', LowercaseLetter),
+    ('\u{0132}', '\u{0132}', UppercaseLetter),
+    ('\u{0133}', '\u{0133}', LowercaseLetter),
+    ('\u{0134}', '\u{0134}', UppercaseLetter),
+    ('\u{0135}', '\u{0135}', LowercaseLetter),
+    ('\u{0136}', '\u{0136}', UppercaseLetter),
+    ('\u{0137}', '\u{0138}', LowercaseLetter),
+    ('\u{0139}', '\u{0139}', UppercaseLetter),
+    ('\u{013A}', '\u{013A}', LowercaseLetter),
+    ('\u{013B}', '\u{013B}', UppercaseLetter),
+    ('\u{013C}', '\u{013C}', LowercaseLetter),
+    ('\u{013D}', '\u{013D}', UppercaseLetter),
+    ('\u{013E}', '\u{013E}', LowercaseLetter),
+    ('\u{013F}', '\u{013F}', UppercaseLetter),
+    ('\u{0140}', '\u{0140}', LowercaseLetter),
+    ('\u{0141}', '\u{0141}', UppercaseLetter),
+    ('\u{0142}', '\u{0142}', LowercaseLetter),
+    ('\u{0143}', '\u{0143}', UppercaseLetter),
+    ('\u{0144}', '\u{0144}', LowercaseLetter),
+    ('\u{0145}', '\u{0145}', UppercaseLetter),
+    ('\u{0146}', '\u{0146}', LowercaseLetter),
+    ('\u{0147}', '\u{0147}', UppercaseLetter),
+    ('\u{0148}', '\u{0149}', LowercaseLetter),
+    ('\u{014A}', '\u{014A}', UppercaseLetter),
+    ('\u{014B}', '\u{014B}', LowercaseLetter),
+    ('\u{014C}', '\u{014C}', UppercaseLetter),
+    ('\u{014D}', '\u{014D}', LowercaseLetter),
+    ('\u{014E}', '\u{014E}', UppercaseLetter),
+    ('\u{014F}', '\u{014F}', LowercaseLetter),
+    ('\u{0150}', '\u{0150}', UppercaseLetter),
+    ('\u{0151}', '\u{0151}', LowercaseLetter),
+    ('\u{0152}', '\u{0152}', UppercaseLetter),
+    ('\u{0153}', '\u{0153}', LowercaseLetter),
+    ('\u{0154}', '\u{0154}', UppercaseLetter),
+    ('\u{0155}', '\u{0155}', LowercaseLetter),
+    ('\u{0156}', '\u{0156}', UppercaseLetter),
+    ('\u{0157}', '\u{0157}', LowercaseLetter),
+    ('\u{0158}', '\u{0158}', UppercaseLetter),
+    ('\u{0159}', '\u{0159}', LowercaseLetter),
+    ('\u{015A}', '\u{015A}', UppercaseLetter),
+    ('\u{015B}', '\u{015B}', LowercaseLetter),
+    ('\u{015C}', '\u{015C}', UppercaseLetter),
+    ('\u{015D}', '\u{015D}', LowercaseLetter),
+    ('\u{015E}', '\u{015E}', UppercaseLetter),
+    ('\u{015F}', '\u{015F}', LowercaseLetter),
+    ('\u{0160}', '\u{0160}', UppercaseLetter),
+    ('\u{0161}', '\u{0161}', LowercaseLetter),
+    ('\u{0162}', '\u{0162}', UppercaseLetter),
+    ('\u{0163}', '\u{0163}', LowercaseLetter),
+    ('\u{0164}', '\u{0164}', UppercaseLetter),
+    ('\u{0165}', '\u{0165}', LowercaseLetter),
+    ('\u{0166}', '\u{0166}', UppercaseLetter),
+    ('\u{0167}', '\u{0167}', LowercaseLetter),
+    ('\u{0168}', '\u{0168}', UppercaseLetter),
+    ('\u{0169}', '\u{0169}', LowercaseLetter),
+    ('\u{016A}', '\u{016A}', UppercaseLetter),
+    ('\u{016B}', '\u{016B}', LowercaseLetter),
+    ('\u{016C}', '\u{016C}', UppercaseLetter),
+    ('\u{016D}', '\u{016D}', LowercaseLetter),
+    ('\u{016E}', '\u{016E}', UppercaseLetter),
+    ('\u{016F}', '\u{016F}', LowercaseLetter),
+    ('\u{0170}', '\u{0170}', UppercaseLetter),
+    ('\u{0171}', '\u{0171}', LowercaseLetter),
+    ('\u{0172}', '\u{0172}', UppercaseLetter),
+    ('\u{0173}', '\u{0173}', LowercaseLetter),
+    ('\u{0174}', '\u{0174}', UppercaseLetter),
+    ('\u{0175}', '\u{0175}', LowercaseLetter),
+    ('\u{0176}', '\u{0176}', UppercaseLetter),
+    ('\u{0177}', '\u{0177}', LowercaseLetter),
+    ('\u{0178}', '\u{0179}', UppercaseLetter),
+    ('\u{017A}', '\u{017A}', LowercaseLetter),
+    ('\u{017B}', '\u{017B}', UppercaseLetter),
+    ('\u{017C}', '\u{017C}', LowercaseLetter),
+    ('\u{017D}', '\u{017D}', UppercaseLetter),
+    ('\u{017E}', '\u{0180}', LowercaseLetter),
+    ('\u{0181}', '\u{0182}', UppercaseLetter),
+    ('\u{0183}', '\u{0183}', LowercaseLetter),
+    ('\u{0184}', '\u{0184}', UppercaseLetter),
+    ('\u{0185}', '\u{0185}', LowercaseLetter),
+    ('\u{0186}', '\u{0187}', UppercaseLetter),
+    ('\u{0188}', '\u{0188}', LowercaseLetter),
+    ('\u{0189}', '\u{018B}', UppercaseLetter),
+    ('\u{018C}', '\u{018D}', LowercaseLetter),
+    ('\u{018E}', '\u{0191}', UppercaseLetter),
+    ('\u{0192}', '\u{0192}', LowercaseLetter),
+    ('\u{0193}', '\u{0194}', UppercaseLetter),
+    ('\u{0195}', '\u{0195}', LowercaseLetter),
+    ('\u{0196}', '\u{0198}', UppercaseLetter),
+    ('\u{0199}', '\u{019B}', LowercaseLetter),
+    ('\u{019C}', '\u{019D}', UppercaseLetter),
+    ('\u{019E}', '\u{019E}', LowercaseLetter),
+    ('\u{019F}', '\u{01A0}', UppercaseLetter),
+    ('\u{01A1}', '\u{01A1}', LowercaseLetter),
+    ('\u{01A2}', '\u{01A2}', UppercaseLetter),
+    ('\u{01A3}', '\u{01A3}', LowercaseLetter),
+    ('\u{01A4}', '\u{01A4}', UppercaseLetter),
+    ('\u{01A5}', '\u{01A5}', LowercaseLetter),
+    ('\u{01A6}', '\u{01A7}', UppercaseLetter),
+    ('\u{01A8}', '\u{01A8}', LowercaseLetter),
+    ('\u{01A9}', '\u{01A9}', UppercaseLetter),
+    ('\u{01AA}', '\u{01AB}', LowercaseLetter),
+    ('\u{01AC}', '\u{01AC}', UppercaseLetter),
+    ('\u{01AD}', '\u{01AD}', LowercaseLetter),
+    ('\u{01AE}', '\u{01AF}', UppercaseLetter),
+    ('\u{01B0}', '\u{01B0}', LowercaseLetter),
+    ('\u{01B1}', '\u{01B3}', UppercaseLetter),
+    ('\u{01B4}', '\u{01B4}', LowercaseLetter),
+    ('\u{01B5}', '\u{01B5}', UppercaseLetter),
+    ('\u{01B6}', '\u{01B6}', LowercaseLetter),
+    ('\u{01B7}', '\u{01B8}', UppercaseLetter),
+    ('\u{01B9}', '\u{01BA}', LowercaseLetter),
+    ('\u{01BB}', '\u{01BB}', OtherLetter),
+    ('\u{01BC}', '\u{01BC}', UppercaseLetter),
+    ('\u{01BD}', '\u{01BF}', LowercaseLetter),
+    ('\u{01C0}', '\u{01C3}', OtherLetter),
+    ('\u{01C4}', '\u{01C4}', UppercaseLetter),
+    ('\u{01C5}', '\u{01C5}', TitlecaseLetter),
+    ('\u{01C6}', '\u{01C6}', LowercaseLetter),
+    ('\u{01C7}', '\u{01C7}', UppercaseLetter),
+    ('\u{01C8}', '\u{01C8}', TitlecaseLetter),
+    ('\u{01C9}', '\u{01C9}', LowercaseLetter),
+    ('\u{01CA}', '\u{01CA}', UppercaseLetter),
+    ('\u{01CB}', '\u{01CB}', TitlecaseLetter),
+    ('\u{01CC}', '\u{01CC}', LowercaseLetter),
+    ('\u{01CD}', '\u{01CD}', UppercaseLetter),
+    ('\u{01CE}', '\u{01CE}', LowercaseLetter),
+    ('\u{01CF}', '\u{01CF}', UppercaseLetter),
+    ('\u{01D0}', '\u{01D0}', LowercaseLetter),
+    ('\u{01D1}', '\u{01D1}', UppercaseLetter),
+    ('\u{01D2}', '\u{01D2}', LowercaseLetter),
+    ('\u{01D3}', '\u{01D3}', UppercaseLetter),
+    ('\u{01D4}', '\u{01D4}', LowercaseLetter),
+    ('\u{01D5}', '\u{01D5}', UppercaseLetter),
+    ('\u{01D6}', '\u{01D6}', LowercaseLetter),
+    ('\u{01D7}', '\u{01D7}', UppercaseLetter),
+    ('\u{01D8}', '\u{01D8}', LowercaseLetter),
+    ('\u{01D9}', '\u{01D9}', UppercaseLetter),
+    ('\u{01DA}', '\u{01DA}', LowercaseLetter),
+    ('\u{01DB}', '\u{01DB}', UppercaseLetter),
+    ('\u{01DC}', '\u{01DD}', LowercaseLetter),
+    ('\u{01DE}', '\u{01DE}', UppercaseLetter),
+    ('\u{01DF}', '\u{01DF}', LowercaseLetter),
+    ('\u{01E0}', '\u{01E0}', UppercaseLetter),
+    ('\u{01E1}', '\u{01E1}', LowercaseLetter),
+    ('\u{01E2}', '\u{01E2}', UppercaseLetter),
+    ('\u{01E3}', '\u{01E3}', LowercaseLetter),
+    ('\u{01E4}', '\u{01E4}', UppercaseLetter),
+    ('\u{01E5}', '\u{01E5}', LowercaseLetter),
+    ('\u{01E6}', '\u{01E6}', UppercaseLetter),
+    ('\u{01E7}', '\u{01E7}', LowercaseLetter),
+    ('\u{01E8}', '\u{01E8}', UppercaseLetter),
+    ('\u{01E9}', '\u{01E9}', LowercaseLetter),
+    ('\u{01EA}', '\u{01EA}', UppercaseLetter),
+    ('\u{01EB}', '\u{01EB}', LowercaseLetter),
+    ('\u{01EC}', '\u{01EC}', UppercaseLetter),
+    ('\u{01ED}', '\u{01ED}', LowercaseLetter),
+    ('\u{01EE}', '\u{01EE}', UppercaseLetter),
+    ('\u{01EF}', '\u{01F0}', LowercaseLetter),
+    ('\u{01F1}', '\u{01F1}', UppercaseLetter),
+    ('\u{01F2}', '\u{01F2}', TitlecaseLetter),
+    ('\u{01F3}', '\u{01F3}', LowercaseLetter),
+    ('\u{01F4}', '\u{01F4}', UppercaseLetter),
+    ('\u{01F5}', '\u{01F5}', LowercaseLetter),
+    ('\u{01F6}', '\u{01F8}', UppercaseLetter),
+    ('\u{01F9}', '\u{01F9}', LowercaseLetter),
+    ('\u{01FA}', '\u{01FA}', UppercaseLetter),
+    ('\u{01FB}', '\u{01FB}', LowercaseLetter),
+    ('\u{01FC}', '\u{01FC}', UppercaseLetter),
+    ('\u{01FD}', '\u{01FD}', LowercaseLetter),
+    ('\u{01FE}', '\u{01FE}', UppercaseLetter),
+    ('\u{01FF}', '\u{01FF}', LowercaseLetter),
+    ('\u{0200}', '\u{0200}', UppercaseLetter),
+    ('\u{0201}', '\u{0201}', LowercaseLetter),
+    ('\u{0202}', '\u{0202}', UppercaseLetter),
+    ('\u{0203}', '\u{0203}', LowercaseLetter),
+    ('\u{0204}', '\u{0204}', UppercaseLetter),
+    ('\u{0205}', '\u{0205}', LowercaseLetter),
+    ('\u{0206}', '\u{0206}', UppercaseLetter),
+    ('\u{0207}', '\u{0207}', LowercaseLetter),
+    ('\u{0208}', '\u{0208}', UppercaseLetter),
+    ('\u{0209}', '\u{0209}', LowercaseLetter),
+    ('\u{020A}', '\u{020A}', UppercaseLetter),
+    ('\u{020B}', '\u{020B}', LowercaseLetter),
+    ('\u{020C}', '\u{020C}', UppercaseLetter),
+    ('\u{020D}', '\u{020D}', LowercaseLetter),
+    ('\u{020E}', '\u{020E}', UppercaseLetter),
+    ('\u{020F}', '\u{020F}', LowercaseLetter),
+    ('\u{0210}', '\u{0210}', UppercaseLetter),
+    ('\u{0211}', '\u{0211}', LowercaseLetter),
+    ('\u{0212}', '\u{0212}', UppercaseLetter),
+    ('\u{0213}', '\u{0213}', LowercaseLetter),
+    ('\u{0214}', '\u{0214}', UppercaseLetter),
+    ('\u{0215}', '\u{0215}', LowercaseLetter),
+    ('\u{0216}', '\u{0216}', UppercaseLetter),
+    ('\u{0217}', '\u{0217}', LowercaseLetter),
+    ('\u{0218}', '\u{0218}', UppercaseLetter),
+    ('\u{0219}', '\u{0219}', LowercaseLetter),
+    ('\u{021A}', '\u{021A}', UppercaseLetter),
+    ('\u{021B}', '\u{021B}', LowercaseLetter),
+    ('\u{021C}', '\u{021C}', UppercaseLetter),
+    ('\u{021D}', '\u{021D}', LowercaseLetter),
+    ('\u{021E}', '\u{021E}', UppercaseLetter),
+    ('\u{021F}', '\u{021F}', LowercaseLetter),
+    ('\u{0220}', '\u{0220}', UppercaseLetter),
+    ('\u{0221}', '\u{0221}', LowercaseLetter),
+    ('\u{0222}', '\u{0222}', UppercaseLetter),
+    ('\u{0223}', '\u{0223}', LowercaseLetter),
+    ('\u{0224}', '\u{0224}', UppercaseLetter),
+    ('\u{0225}', '\u{0225}', LowercaseLetter),
+    ('\u{0226}', '\u{0226}', UppercaseLetter),
+    ('\u{0227}', '\u{0227}', LowercaseLetter),
+    ('\u{0228}', '\u{0228}', UppercaseLetter),
+    ('\u{0229}', '\u{0229}', LowercaseLetter),
+    ('\u{022A}', '\u{022A}', UppercaseLetter),
+    ('\u{022B}', '\u{022B}', LowercaseLetter),
+    ('\u{022C}', '\u{022C}', UppercaseLetter),
+    ('\u{022D}', '\u{022D}', LowercaseLetter),
+    ('\u{022E}', '\u{022E}', UppercaseLetter),
+    ('\u{022F}', '\u{022F}', LowercaseLetter),
+    ('\u{0230}', '\u{0230}', UppercaseLetter),
+    ('\u{0231}', '\u{0231}', LowercaseLetter),
+    ('\u{0232}', '\u{0232}', UppercaseLetter),
+    ('\u{0233}', '\u{0239}', LowercaseLetter),
+    ('\u{023A}', '\u{023B}', UppercaseLetter),
+    ('\u{023C}', '\u{023C}', LowercaseLetter),
+    ('\u{023D}', '\u{023E}', UppercaseLetter),
+    ('\u{023F}', '\u{0240}', LowercaseLetter),
+    ('\u{0241}', '\u{0241}', UppercaseLetter),
+    ('\u{0242}', '\u{0242}', LowercaseLetter),
+    ('\u{0243}', '\u{0246}', UppercaseLetter),
+    ('\u{0247}', '\u{0247}', LowercaseLetter),
+    ('\u{0248}', '\u{0248}', UppercaseLetter),
+    ('\u{0249}', '\u{0249}', LowercaseLetter),
+    ('\u{024A}', '\u{024A}', UppercaseLetter),
+    ('\u{024B}', '\u{024B}', LowercaseLetter),
+    ('\u{024C}', '\u{024C}', UppercaseLetter),
+    ('\u{024D}', '\u{024D}', LowercaseLetter),
+    ('\u{024E}', '\u{024E}', UppercaseLetter),
+    ('\u{024F}', '\u{0293}', LowercaseLetter),
+    ('\u{0294}', '\u{0294}', OtherLetter),
+    ('\u{0295}', '\u{02AF}', LowercaseLetter),
+    ('\u{02B0}', '\u{02C1}', ModifierLetter),
+    ('\u{02C2}', '\u{02C5}', ModifierSymbol),
+    ('\u{02C6}', '\u{02D1}', ModifierLetter),
+    ('\u{02D2}', '\u{02DF}', ModifierSymbol),
+    ('\u{02E0}', '\u{02E4}', ModifierLetter),
+    ('\u{02E5}', '\u{02EB}', ModifierSymbol),
+    ('\u{02EC}', '\u{02EC}', ModifierLetter),
+    ('\u{02ED}', '\u{02ED}', ModifierSymbol),
+    ('\u{02EE}', '\u{02EE}', ModifierLetter),
+    ('\u{02EF}', '\u{02FF}', ModifierSymbol),
+    ('\u{0300}', '\u{036F}', NonspacingMark),
+    ('\u{0370}', '\u{0370}', UppercaseLetter),
+    ('\u{0371}', '\u{0371}', LowercaseLetter),
+    ('\u{0372}', '\u{0372}', UppercaseLetter),
+    ('\u{0373}', '\u{0373}', LowercaseLetter),
+    ('\u{0374}', '\u{0374}', ModifierLetter),
+    ('\u{0375}', '\u{0375}', ModifierSymbol),
+    ('\u{0376}', '\u{0376}', UppercaseLetter),
+    ('\u{0377}', '\u{0377}', LowercaseLetter),
+    ('\u{037A}', '\u{037A}', ModifierLetter),
+    ('\u{037B}', '\u{037D}', LowercaseLetter),
+    ('\u{037E}', '\u{037E}', OtherPunctuation),
+    ('\u{037F}', '\u{037F}', UppercaseLetter),
+    ('\u{0384}', '\u{0385}', ModifierSymbol),
+    ('\u{0386}', '\u{0386}', UppercaseLetter),
+    ('\u{0387}', '\u{0387}', OtherPunctuation),
+    ('\u{0388}', '\u{038A}', UppercaseLetter),
+    ('\u{038C}', '\u{038C}', UppercaseLetter),
+    ('\u{038E}', '\u{038F}', UppercaseLetter),
+    ('\u{0390}', '\u{0390}', LowercaseLetter),
+    ('\u{0391}', '\u{03A1}', UppercaseLetter),
+    ('\u{03A3}', '\u{03AB}', UppercaseLetter),
+    ('\u{03AC}', '\u{03CE}', LowercaseLetter),
+    ('\u{03CF}', '\u{03CF}', UppercaseLetter),
+    ('\u{03D0}', '\u{03D1}', LowercaseLetter),
+    ('\u{03D2}', '\u{03D4}', UppercaseLetter),
+    ('\u{03D5}', '\u{03D7}', LowercaseLetter),
+    ('\u{03D8}', '\u{03D8}', UppercaseLetter),
+    ('\u{03D9}', '\u{03D9}', LowercaseLetter),
+    ('\u{03DA}', '\u{03DA}', UppercaseLetter),
+    ('\u{03DB}', '\u{03DB}', LowercaseLetter),
+    ('\u{03DC}', '\u{03DC}', UppercaseLetter),
+    ('\u{03DD}', '\u{03DD}', LowercaseLetter),
+    ('\u{03DE}', '\u{03DE}', UppercaseLetter),
+    ('\u{03DF}', '\u{03DF}', LowercaseLetter),
+    ('\u{03E0}', '\u{03E0}', UppercaseLetter),
+    ('\u{03E1}', '\u{03E1}', LowercaseLetter),
+    ('\u{03E2}', '\u{03E2}', UppercaseLetter),
+    ('\u{03E3}', '\u{03E3}', LowercaseLetter),
+    ('\u{03E4}', '\u{03E4}', UppercaseLetter),
+    ('\u{03E5}', '\u{03E5}', LowercaseLetter),
+    ('\u{03E6}', '\u{03E6}', UppercaseLetter),
+    ('\u{03E7}', '\u{03E7}', LowercaseLetter),
+    ('\u{03E8}', '\u{03E8}', UppercaseLetter),
+    ('\u{03E9}', '\u{03E9}', LowercaseLetter),
+    ('\u{03EA}', '\u{03EA}', UppercaseLetter),
+    ('\u{03EB}', '\u{03EB}', LowercaseLetter),
+    ('\u{03EC}', '\u{03EC}', UppercaseLetter),
+    ('\u{03ED}', '\u{03ED}', LowercaseLetter),
+    ('\u{03EE}', '\u{03EE}', UppercaseLetter),
+    ('\u{03EF}', '\u{03F3}', LowercaseLetter),
+    ('\u{03F4}', '\u{03F4}', UppercaseLetter),
+    ('\u{03F5}', '\u{03F5}', LowercaseLetter),
+    ('\u{03F6}', '\u{03F6}', MathSymbol),
+    ('\u{03F7}', '\u{03F7}', UppercaseLetter),
+    ('\u{03F8}', '\u{03F8}', LowercaseLetter),
+    ('\u{03F9}', '\u{03FA}', UppercaseLetter),
+    ('\u{03FB}', '\u{03FC}', LowercaseLetter),
+    ('\u{03FD}', '\u{042F}', UppercaseLetter),
+    ('\u{0430}', '\u{045F}', LowercaseLetter),
+    ('\u{0460}', '\u{0460}', UppercaseLetter),
+    ('\u{0461}', '\u{0461}', LowercaseLetter),
+    ('\u{0462}', '\u{0462}', UppercaseLetter),
+    ('\u{0463}', '\u{0463}', LowercaseLetter),
+    ('\u{0464}', '\u{0464}', UppercaseLetter),
+    ('\u{0465}', '\u{0465}', LowercaseLetter),
+    ('\u{0466}', '\u{0466}', UppercaseLetter),
+    ('\u{0467}', '\u{0467}', LowercaseLetter),
+    ('\u{0468}', '\u{0468}', UppercaseLetter),
+    ('\u{0469}', '\u{0469}', LowercaseLetter),
+    ('\u{046A}', '\u{046A}', UppercaseLetter),
+    ('\u{046B}', '\u{046B}', LowercaseLetter),
+    ('\u{046C}', '\u{046C}', UppercaseLetter),
+    ('\u{046D}', '\u{046D}', LowercaseLetter),
+    ('\u{046E}', '\u{046E}', UppercaseLetter),
+    ('\u{046F}', '\u{046F}', LowercaseLetter),
+    ('\u{0470}', '\u{0470}', UppercaseLetter),
+    ('\u{0471}', '\u{0471}', LowercaseLetter),
+    ('\u{0472}', '\u{0472}', UppercaseLetter),
+    ('\u{0473}', '\u{0473}', LowercaseLetter),
+    ('\u{0474}', '\u{0474}', UppercaseLetter),
+    ('\u{0475}', '\u{0475}', LowercaseLetter),
+    ('\u{0476}', '\u{0476}', UppercaseLetter),
+    ('\u{0477}', '\u{0477}', LowercaseLetter),
+    ('\u{0478}', '\u{0478}', UppercaseLetter),
+    ('\u{0479}', '\u{0479}', LowercaseLetter),
+    ('\u{047A}', '\u{047A}', UppercaseLetter),
+    ('\u{047B}', '\u{047B}', LowercaseLetter),
+    ('\u{047C}', '\u{047C}', UppercaseLetter),
+    ('\u{047D}', '\u{047D}', LowercaseLetter),
+    ('\u{047E}', '\u{047E}', UppercaseLetter),
+    ('\u{047F}', '\u{047F}', LowercaseLetter),
+    ('\u{0480}', '\u{0480}', UppercaseLetter),
+    ('\u{0481}', '\u{0481}', LowercaseLetter),
+    ('\u{0482}', '\u{0482}', OtherSymbol),
+    ('\u{0483}', '\u{0487}', NonspacingMark),
+    ('\u{0488}', '\u{0489}', EnclosingMark),
+    ('\u{048A}', '\u{048A}', UppercaseLetter),
+    ('\u{048B}', '\u{048B}', LowercaseLetter),
+    ('\u{048C}', '\u{048C}', UppercaseLetter),
+    ('\u{048D}', '\u{048D}', LowercaseLetter),
+    ('\u{048E}', '\u{048E}', UppercaseLetter),
+    ('\u{048F}', '\u{048F}', LowercaseLetter),
+    ('\u{0490}', '\u{0490}', UppercaseLetter),
+    ('\u{0491}', '\u{0491}', LowercaseLetter),
+    ('\u{0492}', '\u{0492}', UppercaseLetter),
+    ('\u{0493}', '\u{0493}', LowercaseLetter),
+    ('\u{0494}', '\u{0494}', UppercaseLetter),
+    ('\u{0495}', '\u{0495}', LowercaseLetter),
+    ('\u{0496}', '\u{0496}', UppercaseLetter),
+    ('\u{0497}', '\u{0497}', LowercaseLetter),
+    ('\u{0498}', '\u{0498}', UppercaseLetter),
+    ('\u{0499}', '\u{0499}', LowercaseLetter),
+    ('\u{049A}', '\u{049A}', UppercaseLetter),
+    ('\u{049B}', '\u{049B}', LowercaseLetter),
+    ('\u{049C}', '\u{049C}', UppercaseLetter),
+    ('\u{049D}', '\u{049D}', LowercaseLetter),
+    ('\u{049E}', '\u{049E}', UppercaseLetter),
+    ('\u{049F}', '\u{049F}', LowercaseLetter),
+    ('\u{04A0}', '\u{04A0}', UppercaseLetter),
+    ('\u{04A1}', '\u{04A1}', LowercaseLetter),
+    ('\u{04A2}', '\u{04A2}', UppercaseLetter),
+    ('\u{04A3}', '\u{04A3}', LowercaseLetter),
+    ('\u{04A4}', '\u{04A4}', UppercaseLetter),
+    ('\u{04A5}', '\u{04A5}', LowercaseLetter),
+    ('\u{04A6}', '\u{04A6}', UppercaseLetter),
+    ('\u{04A7}', '\u{04A7}', LowercaseLetter),
+    ('\u{04A8}', '\u{04A8}', UppercaseLetter),
+    ('\u{04A9}', '\u{04A9}', LowercaseLetter),
+    ('\u{04AA}', '\u{04AA}', UppercaseLetter),
+    ('\u{04AB}', '\u{04AB}', LowercaseLetter),
+    ('\u{04AC}', '\u{04AC}', UppercaseLetter),
+    ('\u{04AD}', '\u{04AD}', LowercaseLetter),
+    ('\u{04AE}', '\u{04AE}', UppercaseLetter),
+    ('\u{04AF}', '\u{04AF}', LowercaseLetter),
+    ('\u{04B0}', '\u{04B0}', UppercaseLetter),
+    ('\u{04B1}', '\u{04B1}', LowercaseLetter),
+    ('\u{04B2}', '\u{04B2}', UppercaseLetter),
+    ('\u{04B3}', '\u{04B3}', LowercaseLetter),
+    ('\u{04B4}', '\u{04B4}', UppercaseLetter),
+    ('\u{04B5}', '\u{04B5}', LowercaseLetter),
+    ('\u{04B6}', '\u{04B6}', UppercaseLetter),
+    ('\u{04B7}', '\u{04B7}', LowercaseLetter),
+    ('\u{04B8}', '\u{04B8}', UppercaseLetter),
+    ('\u{04B9}', '\u{04B9}', LowercaseLetter),
+    ('\u{04BA}', '\u{04BA}', UppercaseLetter),
+    ('\u{04BB}', '\u{04BB}', LowercaseLetter),
+    ('\u{04BC}', '\u{04BC}', UppercaseLetter),
+    ('\u{04BD}', '\u{04BD}', LowercaseLetter),
+    ('\u{04BE}', '\u{04BE}', UppercaseLetter),
+    ('\u{04BF}', '\u{04BF}', LowercaseLetter),
+    ('\u{04C0}', '\u{04C1}', UppercaseLetter),
+    ('\u{04C2}', '\u{04C2}', LowercaseLetter),
+    ('\u{04C3}', '\u{04C3}', UppercaseLetter),
+    ('\u{04C4}', '\u{04C4}', LowercaseLetter),
+    ('\u{04C5}', '\u{04C5}', UppercaseLetter),
+    ('\u{04C6}', '\u{04C6}', LowercaseLetter),
+    ('\u{04C7}', '\u{04C7}', UppercaseLetter),
+    ('\u{04C8}', '\u{04C8}', LowercaseLetter),
+    ('\u{04C9}', '\u{04C9}', UppercaseLetter),
+    ('\u{04CA}', '\u{04CA}', LowercaseLetter),
+    ('\u{04CB}', '\u{04CB}', UppercaseLetter),
+    ('\u{04CC}', '\u{04CC}', LowercaseLetter),
+    ('\u{04CD}', '\u{04CD}', UppercaseLetter),
+    ('\u{04CE}', '\u{04CF}', LowercaseLetter),
+    ('\u{04D0}', '\u{04D0}', UppercaseLetter),
+    ('\u{04D1}', '\u{04D1}', LowercaseLetter),
+    ('\u{04D2}', '\u{04D2}', UppercaseLetter),
+    ('\u{04D3}', '\u{04D3}', LowercaseLetter),
+    ('\u{04D4}', '\u{04D4}', UppercaseLetter),
+    ('\u{04D5}', '\u{04D5}', LowercaseLetter),
+    ('\u{04D6}', '\u{04D6}', UppercaseLetter),
+    ('\u{04D7}', '\u{04D7}', LowercaseLetter),
+    ('\u{04D8}', '\u{04D8}', UppercaseLetter),
+    ('\u{04D9}', '\u{04D9}', LowercaseLetter),
+    ('\u{04DA}', '\u{04DA}', UppercaseLetter),
+    ('\u{04DB}', '\u{04DB}', LowercaseLetter),
+    ('\u{04DC}', '\u{04DC}', UppercaseLetter),
+    ('\u{04DD}', '\u{04DD}', LowercaseLetter),
+    ('\u{04DE}', '\u{04DE}', UppercaseLetter),
+    ('\u{04DF}', '\u{04DF}', LowercaseLetter),
+    ('\u{04E0}', '\u{04E0}', UppercaseLetter),
+    ('\u{04E1}', '\u{04E1}', LowercaseLetter),
+    ('\u{04E2}', '\u{04E2}', UppercaseLetter),
+    ('\u{04E3}', '\u{04E3}', LowercaseLetter),
+    ('\u{04E4}', '\u{04E4}', UppercaseLetter),
+    ('\u{04E5}', '\u{04E5}', LowercaseLetter),
+    ('\u{04E6}', '\u{04E6}', UppercaseLetter),
+    ('\u{04E7}', '\u{04E7}', LowercaseLetter),
+    ('\u{04E8}', '\u{04E8}', UppercaseLetter),
+    ('\u{04E9}', '\u{04E9}', LowercaseLetter),
+    ('\u{04EA}', '\u{04EA}', UppercaseLetter),
+    ('\u{04EB}', '\u{04EB}', LowercaseLetter),
+    ('\u{04EC}', '\u{04EC}', UppercaseLetter),
+    ('\u{04ED}', '\u{04ED}', LowercaseLetter),
+    ('\u{04EE}', '\u{04EE}', UppercaseLetter),
+    ('\u{04EF}', '\u{04EF}', LowercaseLetter),
+    ('\u{04F0}', '\u{04F0}', UppercaseLetter),
+    ('\u{04F1}', '\u{04F1}', LowercaseLetter),
+    ('\u{04F2}', '\u{04F2}', UppercaseLetter),
+    ('\u{04F3}', '\u{04F3}', LowercaseLetter),
+    ('\u{04F4}', '\u{04F4}', UppercaseLetter),
+    ('\u{04F5}', '\u{04F5}', LowercaseLetter),
+    ('\u{04F6}', '\u{04F6}', UppercaseLetter),
+    ('\u{04F7}', '\u{04F7}', LowercaseLetter),
+    ('\u{04F8}', '\u{04F8}', UppercaseLetter),
+    ('\u{04F9}', '\u{04F9}', LowercaseLetter),
+    ('\u{04FA}', '\u{04FA}', UppercaseLetter),
+    ('\u{04FB}', '\u{04FB}', LowercaseLetter),
+    ('\u{04FC}', '\u{04FC}', UppercaseLetter),
+    ('\u{04FD}', '\u{04FD}', LowercaseLetter),
+    ('\u{04FE}', '\u{04FE}', UppercaseLetter),
+    ('\u{04FF}', '\u{04FF}', LowercaseLetter),
+    ('\u{0500}', '\u{0500}', UppercaseLetter),
+    ('\u{0501}', '\u{0501}', LowercaseLetter),
+    ('\u{0502}', '\u{0502}', UppercaseLetter),
+    ('\u{0503}', '\u{0503}', LowercaseLetter),
+    ('\u{0504}', '\u{0504}', UppercaseLetter),
+    ('\u{0505}', '\u{0505}', LowercaseLetter),
+    ('\u{0506}', '\u{0506}', UppercaseLetter),
+    ('\u{0507}', '\u{0507}', LowercaseLetter),
+    ('\u{0508}', '\u{0508}', UppercaseLetter),
+    ('\u{0509}', '\u{0509}', LowercaseLetter),
+    ('\u{050A}', '\u{050A}', UppercaseLetter),
+    ('\u{050B}', '\u{050B}', LowercaseLetter),
+    ('\u{050C}', '\u{050C}', UppercaseLetter),
+    ('\u{050D}', '\u{050D}', LowercaseLetter),
+    ('\u{050E}', '\u{050E}', UppercaseLetter),
+    ('\u{050F}', '\u{050F}', LowercaseLetter),
+    ('\u{0510}', '\u{0510}', UppercaseLetter),
+    ('\u{0511}', '\u{0511}', LowercaseLetter),
+    ('\u{0512}', '\u{0512}', UppercaseLetter),
+    ('\u{0513}', '\u{0513}', LowercaseLetter),
+    ('\u{0514}', '\u{0514}', UppercaseLetter),
+    ('\u{0515}', '\u{0515}', LowercaseLetter),
+    ('\u{0516}', '\u{0516}', UppercaseLetter),
+    ('\u{0517}', '\u{0517}', LowercaseLetter),
+    ('\u{0518}', '\u{0518}', UppercaseLetter),
+    ('\u{0519}', '\u{0519}', LowercaseLetter),
+    ('\u{051A}', '\u{051A}', UppercaseLetter),
+    ('\u{051B}', '\u{051B}', LowercaseLetter),
+    ('\u{051C}', '\u{051C}', UppercaseLetter),
+    ('\u{051D}', '\u{051D}', LowercaseLetter),
+    ('\u{051E}', '\u{051E}', UppercaseLetter),
+    ('\u{051F}', '\u{051F}', LowercaseLetter),
+    ('\u{0520}', '\u{0520}', UppercaseLetter),
+    ('\u{0521}', '\u{0521}', LowercaseLetter),
+    ('\u{0522}', '\u{0522}', UppercaseLetter),
+    ('\u{0523}', '\u{0523}', LowercaseLetter),
+    ('\u{0524}', '\u{0524}', UppercaseLetter),
+    ('\u{0525}', '\u{0525}', LowercaseLetter),
+    ('\u{0526}', '\u{0526}', UppercaseLetter),
+    ('\u{0527}', '\u{0527}', LowercaseLetter),
+    ('\u{0528}', '\u{0528}', UppercaseLetter),
+    ('\u{0529}', '\u{0529}', LowercaseLetter),
+    ('\u{052A}', '\u{052A}', UppercaseLetter),
+    ('\u{052B}', '\u{052B}', LowercaseLetter),
+    ('\u{052C}', '\u{052C}', UppercaseLetter),
+    ('\u{052D}', '\u{052D}', LowercaseLetter),
+    ('\u{052E}', '\u{052E}', UppercaseLetter),
+    ('\u{052F}', '\u{052F}', LowercaseLetter),
+    ('\u{0531}', '\u{0556}', UppercaseLetter),
+    ('\u{0559}', '\u{0559}', ModifierLetter),
+    ('\u{055A}', '\u{055F}', OtherPunctuation),
+    ('\u{0560}', '\u{0588}', LowercaseLetter),
+    ('\u{0589}', '\u{0589}', OtherPunctuation),
+    ('\u{058A}', '\u{058A}', DashPunctuation),
+    ('\u{058D}', '\u{058E}', OtherSymbol),
+    ('\u{058F}', '\u{058F}', CurrencySymbol),
+    ('\u{0591}', '\u{05BD}', NonspacingMark),
+    ('\u{05BE}', '\u{05BE}', DashPunctuation),
+    ('\u{05BF}', '\u{05BF}', NonspacingMark),
+    ('\u{05C0}', '\u{05C0}', OtherPunctuation),
+    ('\u{05C1}', '\u{05C2}', NonspacingMark),
+    ('\u{05C3}', '\u{05C3}', OtherPunctuation),
+    ('\u{05C4}', '\u{05C5}', NonspacingMark),
+    ('\u{05C6}', '\u{05C6}', OtherPunctuation),
+    ('\u{05C7}', '\u{05C7}', NonspacingMark),
+    ('\u{05D0}', '\u{05EA}', OtherLetter),
+    ('\u{05EF}', '\u{05F2}', OtherLetter),
+    ('\u{05F3}', '\u{05F4}', OtherPunctuation),
+    ('\u{0600}', '\u{0605}', Format),
+    ('\u{0606}', '\u{0608}', MathSymbol),
+    ('\u{0609}', '\u{060A}', OtherPunctuation),
+    ('\u{060B}', '\u{060B}', CurrencySymbol),
+    ('\u{060C}', '\u{060D}', OtherPunctuation),
+    ('\u{060E}', '\u{060F}', OtherSymbol),
+    ('\u{0610}', '\u{061A}', NonspacingMark),
+    ('\u{061B}', '\u{061B}', OtherPunctuation),
+    ('\u{061C}', '\u{061C}', Format),
+    ('\u{061D}', '\u{061F}', OtherPunctuation),
+    ('\u{0620}', '\u{063F}', OtherLetter),
+    ('\u{0640}', '\u{0640}', ModifierLetter),
+    ('\u{0641}', '\u{064A}', OtherLetter),
+    ('\u{064B}', '\u{065F}', NonspacingMark),
+    ('\u{0660}', '\u{0669}', DecimalNumber),
+    ('\u{066A}', '\u{066D}', OtherPunctuation),
+    ('\u{066E}', '\u{066F}', OtherLetter),
+    ('\u{0670}', '\u{0670}', NonspacingMark),
+    ('\u{0671}', '\u{06D3}', OtherLetter),
+    ('\u{06D4}', '\u{06D4}', OtherPunctuation),
+    ('\u{06D5}', '\u{06D5}', OtherLetter),
+    ('\u{06D6}', '\u{06DC}', NonspacingMark),
+    ('\u{06DD}', '\u{06DD}', Format),
+    ('\u{06DE}', '\u{06DE}', OtherSymbol),
+    ('\u{06DF}', '\u{06E4}', NonspacingMark),
+    ('\u{06E5}', '\u{06E6}', ModifierLetter),
+    ('\u{06E7}', '\u{06E8}', NonspacingMark),
+    ('\u{06E9}', '\u{06E9}', OtherSymbol),
+    ('\u{06EA}', '\u{06ED}', NonspacingMark),
+    ('\u{06EE}', '\u{06EF}', OtherLetter),
+    ('\u{06F0}', '\u{06F9}', DecimalNumber),
+    ('\u{06FA}', '\u{06FC}', OtherLetter),
+    ('\u{06FD}', '\u{06FE}', OtherSymbol),
+    ('\u{06FF}', '\u{06FF}', OtherLetter),
+    ('\u{0700}', '\u{070D}', OtherPunctuation),
+    ('\u{070F}', '\u{070F}', Format),
+    ('\u{0710}', '\u{0710}', OtherLetter),
+    ('\u{0711}', '\u{0711}', NonspacingMark),
+    ('\u{0712}', '\u{072F}', OtherLetter),
+    ('\u{0730}', '\u{074A}', NonspacingMark),
+    ('\u{074D}', '\u{07A5}', OtherLetter),
+    ('\u{07A6}', '\u{07B0}', NonspacingMark),
+    ('\u{07B1}', '\u{07B1}', OtherLetter),
+    ('\u{07C0}', '\u{07C9}', DecimalNumber),
+    ('\u{07CA}', '\u{07EA}', OtherLetter),
+    ('\u{07EB}', '\u{07F3}', NonspacingMark),
+    ('\u{07F4}', '\u{07F5}', ModifierLetter),
+    ('\u{07F6}', '\u{07F6}', OtherSymbol),
+    ('\u{07F7}', '\u{07F9}', OtherPunctuation),
+    ('\u{07FA}', '\u{07FA}', ModifierLetter),
+    ('\u{07FD}', '\u{07FD}', NonspacingMark),
+    ('\u{07FE}', '\u{07FF}', CurrencySymbol),
+    ('\u{0800}', '\u{0815}', OtherLetter),
+    ('\u{0816}', '\u{0819}', NonspacingMark),
+    ('\u{081A}', '\u{081A}', ModifierLetter),
+    ('\u{081B}', '\u{0823}', NonspacingMark),
+    ('\u{0824}', '\u{0824}', ModifierLetter),
+    ('\u{0825}', '\u{0827}', NonspacingMark),
+    ('\u{0828}', '\u{0828}', ModifierLetter),
+    ('\u{0829}', '\u{082D}', NonspacingMark),
+    ('\u{0830}', '\u{083E}', OtherPunctuation),
+    ('\u{0840}', '\u{0858}', OtherLetter),
+    ('\u{0859}', '\u{085B}', NonspacingMark),
+    ('\u{085E}', '\u{085E}', OtherPunctuation),
+    ('\u{0860}', '\u{086A}', OtherLetter),
+    ('\u{0870}', '\u{0887}', OtherLetter),
+    ('\u{0888}', '\u{0888}', ModifierSymbol),
+    ('\u{0889}', '\u{088E}', OtherLetter),
+    ('\u{0890}', '\u{0891}', Format),
+    ('\u{0898}', '\u{089F}', NonspacingMark),
+    ('\u{08A0}', '\u{08C8}', OtherLetter),
+    ('\u{08C9}', '\u{08C9}', ModifierLetter),
+    ('\u{08CA}', '\u{08E1}', NonspacingMark),
+    ('\u{08E2}', '\u{08E2}', Format),
+    ('\u{08E3}', '\u{0902}', NonspacingMark),
+    ('\u{0903}', '\u{0903}', SpacingMark),
+    ('\u{0904}', '\u{0939}', OtherLetter),
+    ('\u{093A}', '\u{093A}', NonspacingMark),
+    ('\u{093B}', '\u{093B}', SpacingMark),
+    ('\u{093C}', '\u{093C}', NonspacingMark),
+    ('\u{093D}', '\u{093D}', OtherLetter),
+    ('\u{093E}', '\u{0940}', SpacingMark),
+    ('\u{0941}', '\u{0948}', NonspacingMark),
+    ('\u{0949}', '\u{094C}', SpacingMark),
+    ('\u{094D}', '\u{094D}', NonspacingMark),
+    ('\u{094E}', '\u{094F}', SpacingMark),
+    ('\u{0950}', '\u{0950}', OtherLetter),
+    ('\u{0951}', '\u{0957}', NonspacingMark),
+    ('\u{0958}', '\u{0961}', OtherLetter),
+    ('\u{0962}', '\u{0963}', NonspacingMark),
+    ('\u{0964}', '\u{0965}', OtherPunctuation),
+    ('\u{0966}', '\u{096F}', DecimalNumber),
+    ('\u{0970}', '\u{0970}', OtherPunctuation),
+    ('\u{0971}', '\u{0971}', ModifierLetter),
+    ('\u{0972}', '\u{0980}', OtherLetter),
+    ('\u{0981}', '\u{0981}', NonspacingMark),
+    ('\u{0982}', '\u{0983}', SpacingMark),
+    ('\u{0985}', '\u{098C}', OtherLetter),
+    ('\u{098F}', '\u{0990}', OtherLetter),
+    ('\u{0993}', '\u{09A8}', OtherLetter),
+    ('\u{09AA}', '\u{09B0}', OtherLetter),
+    ('\u{09B2}', '\u{09B2}', OtherLetter),
+    ('\u{09B6}', '\u{09B9}', OtherLetter),
+    ('\u{09BC}', '\u{09BC}', NonspacingMark),
+    ('\u{09BD}', '\u{09BD}', OtherLetter),
+    ('\u{09BE}', '\u{09C0}', SpacingMark),
+    ('\u{09C1}', '\u{09C4}', NonspacingMark),
+    ('\u{09C7}', '\u{09C8}', SpacingMark),
+    ('\u{09CB}', '\u{09CC}', SpacingMark),
+    ('\u{09CD}', '\u{09CD}', NonspacingMark),
+    ('\u{09CE}', '\u{09CE}', OtherLetter),
+    ('\u{09D7}', '\u{09D7}', SpacingMark),
+    ('\u{09DC}', '\u{09DD}', OtherLetter),
+    ('\u{09DF}', '\u{09E1}', OtherLetter),
+    ('\u{09E2}', '\u{09E3}', NonspacingMark),
+    ('\u{09E6}', '\u{09EF}', DecimalNumber),
+    ('\u{09F0}', '\u{09F1}', OtherLetter),
+    ('\u{09F2}', '\u{09F3}', CurrencySymbol),
+    ('\u{09F4}', '\u{09F9}', OtherNumber),
+    ('\u{09FA}', '\u{09FA}', OtherSymbol),
+    ('\u{09FB}', '\u{09FB}', CurrencySymbol),
+    ('\u{09FC}', '\u{09FC}', OtherLetter),
+    ('\u{09FD}', '\u{09FD}', OtherPunctuation),
+    ('\u{09FE}', '\u{09FE}', NonspacingMark),
+    ('\u{0A01}', '\u{0A02}', NonspacingMark),
+    ('\u{0A03}', '\u{0A03}', SpacingMark),
+    ('\u{0A05}', '\u{0A0A}', OtherLetter),
+    ('\u{0A0F}', '\u{0A10}', OtherLetter),
+    ('\u{0A13}', '\u{0A28}', OtherLetter),
+    ('\u{0A2A}', '\u{0A30}', OtherLetter),
+    ('\u{0A32}', '\u{0A33}', OtherLetter),
+    ('\u{0A35}', '\u{0A36}', OtherLetter),
+    ('\u{0A38}', '\u{0A39}', OtherLetter),
+    ('\u{0A3C}', '\u{0A3C}', NonspacingMark),
+    ('\u{0A3E}', '\u{0A40}', SpacingMark),
+    ('\u{0A41}', '\u{0A42}', NonspacingMark),
+    ('\u{0A47}', '\u{0A48}', NonspacingMark),
+    ('\u{0A4B}', '\u{0A4D}', NonspacingMark),
+    ('\u{0A51}', '\u{0A51}', NonspacingMark),
+    ('\u{0A59}', '\u{0A5C}', OtherLetter),
+    ('\u{0A5E}', '\u{0A5E}', OtherLetter),
+    ('\u{0A66}', '\u{0A6F}', DecimalNumber),
+    ('\u{0A70}', '\u{0A71}', NonspacingMark),
+    ('\u{0A72}', '\u{0A74}', OtherLetter),
+    ('\u{0A75}', '\u{0A75}', NonspacingMark),
+    ('\u{0A76}', '\u{0A76}', OtherPunctuation),
+    ('\u{0A81}', '\u{0A82}', NonspacingMark),
+    ('\u{0A83}', '\u{0A83}', SpacingMark),
+    ('\u{0A85}', '\u{0A8D}', OtherLetter),
+    ('\u{0A8F}', '\u{0A91}', OtherLetter),
+    ('\u{0A93}', '\u{0AA8}', OtherLetter),
+    ('\u{0AAA}', '\u{0AB0}', OtherLetter),
+    ('\u{0AB2}', '\u{0AB3}', OtherLetter),
+    ('\u{0AB5}', '\u{0AB9}', OtherLetter),
+    ('\u{0ABC}', '\u{0ABC}', NonspacingMark),
+    ('\u{0ABD}', '\u{0ABD}', OtherLetter),
+    ('\u{0ABE}', '\u{0AC0}', SpacingMark),
+    ('\u{0AC1}', '\u{0AC5}', NonspacingMark),
+    ('\u{0AC7}', '\u{0AC8}', NonspacingMark),
+    ('\u{0AC9}', '\u{0AC9}', SpacingMark),
+    ('\u{0ACB}', '\u{0ACC}', SpacingMark),
+    ('\u{0ACD}', '\u{0ACD}', NonspacingMark),
+    ('\u{0AD0}', '\u{0AD0}', OtherLetter),
+    ('\u{0AE0}', '\u{0AE1}', OtherLetter),
+    ('\u{0AE2}', '\u{0AE3}', NonspacingMark),
+    ('\u{0AE6}', '\u{0AEF}', DecimalNumber),
+    ('\u{0AF0}', '\u{0AF0}', OtherPunctuation),
+    ('\u{0AF1}', '\u{0AF1}', CurrencySymbol),
+    ('\u{0AF9}', '\u{0AF9}', OtherLetter),
+    ('\u{0AFA}', '\u{0AFF}', NonspacingMark),
+    ('\u{0B01}', '\u{0B01}', NonspacingMark),
+    ('\u{0B02}', '\u{0B03}', SpacingMark),
+    ('\u{0B05}', '\u{0B0C}', OtherLetter),
+    ('\u{0B0F}', '\u{0B10}', OtherLetter),
+    ('\u{0B13}', '\u{0B28}', OtherLetter),
+    ('\u{0B2A}', '\u{0B30}', OtherLetter),
+    ('\u{0B32}', '\u{0B33}', OtherLetter),
+    ('\u{0B35}', '\u{0B39}', OtherLetter),
+    ('\u{0B3C}', '\u{0B3C}', NonspacingMark),
+    ('\u{0B3D}', '\u{0B3D}', OtherLetter),
+    ('\u{0B3E}', '\u{0B3E}', SpacingMark),
+    ('\u{0B3F}', '\u{0B3F}', NonspacingMark),
+    ('\u{0B40}', '\u{0B40}', SpacingMark),
+    ('\u{0B41}', '\u{0B44}', NonspacingMark),
+    ('\u{0B47}', '\u{0B48}', SpacingMark),
+    ('\u{0B4B}', '\u{0B4C}', SpacingMark),
+    ('\u{0B4D}', '\u{0B4D}', NonspacingMark),
+    ('\u{0B55}', '\u{0B56}', NonspacingMark),
+    ('\u{0B57}', '\u{0B57}', SpacingMark),
+    ('\u{0B5C}', '\u{0B5D}', OtherLetter),
+    ('\u{0B5F}', '\u{0B61}', OtherLetter),
+    ('\u{0B62}', '\u{0B63}', NonspacingMark),
+    ('\u{0B66}', '\u{0B6F}', DecimalNumber),
+    ('\u{0B70}', '\u{0B70}', OtherSymbol),
+    ('\u{0B71}', '\u{0B71}', OtherLetter),
+    ('\u{0B72}', '\u{0B77}', OtherNumber),
+    ('\u{0B82}', '\u{0B82}', NonspacingMark),
+    ('\u{0B83}', '\u{0B83}', OtherLetter),
+    ('\u{0B85}', '\u{0B8A}', OtherLetter),
+    ('\u{0B8E}', '\u{0B90}', OtherLetter),
+    ('\u{0B92}', '\u{0B95}', OtherLetter),
+    ('\u{0B99}', '\u{0B9A}', OtherLetter),
+    ('\u{0B9C}', '\u{0B9C}', OtherLetter),
+    ('\u{0B9E}', '\u{0B9F}', OtherLetter),
+    ('\u{0BA3}', '\u{0BA4}', OtherLetter),
+    ('\u{0BA8}', '\u{0BAA}', OtherLetter),
+    ('\u{0BAE}', '\u{0BB9}', OtherLetter),
+    ('\u{0BBE}', '\u{0BBF}', SpacingMark),
+    ('\u{0BC0}', '\u{0BC0}', NonspacingMark),
+    ('\u{0BC1}', '\u{0BC2}', SpacingMark),
+    ('\u{0BC6}', '\u{0BC8}', SpacingMark),
+    ('\u{0BCA}', '\u{0BCC}', SpacingMark),
+    ('\u{0BCD}', '\u{0BCD}', NonspacingMark),
+    ('\u{0BD0}', '\u{0BD0}', OtherLetter),
+    ('\u{0BD7}', '\u{0BD7}', SpacingMark),
+    ('\u{0BE6}', '\u{0BEF}', DecimalNumber),
+    ('\u{0BF0}', '\u{0BF2}', OtherNumber),
+    ('\u{0BF3}', '\u{0BF8}', OtherSymbol),
+    ('\u{0BF9}', '\u{0BF9}', CurrencySymbol),
+    ('\u{0BFA}', '\u{0BFA}', OtherSymbol),
+    ('\u{0C00}', '\u{0C00}', NonspacingMark),
+    ('\u{0C01}', '\u{0C03}', SpacingMark),
+    ('\u{0C04}', '\u{0C04}', NonspacingMark),
+    ('\u{0C05}', '\u{0C0C}', OtherLetter),
+    ('\u{0C0E}', '\u{0C10}', OtherLetter),
+    ('\u{0C12}', '\u{0C28}', OtherLetter),
+    ('\u{0C2A}', '\u{0C39}', OtherLetter),
+    ('\u{0C3C}', '\u{0C3C}', NonspacingMark),
+    ('\u{0C3D}', '\u{0C3D}', OtherLetter),
+    ('\u{0C3E}', '\u{0C40}', NonspacingMark),
+    ('\u{0C41}', '\u{0C44}', SpacingMark),
+    ('\u{0C46}', '\u{0C48}', NonspacingMark),
+    ('\u{0C4A}', '\u{0C4D}', NonspacingMark),
+    ('\u{0C55}', '\u{0C56}', NonspacingMark),
+    ('\u{0C58}', '\u{0C5A}', OtherLetter),
+    ('\u{0C5D}', '\u{0C5D}', OtherLetter),
+    ('\u{0C60}', '\u{0C61}', OtherLetter),
+    ('\u{0C62}', '\u{0C63}', NonspacingMark),
+    ('\u{0C66}', '\u{0C6F}', DecimalNumber),
+    ('\u{0C77}', '\u{0C77}', OtherPunctuation),
+    ('\u{0C78}', '\u{0C7E}', OtherNumber),
+    ('\u{0C7F}', '\u{0C7F}', OtherSymbol),
+    ('\u{0C80}', '\u{0C80}', OtherLetter),
+    ('\u{0C81}', '\u{0C81}', NonspacingMark),
+    ('\u{0C82}', '\u{0C83}', SpacingMark),
+    ('\u{0C84}', '\u{0C84}', OtherPunctuation),
+    ('\u{0C85}', '\u{0C8C}', OtherLetter),
+    ('\u{0C8E}', '\u{0C90}', OtherLetter),
+    ('\u{0C92}', '\u{0CA8}', OtherLetter),
+    ('\u{0CAA}', '\u{0CB3}', OtherLetter),
+    ('\u{0CB5}', '\u{0CB9}', OtherLetter),
+    ('\u{0CBC}', '\u{0CBC}', NonspacingMark),
+    ('\u{0CBD}', '\u{0CBD}', OtherLetter),
+    ('\u{0CBE}', '\u{0CBE}', SpacingMark),
+    ('\u{0CBF}', '\u{0CBF}', NonspacingMark),
+    ('\u{0CC0}', '\u{0CC4}', SpacingMark),
+    ('\u{0CC6}', '\u{0CC6}', NonspacingMark),
+    ('\u{0CC7}', '\u{0CC8}', SpacingMark),
+    ('\u{0CCA}', '\u{0CCB}', SpacingMark),
+    ('\u{0CCC}', '\u{0CCD}', NonspacingMark),
+    ('\u{0CD5}', '\u{0CD6}', SpacingMark),
+    ('\u{0CDD}', '\u{0CDE}', OtherLetter),
+    ('\u{0CE0}', '\u{0CE1}', OtherLetter),
+    ('\u{0CE2}', '\u{0CE3}', NonspacingMark),
+    ('\u{0CE6}', '\u{0CEF}', DecimalNumber),
+    ('\u{0CF1}', '\u{0CF2}', OtherLetter),
+    ('\u{0D00}', '\u{0D01}', NonspacingMark),
+    ('\u{0D02}', '\u{0D03}', SpacingMark),
+    ('\u{0D04}', '\u{0D0C}', OtherLetter),
+    ('\u{0D0E}', '\u{0D10}', OtherLetter),
+    ('\u{0D12}', '\u{0D3A}', OtherLetter),
+    ('\u{0D3B}', '\u{0D3C}', NonspacingMark),
+    ('\u{0D3D}', '\u{0D3D}', OtherLetter),
+    ('\u{0D3E}', '\u{0D40}', SpacingMark),
+    ('\u{0D41}', '\u{0D44}', NonspacingMark),
+    ('\u{0D46}', '\u{0D48}', SpacingMark),
+    ('\u{0D4A}', '\u{0D4C}', SpacingMark),
+    ('\u{0D4D}', '\u{0D4D}', NonspacingMark),
+    ('\u{0D4E}', '\u{0D4E}', OtherLetter),
+    ('\u{0D4F}', '\u{0D4F}', OtherSymbol),
+    ('\u{0D54}', '\u{0D56}', OtherLetter),
+    ('\u{0D57}', '\u{0D57}', SpacingMark),
+    ('\u{0D58}', '\u{0D5E}', OtherNumber),
+    ('\u{0D5F}', '\u{0D61}', OtherLetter),
+    ('\u{0D62}', '\u{0D63}', NonspacingMark),
+    ('\u{0D66}', '\u{0D6F}', DecimalNumber),
+    ('\u{0D70}', '\u{0D78}', OtherNumber),
+    ('\u{0D79}', '\u{0D79}', OtherSymbol),
+    ('\u{0D7A}', '\u{0D7F}', OtherLetter),
+    ('\u{0D81}', '\u{0D81}', NonspacingMark),
+    ('\u{0D82}', '\u{0D83}', SpacingMark),
+    ('\u{0D85}', '\u{0D96}', OtherLetter),
+    ('\u{0D9A}', '\u{0DB1}', OtherLetter),
+    ('\u{0DB3}', '\u{0DBB}', OtherLetter),
+    ('\u{0DBD}', '\u{0DBD}', OtherLetter),
+    ('\u{0DC0}', '\u{0DC6}', OtherLetter),
+    ('\u{0DCA}', '\u{0DCA}', NonspacingMark),
+    ('\u{0DCF}', '\u{0DD1}', SpacingMark),
+    ('\u{0DD2}', '\u{0DD4}', NonspacingMark),
+    ('\u{0DD6}', '\u{0DD6}', NonspacingMark),
+    ('\u{0DD8}', '\u{0DDF}', SpacingMark),
+    ('\u{0DE6}', '\u{0DEF}', DecimalNumber),
+    ('\u{0DF2}', '\u{0DF3}', SpacingMark),
+    ('\u{0DF4}', '\u{0DF4}', OtherPunctuation),
+    ('\u{0E01}', '\u{0E30}', OtherLetter),
+    ('\u{0E31}', '\u{0E31}', NonspacingMark),
+    ('\u{0E32}', '\u{0E33}', OtherLetter),
+    ('\u{0E34}', '\u{0E3A}', NonspacingMark),
+    ('\u{0E3F}', '\u{0E3F}', CurrencySymbol),
+    ('\u{0E40}', '\u{0E45}', OtherLetter),
+    ('\u{0E46}', '\u{0E46}', ModifierLetter),
+    ('\u{0E47}', '\u{0E4E}', NonspacingMark),
+    ('\u{0E4F}', '\u{0E4F}', OtherPunctuation),
+    ('\u{0E50}', '\u{0E59}', DecimalNumber),
+    ('\u{0E5A}', '\u{0E5B}', OtherPunctuation),
+    ('\u{0E81}', '\u{0E82}', OtherLetter),
+    ('\u{0E84}', '\u{0E84}', OtherLetter),
+    ('\u{0E86}', '\u{0E8A}', OtherLetter),
+    ('\u{0E8C}', '\u{0EA3}', OtherLetter),
+    ('\u{0EA5}', '\u{0EA5}', OtherLetter),
+    ('\u{0EA7}', '\u{0EB0}', OtherLetter),
+    ('\u{0EB1}', '\u{0EB1}', NonspacingMark),
+    ('\u{0EB2}', '\u{0EB3}', OtherLetter),
+    ('\u{0EB4}', '\u{0EBC}', NonspacingMark),
+    ('\u{0EBD}', '\u{0EBD}', OtherLetter),
+    ('\u{0EC0}', '\u{0EC4}', OtherLetter),
+    ('\u{0EC6}', '\u{0EC6}', ModifierLetter),
+    ('\u{0EC8}', '\u{0ECD}', NonspacingMark),
+    ('\u{0ED0}', '\u{0ED9}', DecimalNumber),
+    ('\u{0EDC}', '\u{0EDF}', OtherLetter),
+    ('\u{0F00}', '\u{0F00}', OtherLetter),
+    ('\u{0F01}', '\u{0F03}', OtherSymbol),
+    ('\u{0F04}', '\u{0F12}', OtherPunctuation),
+    ('\u{0F13}', '\u{0F13}', OtherSymbol),
+    ('\u{0F14}', '\u{0F14}', OtherPunctuation),
+    ('\u{0F15}', '\u{0F17}', OtherSymbol),
+    ('\u{0F18}', '\u{0F19}', NonspacingMark),
+    ('\u{0F1A}', '\u{0F1F}', OtherSymbol),
+    ('\u{0F20}', '\u{0F29}', DecimalNumber),
+    ('\u{0F2A}', '\u{0F33}', OtherNumber),
+    ('\u{0F34}', '\u{0F34}', OtherSymbol),
+    ('\u{0F35}', '\u{0F35}', NonspacingMark),
+    ('\u{0F36}', '\u{0F36}', OtherSymbol),
+    ('\u{0F37}', '\u{0F37}', NonspacingMark),
+    ('\u{0F38}', '\u{0F38}', OtherSymbol),
+    ('\u{0F39}', '\u{0F39}', NonspacingMark),
+    ('\u{0F3A}', '\u{0F3A}', OpenPunctuation),
+    ('\u{0F3B}', '\u{0F3B}', ClosePunctuation),
+    ('\u{0F3C}', '\u{0F3C}', OpenPunctuation),
+    ('\u{0F3D}', '\u{0F3D}', ClosePunctuation),
+    ('\u{0F3E}', '\u{0F3F}', SpacingMark),
+    ('\u{0F40}', '\u{0F47}', OtherLetter),
+    ('\u{0F49}', '\u{0F6C}', OtherLetter),
+    ('\u{0F71}', '\u{0F7E}', NonspacingMark),
+    ('\u{0F7F}', '\u{0F7F}', SpacingMark),
+    ('\u{0F80}', '\u{0F84}', NonspacingMark),
+    ('\u{0F85}', '\u{0F85}', OtherPunctuation),
+    ('\u{0F86}', '\u{0F87}', NonspacingMark),
+    ('\u{0F88}', '\u{0F8C}', OtherLetter),
+    ('\u{0F8D}', '\u{0F97}', NonspacingMark),
+    ('\u{0F99}', '\u{0FBC}', NonspacingMark),
+    ('\u{0FBE}', '\u{0FC5}', OtherSymbol),
+    ('\u{0FC6}', '\u{0FC6}', NonspacingMark),
+    ('\u{0FC7}', '\u{0FCC}', OtherSymbol),
+    ('\u{0FCE}', '\u{0FCF}', OtherSymbol),
+    ('\u{0FD0}', '\u{0FD4}', OtherPunctuation),
+    ('\u{0FD5}', '\u{0FD8}', OtherSymbol),
+    ('\u{0FD9}', '\u{0FDA}', OtherPunctuation),
+    ('\u{1000}', '\u{102A}', OtherLetter),
+    ('\u{102B}', '\u{102C}', SpacingMark),
+    ('\u{102D}', '\u{1030}', NonspacingMark),
+    ('\u{1031}', '\u{1031}', SpacingMark),
+    ('\u{1032}', '\u{1037}', NonspacingMark),
+    ('\u{1038}', '\u{1038}', SpacingMark),
+    ('\u{1039}', '\u{103A}', NonspacingMark),
+    ('\u{103B}', '\u{103C}', SpacingMark),
+    ('\u{103D}', '\u{103E}', NonspacingMark),
+    ('\u{103F}', '\u{103F}', OtherLetter),
+    ('\u{1040}', '\u{1049}', DecimalNumber),
+    ('\u{104A}', '\u{104F}', OtherPunctuation),
+    ('\u{1050}', '\u{1055}', OtherLetter),
+    ('\u{1056}', '\u{1057}', SpacingMark),
+    ('\u{1058}', '\u{1059}', NonspacingMark),
+    ('\u{105A}', '\u{105D}', OtherLetter),
+    ('\u{105E}', '\u{1060}', NonspacingMark),
+    ('\u{1061}', '\u{1061}', OtherLetter),
+    ('\u{1062}', '\u{1064}', SpacingMark),
+    ('\u{1065}', '\u{1066}', OtherLetter),
+    ('\u{1067}', '\u{106D}', SpacingMark),
+    ('\u{106E}', '\u{1070}', OtherLetter),
+    ('\u{1071}', '\u{1074}', NonspacingMark),
+    ('\u{1075}', '\u{1081}', OtherLetter),
+    ('\u{1082}', '\u{1082}', NonspacingMark),
+    ('\u{1083}', '\u{1084}', SpacingMark),
+    ('\u{1085}', '\u{1086}', NonspacingMark),
+    ('\u{1087}', '\u{108C}', SpacingMark),
+    ('\u{108D}', '\u{108D}', NonspacingMark),
+    ('\u{108E}', '\u{108E}', OtherLetter),
+    ('\u{108F}', '\u{108F}', SpacingMark),
+    ('\u{1090}', '\u{1099}', DecimalNumber),
+    ('\u{109A}', '\u{109C}', SpacingMark),
+    ('\u{109D}', '\u{109D}', NonspacingMark),
+    ('\u{109E}', '\u{109F}', OtherSymbol),
+    ('\u{10A0}', '\u{10C5}', UppercaseLetter),
+    ('\u{10C7}', '\u{10C7}', UppercaseLetter),
+    ('\u{10CD}', '\u{10CD}', UppercaseLetter),
+    ('\u{10D0}', '\u{10FA}', LowercaseLetter),
+    ('\u{10FB}', '\u{10FB}', OtherPunctuation),
+    ('\u{10FC}', '\u{10FC}', ModifierLetter),
+    ('\u{10FD}', '\u{10FF}', LowercaseLetter),
+    ('\u{1100}', '\u{1248}', OtherLetter),
+    ('\u{124A}', '\u{124D}', OtherLetter),
+    ('\u{1250}', '\u{1256}', OtherLetter),
+    ('\u{1258}', '\u{1258}', OtherLetter),
+    ('\u{125A}', '\u{125D}', OtherLetter),
+    ('\u{1260}', '\u{1288}', OtherLetter),
+    ('\u{128A}', '\u{128D}', OtherLetter),
+    ('\u{1290}', '\u{12B0}', OtherLetter),
+    ('\u{12B2}', '\u{12B5}', OtherLetter),
+    ('\u{12B8}', '\u{12BE}', OtherLetter),
+    ('\u{12C0}', '\u{12C0}', OtherLetter),
+    ('\u{12C2}', '\u{12C5}', OtherLetter),
+    ('\u{12C8}', '\u{12D6}', OtherLetter),
+    ('\u{12D8}', '\u{1310}', OtherLetter),
+    ('\u{1312}', '\u{1315}', OtherLetter),
+    ('\u{1318}', '\u{135A}', OtherLetter),
+    ('\u{135D}', '\u{135F}', NonspacingMark),
+    ('\u{1360}', '\u{1368}', OtherPunctuation),
+    ('\u{1369}', '\u{137C}', OtherNumber),
+    ('\u{1380}', '\u{138F}', OtherLetter),
+    ('\u{1390}', '\u{1399}', OtherSymbol),
+    ('\u{13A0}', '\u{13F5}', UppercaseLetter),
+    ('\u{13F8}', '\u{13FD}', LowercaseLetter),
+    ('\u{1400}', '\u{1400}', DashPunctuation),
+    ('\u{1401}', '\u{166C}', OtherLetter),
+    ('\u{166D}', '\u{166D}', OtherSymbol),
+    ('\u{166E}', '\u{166E}', OtherPunctuation),
+    ('\u{166F}', '\u{167F}', OtherLetter),
+    ('\u{1680}', '\u{1680}', SpaceSeparator),
+    ('\u{1681}', '\u{169A}', OtherLetter),
+    ('\u{169B}', '\u{169B}', OpenPunctuation),
+    ('\u{169C}', '\u{169C}', ClosePunctuation),
+    ('\u{16A0}', '\u{16EA}', OtherLetter),
+    ('\u{16EB}', '\u{16ED}', OtherPunctuation),
+    ('\u{16EE}', '\u{16F0}', LetterNumber),
+    ('\u{16F1}', '\u{16F8}', OtherLetter),
+    ('\u{1700}', '\u{1711}', OtherLetter),
+    ('\u{1712}', '\u{1714}', NonspacingMark),
+    ('\u{1715}', '\u{1715}', SpacingMark),
+    ('\u{171F}', '\u{1731}', OtherLetter),
+    ('\u{1732}', '\u{1733}', NonspacingMark),
+    ('\u{1734}', '\u{1734}', SpacingMark),
+    ('\u{1735}', '\u{1736}', OtherPunctuation),
+    ('\u{1740}', '\u{1751}', OtherLetter),
+    ('\u{1752}', '\u{1753}', NonspacingMark),
+    ('\u{1760}', '\u{176C}', OtherLetter),
+    ('\u{176E}', '\u{1770}', OtherLetter),
+    ('\u{1772}', '\u{1773}', NonspacingMark),
+    ('\u{1780}', '\u{17B3}', OtherLetter),
+    ('\u{17B4}', '\u{17B5}', NonspacingMark),
+    ('\u{17B6}', '\u{17B6}', SpacingMark),
+    ('\u{17B7}', '\u{17BD}', NonspacingMark),
+    ('\u{17BE}', '\u{17C5}', SpacingMark),
+    ('\u{17C6}', '\u{17C6}', NonspacingMark),
+    ('\u{17C7}', '\u{17C8}', SpacingMark),
+    ('\u{17C9}', '\u{17D3}', NonspacingMark),
+    ('\u{17D4}', '\u{17D6}', OtherPunctuation),
+    ('\u{17D7}', '\u{17D7}', ModifierLetter),
+    ('\u{17D8}', '\u{17DA}', OtherPunctuation),
+    ('\u{17DB}', '\u{17DB}', CurrencySymbol),
+    ('\u{17DC}', '\u{17DC}', OtherLetter),
+    ('\u{17DD}', '\u{17DD}', NonspacingMark),
+    ('\u{17E0}', '\u{17E9}', DecimalNumber),
+    ('\u{17F0}', '\u{17F9}', OtherNumber),
+    ('\u{1800}', '\u{1805}', OtherPunctuation),
+    ('\u{1806}', '\u{1806}', DashPunctuation),
+    ('\u{1807}', '\u{180A}', OtherPunctuation),
+    ('\u{180B}', '\u{180D}', NonspacingMark),
+    ('\u{180E}', '\u{180E}', Format),
+    ('\u{180F}', '\u{180F}', NonspacingMark),
+    ('\u{1810}', '\u{1819}', DecimalNumber),
+    ('\u{1820}', '\u{1842}', OtherLetter),
+    ('\u{1843}', '\u{1843}', ModifierLetter),
+    ('\u{1844}', '\u{1878}', OtherLetter),
+    ('\u{1880}', '\u{1884}', OtherLetter),
+    ('\u{1885}', '\u{1886}', NonspacingMark),
+    ('\u{1887}', '\u{18A8}', OtherLetter),
+    ('\u{18A9}', '\u{18A9}', NonspacingMark),
+    ('\u{18AA}', '\u{18AA}', OtherLetter),
+    ('\u{18B0}', '\u{18F5}', OtherLetter),
+    ('\u{1900}', '\u{191E}', OtherLetter),
+    ('\u{1920}', '\u{1922}', NonspacingMark),
+    ('\u{1923}', '\u{1926}', SpacingMark),
+    ('\u{1927}', '\u{1928}', NonspacingMark),
+    ('\u{1929}', '\u{192B}', SpacingMark),
+    ('\u{1930}', '\u{1931}', SpacingMark),
+    ('\u{1932}', '\u{1932}', NonspacingMark),
+    ('\u{1933}', '\u{1938}', SpacingMark),
+    ('\u{1939}', '\u{193B}', NonspacingMark),
+    ('\u{1940}', '\u{1940}', OtherSymbol),
+    ('\u{1944}', '\u{1945}', OtherPunctuation),
+    ('\u{1946}', '\u{194F}', DecimalNumber),
+    ('\u{1950}', '\u{196D}', OtherLetter),
+    ('\u{1970}', '\u{1974}', OtherLetter),
+    ('\u{1980}', '\u{19AB}', OtherLetter),
+    ('\u{19B0}', '\u{19C9}', OtherLetter),
+    ('\u{19D0}', '\u{19D9}', DecimalNumber),
+    ('\u{19DA}', '\u{19DA}', OtherNumber),
+    ('\u{19DE}', '\u{19FF}', OtherSymbol),
+    ('\u{1A00}', '\u{1A16}', OtherLetter),
+    ('\u{1A17}', '\u{1A18}', NonspacingMark),
+    ('\u{1A19}', '\u{1A1A}', SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', NonspacingMark),
+    ('\u{1A1E}', '\u{1A1F}', OtherPunctuation),
+    ('\u{1A20}', '\u{1A54}', OtherLetter),
+    ('\u{1A55}', '\u{1A55}', SpacingMark),
+    ('\u{1A56}', '\u{1A56}', NonspacingMark),
+    ('\u{1A57}', '\u{1A57}', SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', NonspacingMark),
+    ('\u{1A60}', '\u{1A60}', NonspacingMark),
+    ('\u{1A61}', '\u{1A61}', SpacingMark),
+    ('\u{1A62}', '\u{1A62}', NonspacingMark),
+    ('\u{1A63}', '\u{1A64}', SpacingMark),
+    ('\u{1A65}', '\u{1A6C}', NonspacingMark),
+    ('\u{1A6D}', '\u{1A72}', SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', NonspacingMark),
+    ('\u{1A7F}', '\u{1A7F}', NonspacingMark),
+    ('\u{1A80}', '\u{1A89}', DecimalNumber),
+    ('\u{1A90}', '\u{1A99}', DecimalNumber),
+    ('\u{1AA0}', '\u{1AA6}', OtherPunctuation),
+    ('\u{1AA7}', '\u{1AA7}', ModifierLetter),
+    ('\u{1AA8}', '\u{1AAD}', OtherPunctuation),
+    ('\u{1AB0}', '\u{1ABD}', NonspacingMark),
+    ('\u{1ABE}', '\u{1ABE}', EnclosingMark),
+    ('\u{1ABF}', '\u{1ACE}', NonspacingMark),
+    ('\u{1B00}', '\u{1B03}', NonspacingMark),
+    ('\u{1B04}', '\u{1B04}', SpacingMark),
+    ('\u{1B05}', '\u{1B33}', OtherLetter),
+    ('\u{1B34}', '\u{1B34}', NonspacingMark),
+    ('\u{1B35}', '\u{1B35}', SpacingMark),
+    ('\u{1B36}', '\u{1B3A}', NonspacingMark),
+    ('\u{1B3B}', '\u{1B3B}', SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', NonspacingMark),
+    ('\u{1B3D}', '\u{1B41}', SpacingMark),
+    ('\u{1B42}', '\u{1B42}', NonspacingMark),
+    ('\u{1B43}', '\u{1B44}', SpacingMark),
+    ('\u{1B45}', '\u{1B4C}', OtherLetter),
+    ('\u{1B50}', '\u{1B59}', DecimalNumber),
+    ('\u{1B5A}', '\u{1B60}', OtherPunctuation),
+    ('\u{1B61}', '\u{1B6A}', OtherSymbol),
+    ('\u{1B6B}', '\u{1B73}', NonspacingMark),
+    ('\u{1B74}', '\u{1B7C}', OtherSymbol),
+    ('\u{1B7D}', '\u{1B7E}', OtherPunctuation),
+    ('\u{1B80}', '\u{1B81}', NonspacingMark),
+    ('\u{1B82}', '\u{1B82}', SpacingMark),
+    ('\u{1B83}', '\u{1BA0}', OtherLetter),
+    ('\u{1BA1}', '\u{1BA1}', SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', NonspacingMark),
+    ('\u{1BA6}', '\u{1BA7}', SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', NonspacingMark),
+    ('\u{1BAA}', '\u{1BAA}', SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', NonspacingMark),
+    ('\u{1BAE}', '\u{1BAF}', OtherLetter),
+    ('\u{1BB0}', '\u{1BB9}', DecimalNumber),
+    ('\u{1BBA}', '\u{1BE5}', OtherLetter),
+    ('\u{1BE6}', '\u{1BE6}', NonspacingMark),
+    ('\u{1BE7}', '\u{1BE7}', SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', NonspacingMark),
+    ('\u{1BEA}', '\u{1BEC}', SpacingMark),
+    ('\u{1BED}', '\u{1BED}', NonspacingMark),
+    ('\u{1BEE}', '\u{1BEE}', SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', NonspacingMark),
+    ('\u{1BF2}', '\u{1BF3}', SpacingMark),
+    ('\u{1BFC}', '\u{1BFF}', OtherPunctuation),
+    ('\u{1C00}', '\u{1C23}', OtherLetter),
+    ('\u{1C24}', '\u{1C2B}', SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', NonspacingMark),
+    ('\u{1C34}', '\u{1C35}', SpacingMark),
+    ('\u{1C36}', '\u{1C37}', NonspacingMark),
+    ('\u{1C3B}', '\u{1C3F}', OtherPunctuation),
+    ('\u{1C40}', '\u{1C49}', DecimalNumber),
+    ('\u{1C4D}', '\u{1C4F}', OtherLetter),
+    ('\u{1C50}', '\u{1C59}', DecimalNumber),
+    ('\u{1C5A}', '\u{1C77}', OtherLetter),
+    ('\u{1C78}', '\u{1C7D}', ModifierLetter),
+    ('\u{1C7E}', '\u{1C7F}', OtherPunctuation),
+    ('\u{1C80}', '\u{1C88}', LowercaseLetter),
+    ('\u{1C90}', '\u{1CBA}', UppercaseLetter),
+    ('\u{1CBD}', '\u{1CBF}', UppercaseLetter),
+    ('\u{1CC0}', '\u{1CC7}', OtherPunctuation),
+    ('\u{1CD0}', '\u{1CD2}', NonspacingMark),
+    ('\u{1CD3}', '\u{1CD3}', OtherPunctuation),
+    ('\u{1CD4}', '\u{1CE0}', NonspacingMark),
+    ('\u{1CE1}', '\u{1CE1}', SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', NonspacingMark),
+    ('\u{1CE9}', '\u{1CEC}', OtherLetter),
+    ('\u{1CED}', '\u{1CED}', NonspacingMark),
+    ('\u{1CEE}', '\u{1CF3}', OtherLetter),
+    ('\u{1CF4}', '\u{1CF4}', NonspacingMark),
+    ('\u{1CF5}', '\u{1CF6}', OtherLetter),
+    ('\u{1CF7}', '\u{1CF7}', SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', NonspacingMark),
+    ('\u{1CFA}', '\u{1CFA}', OtherLetter),
+    ('\u{1D00}', '\u{1D2B}', LowercaseLetter),
+    ('\u{1D2C}', '\u{1D6A}', ModifierLetter),
+    ('\u{1D6B}', '\u{1D77}', LowercaseLetter),
+    ('\u{1D78}', '\u{1D78}', ModifierLetter),
+    ('\u{1D79}', '\u{1D9A}', LowercaseLetter),
+    ('\u{1D9B}', '\u{1DBF}', ModifierLetter),
+    ('\u{1DC0}', '\u{1DFF}', NonspacingMark),
+    ('\u{1E00}', '\u{1E00}', UppercaseLetter),
+    ('\u{1E01}', '\u{1E01}', LowercaseLetter),
+    ('\u{1E02}', '\u{1E02}', UppercaseLetter),
+    ('\u{1E03}', '\u{1E03}', LowercaseLetter),
+    ('\u{1E04}', '\u{1E04}', UppercaseLetter),
+    ('\u{1E05}', '\u{1E05}', LowercaseLetter),
+    ('\u{1E06}', '\u{1E06}', UppercaseLetter),
+    ('\u{1E07}', '\u{1E07}', LowercaseLetter),
+    ('\u{1E08}', '\u{1E08}', UppercaseLetter),
+    ('\u{1E09}', '\u{1E09}', LowercaseLetter),
+    ('\u{1E0A}', '\u{1E0A}', UppercaseLetter),
+    ('\u{1E0B}', '\u{1E0B}', LowercaseLetter),
+    ('\u{1E0C}', '\u{1E0C}', UppercaseLetter),
+    ('\u{1E0D}', '\u{1E0D}', LowercaseLetter),
+    ('\u{1E0E}', '\u{1E0E}', UppercaseLetter),
+    ('\u{1E0F}', '\u{1E0F}', LowercaseLetter),
+    ('\u{1E10}', '\u{1E10}', UppercaseLetter),
+    ('\u{1E11}', '\u{1E11}', LowercaseLetter),
+    ('\u{1E12}', '\u{1E12}', UppercaseLetter),
+    ('\u{1E13}', '\u{1E13}', LowercaseLetter),
+    ('\u{1E14}', '\u{1E14}', UppercaseLetter),
+    ('\u{1E15}', '\u{1E15}', LowercaseLetter),
+    ('\u{1E16}', '\u{1E16}', UppercaseLetter),
+    ('\u{1E17}', '\u{1E17}', LowercaseLetter),
+    ('\u{1E18}', '\u{1E18}', UppercaseLetter),
+    ('\u{1E19}', '\u{1E19}', LowercaseLetter),
+    ('\u{1E1A}', '\u{1E1A}', UppercaseLetter),
+    ('\u{1E1B}', '\u{1E1B}', LowercaseLetter),
+    ('\u{1E1C}', '\u{1E1C}', UppercaseLetter),
+    ('\u{1E1D}', '\u{1E1D}', LowercaseLetter),
+    ('\u{1E1E}', '\u{1E1E}', UppercaseLetter),
+    ('\u{1E1F}', '\u{1E1F}', LowercaseLetter),
+    ('\u{1E20}', '\u{1E20}', UppercaseLetter),
+    ('\u{1E21}', '\u{1E21}', LowercaseLetter),
+    ('\u{1E22}', '\u{1E22}', UppercaseLetter),
+    ('\u{1E23}', '\u{1E23}', LowercaseLetter),
+    ('\u{1E24}', '\u{1E24}', UppercaseLetter),
+    ('\u{1E25}', '\u{1E25}', LowercaseLetter),
+    ('\u{1E26}', '\u{1E26}', UppercaseLetter),
+    ('\u{1E27}', '\u{1E27}', LowercaseLetter),
+    ('\u{1E28}', '\u{1E28}', UppercaseLetter),
+    ('\u{1E29}', '\u{1E29}', LowercaseLetter),
+    ('\u{1E2A}', '\u{1E2A}', UppercaseLetter),
+    ('\u{1E2B}', '\u{1E2B}', LowercaseLetter),
+    ('\u{1E2C}', '\u{1E2C}', UppercaseLetter),
+    ('\u{1E2D}', '\u{1E2D}', LowercaseLetter),
+    ('\u{1E2E}', '\u{1E2E}', UppercaseLetter),
+    ('\u{1E2F}', '\u{1E2F}', LowercaseLetter),
+    ('\u{1E30}', '\u{1E30}', UppercaseLetter),
+    ('\u{1E31}', '\u{1E31}', LowercaseLetter),
+    ('\u{1E32}', '\u{1E32}', UppercaseLetter),
+    ('\u{1E33}', '\u{1E33}', LowercaseLetter),
+    ('\u{1E34}', '\u{1E34}', UppercaseLetter),
+    ('\u{1E35}', '\u{1E35}', LowercaseLetter),
+    ('\u{1E36}', '\u{1E36}', UppercaseLetter),
+    ('\u{1E37}', '\u{1E37}', LowercaseLetter),
+    ('\u{1E38}', '\u{1E38}', UppercaseLetter),
+    ('\u{1E39}', '\u{1E39}', LowercaseLetter),
+    ('\u{1E3A}', '\u{1E3A}', UppercaseLetter),
+    ('\u{1E3B}', '\u{1E3B}', LowercaseLetter),
+    ('\u{1E3C}', '\u{1E3C}', UppercaseLetter),
+    ('\u{1E3D}', '\u{1E3D}', LowercaseLetter),
+    ('\u{1E3E}', '\u{1E3E}', UppercaseLetter),
+    ('\u{1E3F}', '\u{1E3F}', LowercaseLetter),
+    ('\u{1E40}', '\u{1E40}', UppercaseLetter),
+    ('\u{1E41}', '\u{1E41}', LowercaseLetter),
+    ('\u{1E42}', '\u{1E42}', UppercaseLetter),
+    ('\u{1E43}', '\u{1E43}', LowercaseLetter),
+    ('\u{1E44}', '\u{1E44}', UppercaseLetter),
+    ('\u{1E45}', '\u{1E45}', LowercaseLetter),
+    ('\u{1E46}', '\u{1E46}', UppercaseLetter),
+    ('\u{1E47}', '\u{1E47}', LowercaseLetter),
+    ('\u{1E48}', '\u{1E48}', UppercaseLetter),
+    ('\u{1E49}', '\u{1E49}', LowercaseLetter),
+    ('\u{1E4A}', '\u{1E4A}', UppercaseLetter),
+    ('\u{1E4B}', '\u{1E4B}', LowercaseLetter),
+    ('\u{1E4C}', '\u{1E4C}', UppercaseLetter),
+    ('\u{1E4D}', '\u{1E4D}', LowercaseLetter),
+    ('\u{1E4E}', '\u{1E4E}', UppercaseLetter),
+    ('\u{1E4F}', '\u{1E4F}', LowercaseLetter),
+    ('\u{1E50}', '\u{1E50}', UppercaseLetter),
+    ('\u{1E51}', '\u{1E51}', LowercaseLetter),
+    ('\u{1E52}', '\u{1E52}', UppercaseLetter),
+    ('\u{1E53}', '\u{1E53}', LowercaseLetter),
+    ('\u{1E54}', '\u{1E54}', UppercaseLetter),
+    ('\u{1E55}', '\u{1E55}', LowercaseLetter),
+    ('\u{1E56}', '\u{1E56}', UppercaseLetter),
+    ('\u{1E57}', '\u{1E57}', LowercaseLetter),
+    ('\u{1E58}', '\u{1E58}', UppercaseLetter),
+    ('\u{1E59}', '\u{1E59}', LowercaseLetter),
+    ('\u{1E5A}', '\u{1E5A}', UppercaseLetter),
+    ('\u{1E5B}', '\u{1E5B}', LowercaseLetter),
+    ('\u{1E5C}', '\u{1E5C}', UppercaseLetter),
+    ('\u{1E5D}', '\u{1E5D}', LowercaseLetter),
+    ('\u{1E5E}', '\u{1E5E}', UppercaseLetter),
+    ('\u{1E5F}', '\u{1E5F}', LowercaseLetter),
+    ('\u{1E60}', '\u{1E60}', UppercaseLetter),
+    ('\u{1E61}', '\u{1E61}', LowercaseLetter),
+    ('\u{1E62}', '\u{1E62}', UppercaseLetter),
+    ('\u{1E63}', '\u{1E63}', LowercaseLetter),
+    ('\u{1E64}', '\u{1E64}', UppercaseLetter),
+    ('\u{1E65}', '\u{1E65}', LowercaseLetter),
+    ('\u{1E66}', '\u{1E66}', UppercaseLetter),
+    ('\u{1E67}', '\u{1E67}', LowercaseLetter),
+    ('\u{1E68}', '\u{1E68}', UppercaseLetter),
+    ('\u{1E69}', '\u{1E69}', LowercaseLetter),
+    ('\u{1E6A}', '\u{1E6A}', UppercaseLetter),
+    ('\u{1E6B}', '\u{1E6B}', LowercaseLetter),
+    ('\u{1E6C}', '\u{1E6C}', UppercaseLetter),
+    ('\u{1E6D}', '\u{1E6D}', LowercaseLetter),
+    ('\u{1E6E}', '\u{1E6E}', UppercaseLetter),
+    ('\u{1E6F}', '\u{1E6F}', LowercaseLetter),
+    ('\u{1E70}', '\u{1E70}', UppercaseLetter),
+    ('\u{1E71}', '\u{1E71}', LowercaseLetter),
+    ('\u{1E72}', '\u{1E72}', UppercaseLetter),
+    ('\u{1E73}', '\u{1E73}', LowercaseLetter),
+    ('\u{1E74}', '\u{1E74}', UppercaseLetter),
+    ('\u{1E75}', '\u{1E75}', LowercaseLetter),
+    ('\u{1E76}', '\u{1E76}', UppercaseLetter),
+    ('\u{1E77}', '\u{1E77}', LowercaseLetter),
+    ('\u{1E78}', '\u{1E78}', UppercaseLetter),
+    ('\u{1E79}', '\u{1E79}', LowercaseLetter),
+    ('\u{1E7A}', '\u{1E7A}', UppercaseLetter),
+    ('\u{1E7B}', '\u{1E7B}', LowercaseLetter),
+    ('\u{1E7C}', '\u{1E7C}', UppercaseLetter),
+    ('\u{1E7D}', '\u{1E7D}', LowercaseLetter),
+    ('\u{1E7E}', '\u{1E7E}', UppercaseLetter),
+    ('\u{1E7F}', '\u{1E7F}', LowercaseLetter),
+    ('\u{1E80}', '\u{1E80}', UppercaseLetter),
+    ('\u{1E81}', '\u{1E81}', LowercaseLetter),
+    ('\u{1E82}', '\u{1E82}', UppercaseLetter),
+    ('\u{1E83}', '\u{1E83}', LowercaseLetter),
+    ('\u{1E84}', '\u{1E84}', UppercaseLetter),
+    ('\u{1E85}', '\u{1E85}', LowercaseLetter),
+    ('\u{1E86}', '\u{1E86}', UppercaseLetter),
+    ('\u{1E87}', '\u{1E87}', LowercaseLetter),
+    ('\u{1E88}', '\u{1E88}', UppercaseLetter),
+    ('\u{1E89}', '\u{1E89}', LowercaseLetter),
+    ('\u{1E8A}', '\u{1E8A}', UppercaseLetter),
+    ('\u{1E8B}', '\u{1E8B}', LowercaseLetter),
+    ('\u{1E8C}', '\u{1E8C}', UppercaseLetter),
+    ('\u{1E8D}', '\u{1E8D}', LowercaseLetter),
+    ('\u{1E8E}', '\u{1E8E}', UppercaseLetter),
+    ('\u{1E8F}', '\u{1E8F}', LowercaseLetter),
+    ('\u{1E90}', '\u{1E90}', UppercaseLetter),
+    ('\u{1E91}', '\u{1E91}', LowercaseLetter),
+    ('\u{1E92}', '\u{1E92}', UppercaseLetter),
+    ('\u{1E93}', '\u{1E93}', LowercaseLetter),
+    ('\u{1E94}', '\u{1E94}', UppercaseLetter),
+    ('\u{1E95}', '\u{1E9D}', LowercaseLetter),
+    ('\u{1E9E}', '\u{1E9E}', UppercaseLetter),
+    ('\u{1E9F}', '\u{1E9F}', LowercaseLetter),
+    ('\u{1EA0}', '\u{1EA0}', UppercaseLetter),
+    ('\u{1EA1}', '\u{1EA1}', LowercaseLetter),
+    ('\u{1EA2}', '\u{1EA2}', UppercaseLetter),
+    ('\u{1EA3}', '\u{1EA3}', LowercaseLetter),
+    ('\u{1EA4}', '\u{1EA4}', UppercaseLetter),
+    ('\u{1EA5}', '\u{1EA5}', LowercaseLetter),
+    ('\u{1EA6}', '\u{1EA6}', UppercaseLetter),
+    ('\u{1EA7}', '\u{1EA7}', LowercaseLetter),
+    ('\u{1EA8}', '\u{1EA8}', UppercaseLetter),
+    ('\u{1EA9}', '\u{1EA9}', LowercaseLetter),
+    ('\u{1EAA}', '\u{1EAA}', UppercaseLetter),
+    ('\u{1EAB}', '\u{1EAB}', LowercaseLetter),
+    ('\u{1EAC}', '\u{1EAC}', UppercaseLetter),
+    ('\u{1EAD}', '\u{1EAD}', LowercaseLetter),
+    ('\u{1EAE}', '\u{1EAE}', UppercaseLetter),
+    ('\u{1EAF}', '\u{1EAF}', LowercaseLetter),
+    ('\u{1EB0}', '\u{1EB0}', UppercaseLetter),
+    ('\u{1EB1}', '\u{1EB1}', LowercaseLetter),
+    ('\u{1EB2}', '\u{1EB2}', UppercaseLetter),
+    ('\u{1EB3}', '\u{1EB3}', LowercaseLetter),
+    ('\u{1EB4}', '\u{1EB4}', UppercaseLetter),
+    ('\u{1EB5}', '\u{1EB5}', LowercaseLetter),
+    ('\u{1EB6}', '\u{1EB6}', UppercaseLetter),
+    ('\u{1EB7}', '\u{1EB7}', LowercaseLetter),
+    ('\u{1EB8}', '\u{1EB8}', UppercaseLetter),
+    ('\u{1EB9}', '\u{1EB9}', LowercaseLetter),
+    ('\u{1EBA}', '\u{1EBA}', UppercaseLetter),
+    ('\u{1EBB}', '\u{1EBB}', LowercaseLetter),
+    ('\u{1EBC}', '\u{1EBC}', UppercaseLetter),
+    ('\u{1EBD}', '\u{1EBD}', LowercaseLetter),
+    ('\u{1EBE}', '\u{1EBE}', UppercaseLetter),
+    ('\u{1EBF}', '\u{1EBF}', LowercaseLetter),
+    ('\u{1EC0}', '\u{1EC0}', UppercaseLetter),
+    ('\u{1EC1}', '\u{1EC1}', LowercaseLetter),
+    ('\u{1EC2}', '\u{1EC2}', UppercaseLetter),
+    ('\u{1EC3}', '\u{1EC3}', LowercaseLetter),
+    ('\u{1EC4}', '\u{1EC4}', UppercaseLetter),
+    ('\u{1EC5}', '\u{1EC5}', LowercaseLetter),
+    ('\u{1EC6}', '\u{1EC6}', UppercaseLetter),
+    ('\u{1EC7}', '\u{1EC7}', LowercaseLetter),
+    ('\u{1EC8}', '\u{1EC8}', UppercaseLetter),
+    ('\u{1EC9}', '\u{1EC9}', LowercaseLetter),
+    ('\u{1ECA}', '\u{1ECA}', UppercaseLetter),
+    ('\u{1ECB}', '\u{1ECB}', LowercaseLetter),
+    ('\u{1ECC}', '\u{1ECC}', UppercaseLetter),
+    ('\u{1ECD}', '\u{1ECD}', LowercaseLetter),
+    ('\u{1ECE}', '\u{1ECE}', UppercaseLetter),
+    ('\u{1ECF}', '\u{1ECF}', LowercaseLetter),
+    ('\u{1ED0}', '\u{1ED0}', UppercaseLetter),
+    ('\u{1ED1}', '\u{1ED1}', LowercaseLetter),
+    ('\u{1ED2}', '\u{1ED2}', UppercaseLetter),
+    ('\u{1ED3}', '\u{1ED3}', LowercaseLetter),
+    ('\u{1ED4}', '\u{1ED4}', UppercaseLetter),
+    ('\u{1ED5}', '\u{1ED5}', LowercaseLetter),
+    ('\u{1ED6}', '\u{1ED6}', UppercaseLetter),
+    ('\u{1ED7}', '\u{1ED7}', LowercaseLetter),
+    ('\u{1ED8}', '\u{1ED8}', UppercaseLetter),
+    ('\u{1ED9}', '\u{1ED9}', LowercaseLetter),
+    ('\u{1EDA}', '\u{1EDA}', UppercaseLetter),
+    ('\u{1EDB}', '\u{1EDB}', LowercaseLetter),
+    ('\u{1EDC}', '\u{1EDC}', UppercaseLetter),
+    ('\u{1EDD}', '\u{1EDD}', LowercaseLetter),
+    ('\u{1EDE}', '\u{1EDE}', UppercaseLetter),
+    ('\u{1EDF}', '\u{1EDF}', LowercaseLetter),
+    ('\u{1EE0}', '\u{1EE0}', UppercaseLetter),
+    ('\u{1EE1}', '\u{1EE1}', LowercaseLetter),
+    ('\u{1EE2}', '\u{1EE2}', UppercaseLetter),
+    ('\u{1EE3}', '\u{1EE3}', LowercaseLetter),
+    ('\u{1EE4}', '\u{1EE4}', UppercaseLetter),
+    ('\u{1EE5}', '\u{1EE5}', LowercaseLetter),
+    ('\u{1EE6}', '\u{1EE6}', UppercaseLetter),
+    ('\u{1EE7}', '\u{1EE7}', LowercaseLetter),
+    ('\u{1EE8}', '\u{1EE8}', UppercaseLetter),
+    ('\u{1EE9}', '\u{1EE9}', LowercaseLetter),
+    ('\u{1EEA}', '\u{1EEA}', UppercaseLetter),
+    ('\u{1EEB}', '\u{1EEB}', LowercaseLetter),
+    ('\u{1EEC}', '\u{1EEC}', UppercaseLetter),
+    ('\u{1EED}', '\u{1EED}', LowercaseLetter),
+    ('\u{1EEE}', '\u{1EEE}', UppercaseLetter),
+    ('\u{1EEF}', '\u{1EEF}', LowercaseLetter),
+    ('\u{1EF0}', '\u{1EF0}', UppercaseLetter),
+    ('\u{1EF1}', '\u{1EF1}', LowercaseLetter),
+    ('\u{1EF2}', '\u{1EF2}', UppercaseLetter),
+    ('\u{1EF3}', '\u{1EF3}', LowercaseLetter),
+    ('\u{1EF4}', '\u{1EF4}', UppercaseLetter),
+    ('\u{1EF5}', '\u{1EF5}', LowercaseLetter),
+    ('\u{1EF6}', '\u{1EF6}', UppercaseLetter),
+    ('\u{1EF7}', '\u{1EF7}', LowercaseLetter),
+    ('\u{1EF8}', '\u{1EF8}', UppercaseLetter),
+    ('\u{1EF9}', '\u{1EF9}', LowercaseLetter),
+    ('\u{1EFA}', '\u{1EFA}', UppercaseLetter),
+    ('\u{1EFB}', '\u{1EFB}', LowercaseLetter),
+    ('\u{1EFC}', '\u{1EFC}', UppercaseLetter),
+    ('\u{1EFD}', '\u{1EFD}', LowercaseLetter),
+    ('\u{1EFE}', '\u{1EFE}', UppercaseLetter),
+    ('\u{1EFF}', '\u{1F07}', LowercaseLetter),
+    ('\u{1F08}', '\u{1F0F}', UppercaseLetter),
+    ('\u{1F10}', '\u{1F15}', LowercaseLetter),
+    ('\u{1F18}', '\u{1F1D}', UppercaseLetter),
+    ('\u{1F20}', '\u{1F27}', LowercaseLetter),
+    ('\u{1F28}', '\u{1F2F}', UppercaseLetter),
+    ('\u{1F30}', '\u{1F37}', LowercaseLetter),
+    ('\u{1F38}', '\u{1F3F}', UppercaseLetter),
+    ('\u{1F40}', '\u{1F45}', LowercaseLetter),
+    ('\u{1F48}', '\u{1F4D}', UppercaseLetter),
+    ('\u{1F50}', '\u{1F57}', LowercaseLetter),
+    ('\u{1F59}', '\u{1F59}', UppercaseLetter),
+    ('\u{1F5B}', '\u{1F5B}', UppercaseLetter),
+    ('\u{1F5D}', '\u{1F5D}', UppercaseLetter),
+    ('\u{1F5F}', '\u{1F5F}', UppercaseLetter),
+    ('\u{1F60}', '\u{1F67}', LowercaseLetter),
+    ('\u{1F68}', '\u{1F6F}', UppercaseLetter),
+    ('\u{1F70}', '\u{1F7D}', LowercaseLetter),
+    ('\u{1F80}', '\u{1F87}', LowercaseLetter),
+    ('\u{1F88}', '\u{1F8F}', TitlecaseLetter),
+    ('\u{1F90}', '\u{1F97}', LowercaseLetter),
+    ('\u{1F98}', '\u{1F9F}', TitlecaseLetter),
+    ('\u{1FA0}', '\u{1FA7}', LowercaseLetter),
+    ('\u{1FA8}', '\u{1FAF}', TitlecaseLetter),
+    ('\u{1FB0}', '\u{1FB4}', LowercaseLetter),
+    ('\u{1FB6}', '\u{1FB7}', LowercaseLetter),
+    ('\u{1FB8}', '\u{1FBB}', UppercaseLetter),
+    ('\u{1FBC}', '\u{1FBC}', TitlecaseLetter),
+    ('\u{1FBD}', '\u{1FBD}', ModifierSymbol),
+    ('\u{1FBE}', '\u{1FBE}', LowercaseLetter),
+    ('\u{1FBF}', '\u{1FC1}', ModifierSymbol),
+    ('\u{1FC2}', '\u{1FC4}', LowercaseLetter),
+    ('\u{1FC6}', '\u{1FC7}', LowercaseLetter),
+    ('\u{1FC8}', '\u{1FCB}', UppercaseLetter),
+    ('\u{1FCC}', '\u{1FCC}', TitlecaseLetter),
+    ('\u{1FCD}', '\u{1FCF}', ModifierSymbol),
+    ('\u{1FD0}', '\u{1FD3}', LowercaseLetter),
+    ('\u{1FD6}', '\u{1FD7}', LowercaseLetter),
+    ('\u{1FD8}', '\u{1FDB}', UppercaseLetter),
+    ('\u{1FDD}', '\u{1FDF}', ModifierSymbol),
+    ('\u{1FE0}', '\u{1FE7}', LowercaseLetter),
+    ('\u{1FE8}', '\u{1FEC}', UppercaseLetter),
+    ('\u{1FED}', '\u{1FEF}', ModifierSymbol),
+    ('\u{1FF2}', '\u{1FF4}', LowercaseLetter),
+    ('\u{1FF6}', '\u{1FF7}', LowercaseLetter),
+    ('\u{1FF8}', '\u{1FFB}', UppercaseLetter),
+    ('\u{1FFC}', '\u{1FFC}', TitlecaseLetter),
+    ('\u{1FFD}', '\u{1FFE}', ModifierSymbol),
+    ('\u{2000}', '\u{200A}', SpaceSeparator),
+    ('\u{200B}', '\u{200F}', Format),
+    ('\u{2010}', '\u{2015}', DashPunctuation),
+    ('\u{2016}', '\u{2017}', OtherPunctuation),
+    ('\u{2018}', '\u{2018}', InitialPunctuation),
+    ('\u{2019}', '\u{2019}', FinalPunctuation),
+    ('\u{201A}', '\u{201A}', OpenPunctuation),
+    ('\u{201B}', '\u{201C}', InitialPunctuation),
+    ('\u{201D}', '\u{201D}', FinalPunctuation),
+    ('\u{201E}', '\u{201E}', OpenPunctuation),
+    ('\u{201F}', '\u{201F}', InitialPunctuation),
+    ('\u{2020}', '\u{2027}', OtherPunctuation),
+    ('\u{2028}', '\u{2028}', LineSeparator),
+    ('\u{2029}', '\u{2029}', ParagraphSeparator),
+    ('\u{202A}', '\u{202E}', Format),
+    ('\u{202F}', '\u{202F}', SpaceSeparator),
+    ('\u{2030}', '\u{2038}', OtherPunctuation),
+    ('\u{2039}', '\u{2039}', InitialPunctuation),
+    ('\u{203A}', '\u{203A}', FinalPunctuation),
+    ('\u{203B}', '\u{203E}', OtherPunctuation),
+    ('\u{203F}', '\u{2040}', ConnectorPunctuation),
+    ('\u{2041}', '\u{2043}', OtherPunctuation),
+    ('\u{2044}', '\u{2044}', MathSymbol),
+    ('\u{2045}', '\u{2045}', OpenPunctuation),
+    ('\u{2046}', '\u{2046}', ClosePunctuation),
+    ('\u{2047}', '\u{2051}', OtherPunctuation),
+    ('\u{2052}', '\u{2052}', MathSymbol),
+    ('\u{2053}', '\u{2053}', OtherPunctuation),
+    ('\u{2054}', '\u{2054}', ConnectorPunctuation),
+    ('\u{2055}', '\u{205E}', OtherPunctuation),
+    ('\u{205F}', '\u{205F}', SpaceSeparator),
+    ('\u{2060}', '\u{2064}', Format),
+    ('\u{2066}', '\u{206F}', Format),
+    ('\u{2070}', '\u{2070}', OtherNumber),
+    ('\u{2071}', '\u{2071}', ModifierLetter),
+    ('\u{2074}', '\u{2079}', OtherNumber),
+    ('\u{207A}', '\u{207C}', MathSymbol),
+    ('\u{207D}', '\u{207D}', OpenPunctuation),
+    ('\u{207E}', '\u{207E}', ClosePunctuation),
+    ('\u{207F}', '\u{207F}', ModifierLetter),
+    ('\u{2080}', '\u{2089}', OtherNumber),
+    ('\u{208A}', '\u{208C}', MathSymbol),
+    ('\u{208D}', '\u{208D}', OpenPunctuation),
+    ('\u{208E}', '\u{208E}', ClosePunctuation),
+    ('\u{2090}', '\u{209C}', ModifierLetter),
+    ('\u{20A0}', '\u{20C0}', CurrencySymbol),
+    ('\u{20D0}', '\u{20DC}', NonspacingMark),
+    ('\u{20DD}', '\u{20E0}', EnclosingMark),
+    ('\u{20E1}', '\u{20E1}', NonspacingMark),
+    ('\u{20E2}', '\u{20E4}', EnclosingMark),
+    ('\u{20E5}', '\u{20F0}', NonspacingMark),
+    ('\u{2100}', '\u{2101}', OtherSymbol),
+    ('\u{2102}', '\u{2102}', UppercaseLetter),
+    ('\u{2103}', '\u{2106}', OtherSymbol),
+    ('\u{2107}', '\u{2107}', UppercaseLetter),
+    ('\u{2108}', '\u{2109}', OtherSymbol),
+    ('\u{210A}', '\u{210A}', LowercaseLetter),
+    ('\u{210B}', '\u{210D}', UppercaseLetter),
+    ('\u{210E}', '\u{210F}', LowercaseLetter),
+    ('\u{2110}', '\u{2112}', UppercaseLetter),
+    ('\u{2113}', '\u{2113}', LowercaseLetter),
+    ('\u{2114}', '\u{2114}', OtherSymbol),
+    ('\u{2115}', '\u{2115}', UppercaseLetter),
+    ('\u{2116}', '\u{2117}', OtherSymbol),
+    ('\u{2118}', '\u{2118}', MathSymbol),
+    ('\u{2119}', '\u{211D}', UppercaseLetter),
+    ('\u{211E}', '\u{2123}', OtherSymbol),
+    ('\u{2124}', '\u{2124}', UppercaseLetter),
+    ('\u{2125}', '\u{2125}', OtherSymbol),
+    ('\u{2126}', '\u{2126}', UppercaseLetter),
+    ('\u{2127}', '\u{2127}', OtherSymbol),
+    ('\u{2128}', '\u{2128}', UppercaseLetter),
+    ('\u{2129}', '\u{2129}', OtherSymbol),
+    ('\u{212A}', '\u{212D}', UppercaseLetter),
+    ('\u{212E}', '\u{212E}', OtherSymbol),
+    ('\u{212F}', '\u{212F}', LowercaseLetter),
+    ('\u{2130}', '\u{2133}', UppercaseLetter),
+    ('\u{2134}', '\u{2134}', LowercaseLetter),
+    ('\u{2135}', '\u{2138}', OtherLetter),
+    ('\u{2139}', '\u{2139}', LowercaseLetter),
+    ('\u{213A}', '\u{213B}', OtherSymbol),
+    ('\u{213C}', '\u{213D}', LowercaseLetter),
+    ('\u{213E}', '\u{213F}', UppercaseLetter),
+    ('\u{2140}', '\u{2144}', MathSymbol),
+    ('\u{2145}', '\u{2145}', UppercaseLetter),
+    ('\u{2146}', '\u{2149}', LowercaseLetter),
+    ('\u{214A}', '\u{214A}', OtherSymbol),
+    ('\u{214B}', '\u{214B}', MathSymbol),
+    ('\u{214C}', '\u{214D}', OtherSymbol),
+    ('\u{214E}', '\u{214E}', LowercaseLetter),
+    ('\u{214F}', '\u{214F}', OtherSymbol),
+    ('\u{2150}', '\u{215F}', OtherNumber),
+    ('\u{2160}', '\u{2182}', LetterNumber),
+    ('\u{2183}', '\u{2183}', UppercaseLetter),
+    ('\u{2184}', '\u{2184}', LowercaseLetter),
+    ('\u{2185}', '\u{2188}', LetterNumber),
+    ('\u{2189}', '\u{2189}', OtherNumber),
+    ('\u{218A}', '\u{218B}', OtherSymbol),
+    ('\u{2190}', '\u{2194}', MathSymbol),
+    ('\u{2195}', '\u{2199}', OtherSymbol),
+    ('\u{219A}', '\u{219B}', MathSymbol),
+    ('\u{219C}', '\u{219F}', OtherSymbol),
+    ('\u{21A0}', '\u{21A0}', MathSymbol),
+    ('\u{21A1}', '\u{21A2}', OtherSymbol),
+    ('\u{21A3}', '\u{21A3}', MathSymbol),
+    ('\u{21A4}', '\u{21A5}', OtherSymbol),
+    ('\u{21A6}', '\u{21A6}', MathSymbol),
+    ('\u{21A7}', '\u{21AD}', OtherSymbol),
+    ('\u{21AE}', '\u{21AE}', MathSymbol),
+    ('\u{21AF}', '\u{21CD}', OtherSymbol),
+    ('\u{21CE}', '\u{21CF}', MathSymbol),
+    ('\u{21D0}', '\u{21D1}', OtherSymbol),
+    ('\u{21D2}', '\u{21D2}', MathSymbol),
+    ('\u{21D3}', '\u{21D3}', OtherSymbol),
+    ('\u{21D4}', '\u{21D4}', MathSymbol),
+    ('\u{21D5}', '\u{21F3}', OtherSymbol),
+    ('\u{21F4}', '\u{22FF}', MathSymbol),
+    ('\u{2300}', '\u{2307}', OtherSymbol),
+    ('\u{2308}', '\u{2308}', OpenPunctuation),
+    ('\u{2309}', '\u{2309}', ClosePunctuation),
+    ('\u{230A}', '\u{230A}', OpenPunctuation),
+    ('\u{230B}', '\u{230B}', ClosePunctuation),
+    ('\u{230C}', '\u{231F}', OtherSymbol),
+    ('\u{2320}', '\u{2321}', MathSymbol),
+    ('\u{2322}', '\u{2328}', OtherSymbol),
+    ('\u{2329}', '\u{2329}', OpenPunctuation),
+    ('\u{232A}', '\u{232A}', ClosePunctuation),
+    ('\u{232B}', '\u{237B}', OtherSymbol),
+    ('\u{237C}', '\u{237C}', MathSymbol),
+    ('\u{237D}', '\u{239A}', OtherSymbol),
+    ('\u{239B}', '\u{23B3}', MathSymbol),
+    ('\u{23B4}', '\u{23DB}', OtherSymbol),
+    ('\u{23DC}', '\u{23E1}', MathSymbol),
+    ('\u{23E2}', '\u{2426}', OtherSymbol),
+    ('\u{2440}', '\u{244A}', OtherSymbol),
+    ('\u{2460}', '\u{249B}', OtherNumber),
+    ('\u{249C}', '\u{24E9}', OtherSymbol),
+    ('\u{24EA}', '\u{24FF}', OtherNumber),
+    ('\u{2500}', '\u{25B6}', OtherSymbol),
+    ('\u{25B7}', '\u{25B7}', MathSymbol),
+    ('\u{25B8}', '\u{25C0}', OtherSymbol),
+    ('\u{25C1}', '\u{25C1}', MathSymbol),
+    ('\u{25C2}', '\u{25F7}', OtherSymbol),
+    ('\u{25F8}', '\u{25FF}', MathSymbol),
+    ('\u{2600}', '\u{266E}', OtherSymbol),
+    ('\u{266F}', '\u{266F}', MathSymbol),
+    ('\u{2670}', '\u{2767}', OtherSymbol),
+    ('\u{2768}', '\u{2768}', OpenPunctuation),
+    ('\u{2769}', '\u{2769}', ClosePunctuation),
+    ('\u{276A}', '\u{276A}', OpenPunctuation),
+    ('\u{276B}', '\u{276B}', ClosePunctuation),
+    ('\u{276C}', '\u{276C}', OpenPunctuation),
+    ('\u{276D}', '\u{276D}', ClosePunctuation),
+    ('\u{276E}', '\u{276E}', OpenPunctuation),
+    ('\u{276F}', '\u{276F}', ClosePunctuation),
+    ('\u{2770}', '\u{2770}', OpenPunctuation),
+    ('\u{2771}', '\u{2771}', ClosePunctuation),
+    ('\u{2772}', '\u{2772}', OpenPunctuation),
+    ('\u{2773}', '\u{2773}', ClosePunctuation),
+    ('\u{2774}', '\u{2774}', OpenPunctuation),
+    ('\u{2775}', '\u{2775}', ClosePunctuation),
+    ('\u{2776}', '\u{2793}', OtherNumber),
+    ('\u{2794}', '\u{27BF}', OtherSymbol),
+    ('\u{27C0}', '\u{27C4}', MathSymbol),
+    ('\u{27C5}', '\u{27C5}', OpenPunctuation),
+    ('\u{27C6}', '\u{27C6}', ClosePunctuation),
+    ('\u{27C7}', '\u{27E5}', MathSymbol),
+    ('\u{27E6}', '\u{27E6}', OpenPunctuation),
+    ('\u{27E7}', '\u{27E7}', ClosePunctuation),
+    ('\u{27E8}', '\u{27E8}', OpenPunctuation),
+    ('\u{27E9}', '\u{27E9}', ClosePunctuation),
+    ('\u{27EA}', '\u{27EA}', OpenPunctuation),
+    ('\u{27EB}', '\u{27EB}', ClosePunctuation),
+    ('\u{27EC}', '\u{27EC}', OpenPunctuation),
+    ('\u{27ED}', '\u{27ED}', ClosePunctuation),
+    ('\u{27EE}', '\u{27EE}', OpenPunctuation),
+    ('\u{27EF}', '\u{27EF}', ClosePunctuation),
+    ('\u{27F0}', '\u{27FF}', MathSymbol),
+    ('\u{2800}', '\u{28FF}', OtherSymbol),
+    ('\u{2900}', '\u{2982}', MathSymbol),
+    ('\u{2983}', '\u{2983}', OpenPunctuation),
+    ('\u{2984}', '\u{2984}', ClosePunctuation),
+    ('\u{2985}', '\u{2985}', OpenPunctuation),
+    ('\u{2986}', '\u{2986}', ClosePunctuation),
+    ('\u{2987}', '\u{2987}', OpenPunctuation),
+    ('\u{2988}', '\u{2988}', ClosePunctuation),
+    ('\u{2989}', '\u{2989}', OpenPunctuation),
+    ('\u{298A}', '\u{298A}', ClosePunctuation),
+    ('\u{298B}', '\u{298B}', OpenPunctuation),
+    ('\u{298C}', '\u{298C}', ClosePunctuation),
+    ('\u{298D}', '\u{298D}', OpenPunctuation),
+    ('\u{298E}', '\u{298E}', ClosePunctuation),
+    ('\u{298F}', '\u{298F}', OpenPunctuation),
+    ('\u{2990}', '\u{2990}', ClosePunctuation),
+    ('\u{2991}', '\u{2991}', OpenPunctuation),
+    ('\u{2992}', '\u{2992}', ClosePunctuation),
+    ('\u{2993}', '\u{2993}', OpenPunctuation),
+    ('\u{2994}', '\u{2994}', ClosePunctuation),
+    ('\u{2995}', '\u{2995}', OpenPunctuation),
+    ('\u{2996}', '\u{2996}', ClosePunctuation),
+    ('\u{2997}', '\u{2997}', OpenPunctuation),
+    ('\u{2998}', '\u{2998}', ClosePunctuation),
+    ('\u{2999}', '\u{29D7}', MathSymbol),
+    ('\u{29D8}', '\u{29D8}', OpenPunctuation),
+    ('\u{29D9}', '\u{29D9}', ClosePunctuation),
+    ('\u{29DA}', '\u{29DA}', OpenPunctuation),
+    ('\u{29DB}', '\u{29DB}', ClosePunctuation),
+    ('\u{29DC}', '\u{29FB}', MathSymbol),
+    ('\u{29FC}', '\u{29FC}', OpenPunctuation),
+    ('\u{29FD}', '\u{29FD}', ClosePunctuation),
+    ('\u{29FE}', '\u{2AFF}', MathSymbol),
+    ('\u{2B00}', '\u{2B2F}', OtherSymbol),
+    ('\u{2B30}', '\u{2B44}', MathSymbol),
+    ('\u{2B45}', '\u{2B46}', OtherSymbol),
+    ('\u{2B47}', '\u{2B4C}', MathSymbol),
+    ('\u{2B4D}', '\u{2B73}', OtherSymbol),
+    ('\u{2B76}', '\u{2B95}', OtherSymbol),
+    ('\u{2B97}', '\u{2BFF}', OtherSymbol),
+    ('\u{2C00}', '\u{2C2F}', UppercaseLetter),
+    ('\u{2C30}', '\u{2C5F}', LowercaseLetter),
+    ('\u{2C60}', '\u{2C60}', UppercaseLetter),
+    ('\u{2C61}', '\u{2C61}', LowercaseLetter),
+    ('\u{2C62}', '\u{2C64}', UppercaseLetter),
+    ('\u{2C65}', '\u{2C66}', LowercaseLetter),
+    ('\u{2C67}', '\u{2C67}', UppercaseLetter),
+    ('\u{2C68}', '\u{2C68}', LowercaseLetter),
+    ('\u{2C69}', '\u{2C69}', UppercaseLetter),
+    ('\u{2C6A}', '\u{2C6A}', LowercaseLetter),
+    ('\u{2C6B}', '\u{2C6B}', UppercaseLetter),
+    ('\u{2C6C}', '\u{2C6C}', LowercaseLetter),
+    ('\u{2C6D}', '\u{2C70}', UppercaseLetter),
+    ('\u{2C71}', '\u{2C71}', LowercaseLetter),
+    ('\u{2C72}', '\u{2C72}', UppercaseLetter),
+    ('\u{2C73}', '\u{2C74}', LowercaseLetter),
+    ('\u{2C75}', '\u{2C75}', UppercaseLetter),
+    ('\u{2C76}', '\u{2C7B}', LowercaseLetter),
+    ('\u{2C7C}', '\u{2C7D}', ModifierLetter),
+    ('\u{2C7E}', '\u{2C80}', UppercaseLetter),
+    ('\u{2C81}', '\u{2C81}', LowercaseLetter),
+    ('\u{2C82}', '\u{2C82}', UppercaseLetter),
+    ('\u{2C83}', '\u{2C83}', LowercaseLetter),
+    ('\u{2C84}', '\u{2C84}', UppercaseLetter),
+    ('\u{2C85}', '\u{2C85}', LowercaseLetter),
+    ('\u{2C86}', '\u{2C86}', UppercaseLetter),
+    ('\u{2C87}', '\u{2C87}', LowercaseLetter),
+    ('\u{2C88}', '\u{2C88}', UppercaseLetter),
+    ('\u{2C89}', '\u{2C89}', LowercaseLetter),
+    ('\u{2C8A}', '\u{2C8A}', UppercaseLetter),
+    ('\u{2C8B}', '\u{2C8B}', LowercaseLetter),
+    ('\u{2C8C}', '\u{2C8C}', UppercaseLetter),
+    ('\u{2C8D}', '\u{2C8D}', LowercaseLetter),
+    ('\u{2C8E}', '\u{2C8E}', UppercaseLetter),
+    ('\u{2C8F}', '\u{2C8F}', LowercaseLetter),
+    ('\u{2C90}', '\u{2C90}', UppercaseLetter),
+    ('\u{2C91}', '\u{2C91}', LowercaseLetter),
+    ('\u{2C92}', '\u{2C92}', UppercaseLetter),
+    ('\u{2C93}', '\u{2C93}', LowercaseLetter),
+    ('\u{2C94}', '\u{2C94}', UppercaseLetter),
+    ('\u{2C95}', '\u{2C95}', LowercaseLetter),
+    ('\u{2C96}', '\u{2C96}', UppercaseLetter),
+    ('\u{2C97}', '\u{2C97}', LowercaseLetter),
+    ('\u{2C98}', '\u{2C98}', UppercaseLetter),
+    ('\u{2C99}', '\u{2C99}', LowercaseLetter),
+    ('\u{2C9A}', '\u{2C9A}', UppercaseLetter),
+    ('\u{2C9B}', '\u{2C9B}', LowercaseLetter),
+    ('\u{2C9C}', '\u{2C9C}', UppercaseLetter),
+    ('\u{2C9D}', '\u{2C9D}', LowercaseLetter),
+    ('\u{2C9E}', '\u{2C9E}', UppercaseLetter),
+    ('\u{2C9F}', '\u{2C9F}', LowercaseLetter),
+    ('\u{2CA0}', '\u{2CA0}', UppercaseLetter),
+    ('\u{2CA1}', '\u{2CA1}', LowercaseLetter),
+    ('\u{2CA2}', '\u{2CA2}', UppercaseLetter),
+    ('\u{2CA3}', '\u{2CA3}', LowercaseLetter),
+    ('\u{2CA4}', '\u{2CA4}', UppercaseLetter),
+    ('\u{2CA5}', '\u{2CA5}', LowercaseLetter),
+    ('\u{2CA6}', '\u{2CA6}', UppercaseLetter),
+    ('\u{2CA7}', '\u{2CA7}', LowercaseLetter),
+    ('\u{2CA8}', '\u{2CA8}', UppercaseLetter),
+    ('\u{2CA9}', '\u{2CA9}', LowercaseLetter),
+    ('\u{2CAA}', '\u{2CAA}', UppercaseLetter),
+    ('\u{2CAB}', '\u{2CAB}', LowercaseLetter),
+    ('\u{2CAC}', '\u{2CAC}', UppercaseLetter),
+    ('\u{2CAD}', '\u{2CAD}', LowercaseLetter),
+    ('\u{2CAE}', '\u{2CAE}', UppercaseLetter),
+    ('\u{2CAF}', '\u{2CAF}', LowercaseLetter),
+    ('\u{2CB0}', '\u{2CB0}', UppercaseLetter),
+    ('\u{2CB1}', '\u{2CB1}', LowercaseLetter),
+    ('\u{2CB2}', '\u{2CB2}', UppercaseLetter),
+    ('\u{2CB3}', '\u{2CB3}', LowercaseLetter),
+    ('\u{2CB4}', '\u{2CB4}', UppercaseLetter),
+    ('\u{2CB5}', '\u{2CB5}', LowercaseLetter),
+    ('\u{2CB6}', '\u{2CB6}', UppercaseLetter),
+    ('\u{2CB7}', '\u{2CB7}', LowercaseLetter),
+    ('\u{2CB8}', '\u{2CB8}', UppercaseLetter),
+    ('\u{2CB9}', '\u{2CB9}', LowercaseLetter),
+    ('\u{2CBA}', '\u{2CBA}', UppercaseLetter),
+    ('\u{2CBB}', '\u{2CBB}', LowercaseLetter),
+    ('\u{2CBC}', '\u{2CBC}', UppercaseLetter),
+    ('\u{2CBD}', '\u{2CBD}', LowercaseLetter),
+    ('\u{2CBE}', '\u{2CBE}', UppercaseLetter),
+    ('\u{2CBF}', '\u{2CBF}', LowercaseLetter),
+    ('\u{2CC0}', '\u{2CC0}', UppercaseLetter),
+    ('\u{2CC1}', '\u{2CC1}', LowercaseLetter),
+    ('\u{2CC2}', '\u{2CC2}', UppercaseLetter),
+    ('\u{2CC3}', '\u{2CC3}', LowercaseLetter),
+    ('\u{2CC4}', '\u{2CC4}', UppercaseLetter),
+    ('\u{2CC5}', '\u{2CC5}', LowercaseLetter),
+    ('\u{2CC6}', '\u{2CC6}', UppercaseLetter),
+    ('\u{2CC7}', '\u{2CC7}', LowercaseLetter),
+    ('\u{2CC8}', '\u{2CC8}', UppercaseLetter),
+    ('\u{2CC9}', '\u{2CC9}', LowercaseLetter),
+    ('\u{2CCA}', '\u{2CCA}', UppercaseLetter),
+    ('\u{2CCB}', '\u{2CCB}', LowercaseLetter),
+    ('\u{2CCC}', '\u{2CCC}', UppercaseLetter),
+    ('\u{2CCD}', '\u{2CCD}', LowercaseLetter),
+    ('\u{2CCE}', '\u{2CCE}', UppercaseLetter),
+    ('\u{2CCF}', '\u{2CCF}', LowercaseLetter),
+    ('\u{2CD0}', '\u{2CD0}', UppercaseLetter),
+    ('\u{2CD1}', '\u{2CD1}', LowercaseLetter),
+    ('\u{2CD2}', '\u{2CD2}', UppercaseLetter),
+    ('\u{2CD3}', '\u{2CD3}', LowercaseLetter),
+    ('\u{2CD4}', '\u{2CD4}', UppercaseLetter),
+    ('\u{2CD5}', '\u{2CD5}', LowercaseLetter),
+    ('\u{2CD6}', '\u{2CD6}', UppercaseLetter),
+    ('\u{2CD7}', '\u{2CD7}', LowercaseLetter),
+    ('\u{2CD8}', '\u{2CD8}', UppercaseLetter),
+    ('\u{2CD9}', '\u{2CD9}', LowercaseLetter),
+    ('\u{2CDA}', '\u{2CDA}', UppercaseLetter),
+    ('\u{2CDB}', '\u{2CDB}', LowercaseLetter),
+    ('\u{2CDC}', '\u{2CDC}', UppercaseLetter),
+    ('\u{2CDD}', '\u{2CDD}', LowercaseLetter),
+    ('\u{2CDE}', '\u{2CDE}', UppercaseLetter),
+    ('\u{2CDF}', '\u{2CDF}', LowercaseLetter),
+    ('\u{2CE0}', '\u{2CE0}', UppercaseLetter),
+    ('\u{2CE1}', '\u{2CE1}', LowercaseLetter),
+    ('\u{2CE2}', '\u{2CE2}', UppercaseLetter),
+    ('\u{2CE3}', '\u{2CE4}', LowercaseLetter),
+    ('\u{2CE5}', '\u{2CEA}', OtherSymbol),
+    ('\u{2CEB}', '\u{2CEB}', UppercaseLetter),
+    ('\u{2CEC}', '\u{2CEC}', LowercaseLetter),
+    ('\u{2CED}', '\u{2CED}', UppercaseLetter),
+    ('\u{2CEE}', '\u{2CEE}', LowercaseLetter),
+    ('\u{2CEF}', '\u{2CF1}', NonspacingMark),
+    ('\u{2CF2}', '\u{2CF2}', UppercaseLetter),
+    ('\u{2CF3}', '\u{2CF3}', LowercaseLetter),
+    ('\u{2CF9}', '\u{2CFC}', OtherPunctuation),
+    ('\u{2CFD}', '\u{2CFD}', OtherNumber),
+    ('\u{2CFE}', '\u{2CFF}', OtherPunctuation),
+    ('\u{2D00}', '\u{2D25}', LowercaseLetter),
+    ('\u{2D27}', '\u{2D27}', LowercaseLetter),
+    ('\u{2D2D}', '\u{2D2D}', LowercaseLetter),
+    ('\u{2D30}', '\u{2D67}', OtherLetter),
+    ('\u{2D6F}', '\u{2D6F}', ModifierLetter),
+    ('\u{2D70}', '\u{2D70}', OtherPunctuation),
+    ('\u{2D7F}', '\u{2D7F}', NonspacingMark),
+    ('\u{2D80}', '\u{2D96}', OtherLetter),
+    ('\u{2DA0}', '\u{2DA6}', OtherLetter),
+    ('\u{2DA8}', '\u{2DAE}', OtherLetter),
+    ('\u{2DB0}', '\u{2DB6}', OtherLetter),
+    ('\u{2DB8}', '\u{2DBE}', OtherLetter),
+    ('\u{2DC0}', '\u{2DC6}', OtherLetter),
+    ('\u{2DC8}', '\u{2DCE}', OtherLetter),
+    ('\u{2DD0}', '\u{2DD6}', OtherLetter),
+    ('\u{2DD8}', '\u{2DDE}', OtherLetter),
+    ('\u{2DE0}', '\u{2DFF}', NonspacingMark),
+    ('\u{2E00}', '\u{2E01}', OtherPunctuation),
+    ('\u{2E02}', '\u{2E02}', InitialPunctuation),
+    ('\u{2E03}', '\u{2E03}', FinalPunctuation),
+    ('\u{2E04}', '\u{2E04}', InitialPunctuation),
+    ('\u{2E05}', '\u{2E05}', FinalPunctuation),
+    ('\u{2E06}', '\u{2E08}', OtherPunctuation),
+    ('\u{2E09}', '\u{2E09}', InitialPunctuation),
+    ('\u{2E0A}', '\u{2E0A}', FinalPunctuation),
+    ('\u{2E0B}', '\u{2E0B}', OtherPunctuation),
+    ('\u{2E0C}', '\u{2E0C}', InitialPunctuation),
+    ('\u{2E0D}', '\u{2E0D}', FinalPunctuation),
+    ('\u{2E0E}', '\u{2E16}', OtherPunctuation),
+    ('\u{2E17}', '\u{2E17}', DashPunctuation),
+    ('\u{2E18}', '\u{2E19}', OtherPunctuation),
+    ('\u{2E1A}', '\u{2E1A}', DashPunctuation),
+    ('\u{2E1B}', '\u{2E1B}', OtherPunctuation),
+    ('\u{2E1C}', '\u{2E1C}', InitialPunctuation),
+    ('\u{2E1D}', '\u{2E1D}', FinalPunctuation),
+    ('\u{2E1E}', '\u{2E1F}', OtherPunctuation),
+    ('\u{2E20}', '\u{2E20}', InitialPunctuation),
+    ('\u{2E21}', '\u{2E21}', FinalPunctuation),
+    ('\u{2E22}', '\u{2E22}', OpenPunctuation),
+    ('\u{2E23}', '\u{2E23}', ClosePunctuation),
+    ('\u{2E24}', '\u{2E24}', OpenPunctuation),
+    ('\u{2E25}', '\u{2E25}', ClosePunctuation),
+    ('\u{2E26}', '\u{2E26}', OpenPunctuation),
+    ('\u{2E27}', '\u{2E27}', ClosePunctuation),
+    ('\u{2E28}', '\u{2E28}', OpenPunctuation),
+    ('\u{2E29}', '\u{2E29}', ClosePunctuation),
+    ('\u{2E2A}', '\u{2E2E}', OtherPunctuation),
+    ('\u{2E2F}', '\u{2E2F}', ModifierLetter),
+    ('\u{2E30}', '\u{2E39}', OtherPunctuation),
+    ('\u{2E3A}', '\u{2E3B}', DashPunctuation),
+    ('\u{2E3C}', '\u{2E3F}', OtherPunctuation),
+    ('\u{2E40}', '\u{2E40}', DashPunctuation),
+    ('\u{2E41}', '\u{2E41}', OtherPunctuation),
+    ('\u{2E42}', '\u{2E42}', OpenPunctuation),
+    ('\u{2E43}', '\u{2E4F}', OtherPunctuation),
+    ('\u{2E50}', '\u{2E51}', OtherSymbol),
+    ('\u{2E52}', '\u{2E54}', OtherPunctuation),
+    ('\u{2E55}', '\u{2E55}', OpenPunctuation),
+    ('\u{2E56}', '\u{2E56}', ClosePunctuation),
+    ('\u{2E57}', '\u{2E57}', OpenPunctuation),
+    ('\u{2E58}', '\u{2E58}', ClosePunctuation),
+    ('\u{2E59}', '\u{2E59}', OpenPunctuation),
+    ('\u{2E5A}', '\u{2E5A}', ClosePunctuation),
+    ('\u{2E5B}', '\u{2E5B}', OpenPunctuation),
+    ('\u{2E5C}', '\u{2E5C}', ClosePunctuation),
+    ('\u{2E5D}', '\u{2E5D}', DashPunctuation),
+    ('\u{2E80}', '\u{2E99}', OtherSymbol),
+    ('\u{2E9B}', '\u{2EF3}', OtherSymbol),
+    ('\u{2F00}', '\u{2FD5}', OtherSymbol),
+    ('\u{2FF0}', '\u{2FFB}', OtherSymbol),
+    ('\u{3000}', '\u{3000}', SpaceSeparator),
+    ('\u{3001}', '\u{3003}', OtherPunctuation),
+    ('\u{3004}', '\u{3004}', OtherSymbol),
+    ('\u{3005}', '\u{3005}', ModifierLetter),
+    ('\u{3006}', '\u{3006}', OtherLetter),
+    ('\u{3007}', '\u{3007}', LetterNumber),
+    ('\u{3008}', '\u{3008}', OpenPunctuation),
+    ('\u{3009}', '\u{3009}', ClosePunctuation),
+    ('\u{300A}', '\u{300A}', OpenPunctuation),
+    ('\u{300B}', '\u{300B}', ClosePunctuation),
+    ('\u{300C}', '\u{300C}', OpenPunctuation),
+    ('\u{300D}', '\u{300D}', ClosePunctuation),
+    ('\u{300E}', '\u{300E}', OpenPunctuation),
+    ('\u{300F}', '\u{300F}', ClosePunctuation),
+    ('\u{3010}', '\u{3010}', OpenPunctuation),
+    ('\u{3011}', '\u{3011}', ClosePunctuation),
+    ('\u{3012}', '\u{3013}', OtherSymbol),
+    ('\u{3014}', '\u{3014}', OpenPunctuation),
+    ('\u{3015}', '\u{3015}', ClosePunctuation),
+    ('\u{3016}', '\u{3016}', OpenPunctuation),
+    ('\u{3017}', '\u{3017}', ClosePunctuation),
+    ('\u{3018}', '\u{3018}', OpenPunctuation),
+    ('\u{3019}', '\u{3019}', ClosePunctuation),
+    ('\u{301A}', '\u{301A}', OpenPunctuation),
+    ('\u{301B}', '\u{301B}', ClosePunctuation),
+    ('\u{301C}', '\u{301C}', DashPunctuation),
+    ('\u{301D}', '\u{301D}', OpenPunctuation),
+    ('\u{301E}', '\u{301F}', ClosePunctuation),
+    ('\u{3020}', '\u{3020}', OtherSymbol),
+    ('\u{3021}', '\u{3029}', LetterNumber),
+    ('\u{302A}', '\u{302D}', NonspacingMark),
+    ('\u{302E}', '\u{302F}', SpacingMark),
+    ('\u{3030}', '\u{3030}', DashPunctuation),
+    ('\u{3031}', '\u{3035}', ModifierLetter),
+    ('\u{3036}', '\u{3037}', OtherSymbol),
+    ('\u{3038}', '\u{303A}', LetterNumber),
+    ('\u{303B}', '\u{303B}', ModifierLetter),
+    ('\u{303C}', '\u{303C}', OtherLetter),
+    ('\u{303D}', '\u{303D}', OtherPunctuation),
+    ('\u{303E}', '\u{303F}', OtherSymbol),
+    ('\u{3041}', '\u{3096}', OtherLetter),
+    ('\u{3099}', '\u{309A}', NonspacingMark),
+    ('\u{309B}', '\u{309C}', ModifierSymbol),
+    ('\u{309D}', '\u{309E}', ModifierLetter),
+    ('\u{309F}', '\u{309F}', OtherLetter),
+    ('\u{30A0}', '\u{30A0}', DashPunctuation),
+    ('\u{30A1}', '\u{30FA}', OtherLetter),
+    ('\u{30FB}', '\u{30FB}', OtherPunctuation),
+    ('\u{30FC}', '\u{30FE}', ModifierLetter),
+    ('\u{30FF}', '\u{30FF}', OtherLetter),
+    ('\u{3105}', '\u{312F}', OtherLetter),
+    ('\u{3131}', '\u{318E}', OtherLetter),
+    ('\u{3190}', '\u{3191}', OtherSymbol),
+    ('\u{3192}', '\u{3195}', OtherNumber),
+    ('\u{3196}', '\u{319F}', OtherSymbol),
+    ('\u{31A0}', '\u{31BF}', OtherLetter),
+    ('\u{31C0}', '\u{31E3}', OtherSymbol),
+    ('\u{31F0}', '\u{31FF}', OtherLetter),
+    ('\u{3200}', '\u{321E}', OtherSymbol),
+    ('\u{3220}', '\u{3229}', OtherNumber),
+    ('\u{322A}', '\u{3247}', OtherSymbol),
+    ('\u{3248}', '\u{324F}', OtherNumber),
+    ('\u{3250}', '\u{3250}', OtherSymbol),
+    ('\u{3251}', '\u{325F}', OtherNumber),
+    ('\u{3260}', '\u{327F}', OtherSymbol),
+    ('\u{3280}', '\u{3289}', OtherNumber),
+    ('\u{328A}', '\u{32B0}', OtherSymbol),
+    ('\u{32B1}', '\u{32BF}', OtherNumber),
+    ('\u{32C0}', '\u{33FF}', OtherSymbol),
+    ('\u{3400}', '\u{4DBF}', OtherLetter),
+    ('\u{4DC0}', '\u{4DFF}', OtherSymbol),
+    ('\u{4E00}', '\u{A014}', OtherLetter),
+    ('\u{A015}', '\u{A015}', ModifierLetter),
+    ('\u{A016}', '\u{A48C}', OtherLetter),
+    ('\u{A490}', '\u{A4C6}', OtherSymbol),
+    ('\u{A4D0}', '\u{A4F7}', OtherLetter),
+    ('\u{A4F8}', '\u{A4FD}', ModifierLetter),
+    ('\u{A4FE}', '\u{A4FF}', OtherPunctuation),
+    ('\u{A500}', '\u{A60B}', OtherLetter),
+    ('\u{A60C}', '\u{A60C}', ModifierLetter),
+    ('\u{A60D}', '\u{A60F}', OtherPunctuation),
+    ('\u{A610}', '\u{A61F}', OtherLetter),
+    ('\u{A620}', '\u{A629}', DecimalNumber),
+    ('\u{A62A}', '\u{A62B}', OtherLetter),
+    ('\u{A640}', '\u{A640}', UppercaseLetter),
+    ('\u{A641}', '\u{A641}', LowercaseLetter),
+    ('\u{A642}', '\u{A642}', UppercaseLetter),
+    ('\u{A643}', '\u{A643}', LowercaseLetter),
+    ('\u{A644}', '\u{A644}', UppercaseLetter),
+    ('\u{A645}', '\u{A645}', LowercaseLetter),
+    ('\u{A646}', '\u{A646}', UppercaseLetter),
+    ('\u{A647}', '\u{A647}', LowercaseLetter),
+    ('\u{A648}', '\u{A648}', UppercaseLetter),
+    ('\u{A649}', '\u{A649}', LowercaseLetter),
+    ('\u{A64A}', '\u{A64A}', UppercaseLetter),
+    ('\u{A64B}', '\u{A64B}', LowercaseLetter),
+    ('\u{A64C}', '\u{A64C}', UppercaseLetter),
+    ('\u{A64D}', '\u{A64D}', LowercaseLetter),
+    ('\u{A64E}', '\u{A64E}', UppercaseLetter),
+    ('\u{A64F}', '\u{A64F}', LowercaseLetter),
+    ('\u{A650}', '\u{A650}', UppercaseLetter),
+    ('\u{A651}', '\u{A651}', LowercaseLetter),
+    ('\u{A652}', '\u{A652}', UppercaseLetter),
+    ('\u{A653}', '\u{A653}', LowercaseLetter),
+    ('\u{A654}', '\u{A654}', UppercaseLetter),
+    ('\u{A655}', '\u{A655}', LowercaseLetter),
+    ('\u{A656}', '\u{A656}', UppercaseLetter),
+    ('\u{A657}', '\u{A657}', LowercaseLetter),
+    ('\u{A658}', '\u{A658}', UppercaseLetter),
+    ('\u{A659}', '\u{A659}', LowercaseLetter),
+    ('\u{A65A}', '\u{A65A}', UppercaseLetter),
+    ('\u{A65B}', '\u{A65B}', LowercaseLetter),
+    ('\u{A65C}', '\u{A65C}', UppercaseLetter),
+    ('\u{A65D}', '\u{A65D}', LowercaseLetter),
+    ('\u{A65E}', '\u{A65E}', UppercaseLetter),
+    ('\u{A65F}', '\u{A65F}', LowercaseLetter),
+    ('\u{A660}', '\u{A660}', UppercaseLetter),
+    ('\u{A661}', '\u{A661}', LowercaseLetter),
+    ('\u{A662}', '\u{A662}', UppercaseLetter),
+    ('\u{A663}', '\u{A663}', LowercaseLetter),
+    ('\u{A664}', '\u{A664}', UppercaseLetter),
+    ('\u{A665}', '\u{A665}', LowercaseLetter),
+    ('\u{A666}', '\u{A666}', UppercaseLetter),
+    ('\u{A667}', '\u{A667}', LowercaseLetter),
+    ('\u{A668}', '\u{A668}', UppercaseLetter),
+    ('\u{A669}', '\u{A669}', LowercaseLetter),
+    ('\u{A66A}', '\u{A66A}', UppercaseLetter),
+    ('\u{A66B}', '\u{A66B}', LowercaseLetter),
+    ('\u{A66C}', '\u{A66C}', UppercaseLetter),
+    ('\u{A66D}', '\u{A66D}', LowercaseLetter),
+    ('\u{A66E}', '\u{A66E}', OtherLetter),
+    ('\u{A66F}', '\u{A66F}', NonspacingMark),
+    ('\u{A670}', '\u{A672}', EnclosingMark),
+    ('\u{A673}', '\u{A673}', OtherPunctuation),
+    ('\u{A674}', '\u{A67D}', NonspacingMark),
+    ('\u{A67E}', '\u{A67E}', OtherPunctuation),
+    ('\u{A67F}', '\u{A67F}', ModifierLetter),
+    ('\u{A680}', '\u{A680}', UppercaseLetter),
+    ('\u{A681}', '\u{A681}', LowercaseLetter),
+    ('\u{A682}', '\u{A682}', UppercaseLetter),
+    ('\u{A683}', '\u{A683}', LowercaseLetter),
+    ('\u{A684}', '\u{A684}', UppercaseLetter),
+    ('\u{A685}', '\u{A685}', LowercaseLetter),
+    ('\u{A686}', '\u{A686}', UppercaseLetter),
+    ('\u{A687}', '\u{A687}', LowercaseLetter),
+    ('\u{A688}', '\u{A688}', UppercaseLetter),
+    ('\u{A689}', '\u{A689}', LowercaseLetter),
+    ('\u{A68A}', '\u{A68A}', UppercaseLetter),
+    ('\u{A68B}', '\u{A68B}', LowercaseLetter),
+    ('\u{A68C}', '\u{A68C}', UppercaseLetter),
+    ('\u{A68D}', '\u{A68D}', LowercaseLetter),
+    ('\u{A68E}', '\u{A68E}', UppercaseLetter),
+    ('\u{A68F}', '\u{A68F}', LowercaseLetter),
+    ('\u{A690}', '\u{A690}', UppercaseLetter),
+    ('\u{A691}', '\u{A691}', LowercaseLetter),
+    ('\u{A692}', '\u{A692}', UppercaseLetter),
+    ('\u{A693}', '\u{A693}', LowercaseLetter),
+    ('\u{A694}', '\u{A694}', UppercaseLetter),
+    ('\u{A695}', '\u{A695}', LowercaseLetter),
+    ('\u{A696}', '\u{A696}', UppercaseLetter),
+    ('\u{A697}', '\u{A697}', LowercaseLetter),
+    ('\u{A698}', '\u{A698}', UppercaseLetter),
+    ('\u{A699}', '\u{A699}', LowercaseLetter),
+    ('\u{A69A}', '\u{A69A}', UppercaseLetter),
+    ('\u{A69B}', '\u{A69B}', LowercaseLetter),
+    ('\u{A69C}', '\u{A69D}', ModifierLetter),
+    ('\u{A69E}', '\u{A69F}', NonspacingMark),
+    ('\u{A6A0}', '\u{A6E5}', OtherLetter),
+    ('\u{A6E6}', '\u{A6EF}', LetterNumber),
+    ('\u{A6F0}', '\u{A6F1}', NonspacingMark),
+    ('\u{A6F2}', '\u{A6F7}', OtherPunctuation),
+    ('\u{A700}', '\u{A716}', ModifierSymbol),
+    ('\u{A717}', '\u{A71F}', ModifierLetter),
+    ('\u{A720}', '\u{A721}', ModifierSymbol),
+    ('\u{A722}', '\u{A722}', UppercaseLetter),
+    ('\u{A723}', '\u{A723}', LowercaseLetter),
+    ('\u{A724}', '\u{A724}', UppercaseLetter),
+    ('\u{A725}', '\u{A725}', LowercaseLetter),
+    ('\u{A726}', '\u{A726}', UppercaseLetter),
+    ('\u{A727}', '\u{A727}', LowercaseLetter),
+    ('\u{A728}', '\u{A728}', UppercaseLetter),
+    ('\u{A729}', '\u{A729}', LowercaseLetter),
+    ('\u{A72A}', '\u{A72A}', UppercaseLetter),
+    ('\u{A72B}', '\u{A72B}', LowercaseLetter),
+    ('\u{A72C}', '\u{A72C}', UppercaseLetter),
+    ('\u{A72D}', '\u{A72D}', LowercaseLetter),
+    ('\u{A72E}', '\u{A72E}', UppercaseLetter),
+    ('\u{A72F}', '\u{A731}', LowercaseLetter),
+    ('\u{A732}', '\u{A732}', UppercaseLetter),
+    ('\u{A733}', '\u{A733}', LowercaseLetter),
+    ('\u{A734}', '\u{A734}', UppercaseLetter),
+    ('\u{A735}', '\u{A735}', LowercaseLetter),
+    ('\u{A736}', '\u{A736}', UppercaseLetter),
+    ('\u{A737}', '\u{A737}', LowercaseLetter),
+    ('\u{A738}', '\u{A738}', UppercaseLetter),
+    ('\u{A739}', '\u{A739}', LowercaseLetter),
+    ('\u{A73A}', '\u{A73A}', UppercaseLetter),
+    ('\u{A73B}', '\u{A73B}', LowercaseLetter),
+    ('\u{A73C}', '\u{A73C}', UppercaseLetter),
+    ('\u{A73D}', '\u{A73D}', LowercaseLetter),
+    ('\u{A73E}', '\u{A73E}', UppercaseLetter),
+    ('\u{A73F}', '\u{A73F}', LowercaseLetter),
+    ('\u{A740}', '\u{A740}', UppercaseLetter),
+    ('\u{A741}', '\u{A741}', LowercaseLetter),
+    ('\u{A742}', '\u{A742}', UppercaseLetter),
+    ('\u{A743}', '\u{A743}', LowercaseLetter),
+    ('\u{A744}', '\u{A744}', UppercaseLetter),
+    ('\u{A745}', '\u{A745}', LowercaseLetter),
+    ('\u{A746}', '\u{A746}', UppercaseLetter),
+    ('\u{A747}', '\u{A747}', LowercaseLetter),
+    ('\u{A748}', '\u{A748}', UppercaseLetter),
+    ('\u{A749}', '\u{A749}', LowercaseLetter),
+    ('\u{A74A}', '\u{A74A}', UppercaseLetter),
+    ('\u{A74B}', '\u{A74B}', LowercaseLetter),
+    ('\u{A74C}', '\u{A74C}', UppercaseLetter),
+    ('\u{A74D}', '\u{A74D}', LowercaseLetter),
+    ('\u{A74E}', '\u{A74E}', UppercaseLetter),
+    ('\u{A74F}', '\u{A74F}', LowercaseLetter),
+    ('\u{A750}', '\u{A750}', UppercaseLetter),
+    ('\u{A751}', '\u{A751}', LowercaseLetter),
+    ('\u{A752}', '\u{A752}', UppercaseLetter),
+    ('\u{A753}', '\u{A753}', LowercaseLetter),
+    ('\u{A754}', '\u{A754}', UppercaseLetter),
+    ('\u{A755}', '\u{A755}', LowercaseLetter),
+    ('\u{A756}', '\u{A756}', UppercaseLetter),
+    ('\u{A757}', '\u{A757}', LowercaseLetter),
+    ('\u{A758}', '\u{A758}', UppercaseLetter),
+    ('\u{A759}', '\u{A759}', LowercaseLetter),
+    ('\u{A75A}', '\u{A75A}', UppercaseLetter),
+    ('\u{A75B}', '\u{A75B}', LowercaseLetter),
+    ('\u{A75C}', '\u{A75C}', UppercaseLetter),
+    ('\u{A75D}', '\u{A75D}', LowercaseLetter),
+    ('\u{A75E}', '\u{A75E}', UppercaseLetter),
+    ('\u{A75F}', '\u{A75F}', LowercaseLetter),
+    ('\u{A760}', '\u{A760}', UppercaseLetter),
+    ('\u{A761}', '\u{A761}', LowercaseLetter),
+    ('\u{A762}', '\u{A762}', UppercaseLetter),
+    ('\u{A763}', '\u{A763}', LowercaseLetter),
+    ('\u{A764}', '\u{A764}', UppercaseLetter),
+    ('\u{A765}', '\u{A765}', LowercaseLetter),
+    ('\u{A766}', '\u{A766}', UppercaseLetter),
+    ('\u{A767}', '\u{A767}', LowercaseLetter),
+    ('\u{A768}', '\u{A768}', UppercaseLetter),
+    ('\u{A769}', '\u{A769}', LowercaseLetter),
+    ('\u{A76A}', '\u{A76A}', UppercaseLetter),
+    ('\u{A76B}', '\u{A76B}', LowercaseLetter),
+    ('\u{A76C}', '\u{A76C}', UppercaseLetter),
+    ('\u{A76D}', '\u{A76D}', LowercaseLetter),
+    ('\u{A76E}', '\u{A76E}', UppercaseLetter),
+    ('\u{A76F}', '\u{A76F}', LowercaseLetter),
+    ('\u{A770}', '\u{A770}', ModifierLetter),
+    ('\u{A771}', '\u{A778}', LowercaseLetter),
+    ('\u{A779}', '\u{A779}', UppercaseLetter),
+    ('\u{A77A}', '\u{A77A}', LowercaseLetter),
+    ('\u{A77B}', '\u{A77B}', UppercaseLetter),
+    ('\u{A77C}', '\u{A77C}', LowercaseLetter),
+    ('\u{A77D}', '\u{A77E}', UppercaseLetter),
+    ('\u{A77F}', '\u{A77F}', LowercaseLetter),
+    ('\u{A780}', '\u{A780}', UppercaseLetter),
+    ('\u{A781}', '\u{A781}', LowercaseLetter),
+    ('\u{A782}', '\u{A782}', UppercaseLetter),
+    ('\u{A783}', '\u{A783}', LowercaseLetter),
+    ('\u{A784}', '\u{A784}', UppercaseLetter),
+    ('\u{A785}', '\u{A785}', LowercaseLetter),
+    ('\u{A786}', '\u{A786}', UppercaseLetter),
+    ('\u{A787}', '\u{A787}', LowercaseLetter),
+    ('\u{A788}', '\u{A788}', ModifierLetter),
+    ('\u{A789}', '\u{A78A}', ModifierSymbol),
+    ('\u{A78B}', '\u{A78B}', UppercaseLetter),
+    ('\u{A78C}', '\u{A78C}', LowercaseLetter),
+    ('\u{A78D}', '\u{A78D}', UppercaseLetter),
+    ('\u{A78E}', '\u{A78E}', LowercaseLetter),
+    ('\u{A78F}', '\u{A78F}', OtherLetter),
+    ('\u{A790}', '\u{A790}', UppercaseLetter),
+    ('\u{A791}', '\u{A791}', LowercaseLetter),
+    ('\u{A792}', '\u{A792}', UppercaseLetter),
+    ('\u{A793}', '\u{A795}', LowercaseLetter),
+    ('\u{A796}', '\u{A796}', UppercaseLetter),
+    ('\u{A797}', '\u{A797}', LowercaseLetter),
+    ('\u{A798}', '\u{A798}', UppercaseLetter),
+    ('\u{A799}', '\u{A799}', LowercaseLetter),
+    ('\u{A79A}', '\u{A79A}', UppercaseLetter),
+    ('\u{A79B}', '\u{A79B}', LowercaseLetter),
+    ('\u{A79C}', '\u{A79C}', UppercaseLetter),
+    ('\u{A79D}', '\u{A79D}', LowercaseLetter),
+    ('\u{A79E}', '\u{A79E}', UppercaseLetter),
+    ('\u{A79F}', '\u{A79F}', LowercaseLetter),
+    ('\u{A7A0}', '\u{A7A0}', UppercaseLetter),
+    ('\u{A7A1}', '\u{A7A1}', LowercaseLetter),
+    ('\u{A7A2}', '\u{A7A2}', UppercaseLetter),
+    ('\u{A7A3}', '\u{A7A3}', LowercaseLetter),
+    ('\u{A7A4}', '\u{A7A4}', UppercaseLetter),
+    ('\u{A7A5}', '\u{A7A5}', LowercaseLetter),
+    ('\u{A7A6}', '\u{A7A6}', UppercaseLetter),
+    ('\u{A7A7}', '\u{A7A7}', LowercaseLetter),
+    ('\u{A7A8}', '\u{A7A8}', UppercaseLetter),
+    ('\u{A7A9}', '\u{A7A9}', LowercaseLetter),
+    ('\u{A7AA}', '\u{A7AE}', UppercaseLetter),
+    ('\u{A7AF}', '\u{A7AF}', LowercaseLetter),
+    ('\u{A7B0}', '\u{A7B4}', UppercaseLetter),
+    ('\u{A7B5}', '\u{A7B5}', LowercaseLetter),
+    ('\u{A7B6}', '\u{A7B6}', UppercaseLetter),
+    ('\u{A7B7}', '\u{A7B7}', LowercaseLetter),
+    ('\u{A7B8}', '\u{A7B8}', UppercaseLetter),
+    ('\u{A7B9}', '\u{A7B9}', LowercaseLetter),
+    ('\u{A7BA}', '\u{A7BA}', UppercaseLetter),
+    ('\u{A7BB}', '\u{A7BB}', LowercaseLetter),
+    ('\u{A7BC}', '\u{A7BC}', UppercaseLetter),
+    ('\u{A7BD}', '\u{A7BD}', LowercaseLetter),
+    ('\u{A7BE}', '\u{A7BE}', UppercaseLetter),
+    ('\u{A7BF}', '\u{A7BF}', LowercaseLetter),
+    ('\u{A7C0}', '\u{A7C0}', UppercaseLetter),
+    ('\u{A7C1}', '\u{A7C1}', LowercaseLetter),
+    ('\u{A7C2}', '\u{A7C2}', UppercaseLetter),
+    ('\u{A7C3}', '\u{A7C3}', LowercaseLetter),
+    ('\u{A7C4}', '\u{A7C7}', UppercaseLetter),
+    ('\u{A7C8}', '\u{A7C8}', LowercaseLetter),
+    ('\u{A7C9}', '\u{A7C9}', UppercaseLetter),
+    ('\u{A7CA}', '\u{A7CA}', LowercaseLetter),
+    ('\u{A7D0}', '\u{A7D0}', UppercaseLetter),
+    ('\u{A7D1}', '\u{A7D1}', LowercaseLetter),
+    ('\u{A7D3}', '\u{A7D3}', LowercaseLetter),
+    ('\u{A7D5}', '\u{A7D5}', LowercaseLetter),
+    ('\u{A7D6}', '\u{A7D6}', UppercaseLetter),
+    ('\u{A7D7}', '\u{A7D7}', LowercaseLetter),
+    ('\u{A7D8}', '\u{A7D8}', UppercaseLetter),
+    ('\u{A7D9}', '\u{A7D9}', LowercaseLetter),
+    ('\u{A7F2}', '\u{A7F4}', ModifierLetter),
+    ('\u{A7F5}', '\u{A7F5}', UppercaseLetter),
+    ('\u{A7F6}', '\u{A7F6}', LowercaseLetter),
+    ('\u{A7F7}', '\u{A7F7}', OtherLetter),
+    ('\u{A7F8}', '\u{A7F9}', ModifierLetter),
+    ('\u{A7FA}', '\u{A7FA}', LowercaseLetter),
+    ('\u{A7FB}', '\u{A801}', OtherLetter),
+    ('\u{A802}', '\u{A802}', NonspacingMark),
+    ('\u{A803}', '\u{A805}', OtherLetter),
+    ('\u{A806}', '\u{A806}', NonspacingMark),
+    ('\u{A807}', '\u{A80A}', OtherLetter),
+    ('\u{A80B}', '\u{A80B}', NonspacingMark),
+    ('\u{A80C}', '\u{A822}', OtherLetter),
+    ('\u{A823}', '\u{A824}', SpacingMark),
+    ('\u{A825}', '\u{A826}', NonspacingMark),
+    ('\u{A827}', '\u{A827}', SpacingMark),
+    ('\u{A828}', '\u{A82B}', OtherSymbol),
+    ('\u{A82C}', '\u{A82C}', NonspacingMark),
+    ('\u{A830}', '\u{A835}', OtherNumber),
+    ('\u{A836}', '\u{A837}', OtherSymbol),
+    ('\u{A838}', '\u{A838}', CurrencySymbol),
+    ('\u{A839}', '\u{A839}', OtherSymbol),
+    ('\u{A840}', '\u{A873}', OtherLetter),
+    ('\u{A874}', '\u{A877}', OtherPunctuation),
+    ('\u{A880}', '\u{A881}', SpacingMark),
+    ('\u{A882}', '\u{A8B3}', OtherLetter),
+    ('\u{A8B4}', '\u{A8C3}', SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', NonspacingMark),
+    ('\u{A8CE}', '\u{A8CF}', OtherPunctuation),
+    ('\u{A8D0}', '\u{A8D9}', DecimalNumber),
+    ('\u{A8E0}', '\u{A8F1}', NonspacingMark),
+    ('\u{A8F2}', '\u{A8F7}', OtherLetter),
+    ('\u{A8F8}', '\u{A8FA}', OtherPunctuation),
+    ('\u{A8FB}', '\u{A8FB}', OtherLetter),
+    ('\u{A8FC}', '\u{A8FC}', OtherPunctuation),
+    ('\u{A8FD}', '\u{A8FE}', OtherLetter),
+    ('\u{A8FF}', '\u{A8FF}', NonspacingMark),
+    ('\u{A900}', '\u{A909}', DecimalNumber),
+    ('\u{A90A}', '\u{A925}', OtherLetter),
+    ('\u{A926}', '\u{A92D}', NonspacingMark),
+    ('\u{A92E}', '\u{A92F}', OtherPunctuation),
+    ('\u{A930}', '\u{A946}', OtherLetter),
+    ('\u{A947}', '\u{A951}', NonspacingMark),
+    ('\u{A952}', '\u{A953}', SpacingMark),
+    ('\u{A95F}', '\u{A95F}', OtherPunctuation),
+    ('\u{A960}', '\u{A97C}', OtherLetter),
+    ('\u{A980}', '\u{A982}', NonspacingMark),
+    ('\u{A983}', '\u{A983}', SpacingMark),
+    ('\u{A984}', '\u{A9B2}', OtherLetter),
+    ('\u{A9B3}', '\u{A9B3}', NonspacingMark),
+    ('\u{A9B4}', '\u{A9B5}', SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', NonspacingMark),
+    ('\u{A9BA}', '\u{A9BB}', SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', NonspacingMark),
+    ('\u{A9BE}', '\u{A9C0}', SpacingMark),
+    ('\u{A9C1}', '\u{A9CD}', OtherPunctuation),
+    ('\u{A9CF}', '\u{A9CF}', ModifierLetter),
+    ('\u{A9D0}', '\u{A9D9}', DecimalNumber),
+    ('\u{A9DE}', '\u{A9DF}', OtherPunctuation),
+    ('\u{A9E0}', '\u{A9E4}', OtherLetter),
+    ('\u{A9E5}', '\u{A9E5}', NonspacingMark),
+    ('\u{A9E6}', '\u{A9E6}', ModifierLetter),
+    ('\u{A9E7}', '\u{A9EF}', OtherLetter),
+    ('\u{A9F0}', '\u{A9F9}', DecimalNumber),
+    ('\u{A9FA}', '\u{A9FE}', OtherLetter),
+    ('\u{AA00}', '\u{AA28}', OtherLetter),
+    ('\u{AA29}', '\u{AA2E}', NonspacingMark),
+    ('\u{AA2F}', '\u{AA30}', SpacingMark),
+    ('\u{AA31}', '\u{AA32}', NonspacingMark),
+    ('\u{AA33}', '\u{AA34}', SpacingMark),
+    ('\u{AA35}', '\u{AA36}', NonspacingMark),
+    ('\u{AA40}', '\u{AA42}', OtherLetter),
+    ('\u{AA43}', '\u{AA43}', NonspacingMark),
+    ('\u{AA44}', '\u{AA4B}', OtherLetter),
+    ('\u{AA4C}', '\u{AA4C}', NonspacingMark),
+    ('\u{AA4D}', '\u{AA4D}', SpacingMark),
+    ('\u{AA50}', '\u{AA59}', DecimalNumber),
+    ('\u{AA5C}', '\u{AA5F}', OtherPunctuation),
+    ('\u{AA60}', '\u{AA6F}', OtherLetter),
+    ('\u{AA70}', '\u{AA70}', ModifierLetter),
+    ('\u{AA71}', '\u{AA76}', OtherLetter),
+    ('\u{AA77}', '\u{AA79}', OtherSymbol),
+    ('\u{AA7A}', '\u{AA7A}', OtherLetter),
+    ('\u{AA7B}', '\u{AA7B}', SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', NonspacingMark),
+    ('\u{AA7D}', '\u{AA7D}', SpacingMark),
+    ('\u{AA7E}', '\u{AAAF}', OtherLetter),
+    ('\u{AAB0}', '\u{AAB0}', NonspacingMark),
+    ('\u{AAB1}', '\u{AAB1}', OtherLetter),
+    ('\u{AAB2}', '\u{AAB4}', NonspacingMark),
+    ('\u{AAB5}', '\u{AAB6}', OtherLetter),
+    ('\u{AAB7}', '\u{AAB8}', NonspacingMark),
+    ('\u{AAB9}', '\u{AABD}', OtherLetter),
+    ('\u{AABE}', '\u{AABF}', NonspacingMark),
+    ('\u{AAC0}', '\u{AAC0}', OtherLetter),
+    ('\u{AAC1}', '\u{AAC1}', NonspacingMark),
+    ('\u{AAC2}', '\u{AAC2}', OtherLetter),
+    ('\u{AADB}', '\u{AADC}', OtherLetter),
+    ('\u{AADD}', '\u{AADD}', ModifierLetter),
+    ('\u{AADE}', '\u{AADF}', OtherPunctuation),
+    ('\u{AAE0}', '\u{AAEA}', OtherLetter),
+    ('\u{AAEB}', '\u{AAEB}', SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', NonspacingMark),
+    ('\u{AAEE}', '\u{AAEF}', SpacingMark),
+    ('\u{AAF0}', '\u{AAF1}', OtherPunctuation),
+    ('\u{AAF2}', '\u{AAF2}', OtherLetter),
+    ('\u{AAF3}', '\u{AAF4}', ModifierLetter),
+    ('\u{AAF5}', '\u{AAF5}', SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', NonspacingMark),
+    ('\u{AB01}', '\u{AB06}', OtherLetter),
+    ('\u{AB09}', '\u{AB0E}', OtherLetter),
+    ('\u{AB11}', '\u{AB16}', OtherLetter),
+    ('\u{AB20}', '\u{AB26}', OtherLetter),
+    ('\u{AB28}', '\u{AB2E}', OtherLetter),
+    ('\u{AB30}', '\u{AB5A}', LowercaseLetter),
+    ('\u{AB5B}', '\u{AB5B}', ModifierSymbol),
+    ('\u{AB5C}', '\u{AB5F}', ModifierLetter),
+    ('\u{AB60}', '\u{AB68}', LowercaseLetter),
+    ('\u{AB69}', '\u{AB69}', ModifierLetter),
+    ('\u{AB6A}', '\u{AB6B}', ModifierSymbol),
+    ('\u{AB70}', '\u{ABBF}', LowercaseLetter),
+    ('\u{ABC0}', '\u{ABE2}', OtherLetter),
+    ('\u{ABE3}', '\u{ABE4}', SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', NonspacingMark),
+    ('\u{ABE6}', '\u{ABE7}', SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', NonspacingMark),
+    ('\u{ABE9}', '\u{ABEA}', SpacingMark),
+    ('\u{ABEB}', '\u{ABEB}', OtherPunctuation),
+    ('\u{ABEC}', '\u{ABEC}', SpacingMark),
+    ('\u{ABED}', '\u{ABED}', NonspacingMark),
+    ('\u{ABF0}', '\u{ABF9}', DecimalNumber),
+    ('\u{AC00}', '\u{D7A3}', OtherLetter),
+    ('\u{D7B0}', '\u{D7C6}', OtherLetter),
+    ('\u{D7CB}', '\u{D7FB}', OtherLetter),
+    ('\u{E000}', '\u{F8FF}', PrivateUse),
+    ('\u{F900}', '\u{FA6D}', OtherLetter),
+    ('\u{FA70}', '\u{FAD9}', OtherLetter),
+    ('\u{FB00}', '\u{FB06}', LowercaseLetter),
+    ('\u{FB13}', '\u{FB17}', LowercaseLetter),
+    ('\u{FB1D}', '\u{FB1D}', OtherLetter),
+    ('\u{FB1E}', '\u{FB1E}', NonspacingMark),
+    ('\u{FB1F}', '\u{FB28}', OtherLetter),
+    ('\u{FB29}', '\u{FB29}', MathSymbol),
+    ('\u{FB2A}', '\u{FB36}', OtherLetter),
+    ('\u{FB38}', '\u{FB3C}', OtherLetter),
+    ('\u{FB3E}', '\u{FB3E}', OtherLetter),
+    ('\u{FB40}', '\u{FB41}', OtherLetter),
+    ('\u{FB43}', '\u{FB44}', OtherLetter),
+    ('\u{FB46}', '\u{FBB1}', OtherLetter),
+    ('\u{FBB2}', '\u{FBC2}', ModifierSymbol),
+    ('\u{FBD3}', '\u{FD3D}', OtherLetter),
+    ('\u{FD3E}', '\u{FD3E}', ClosePunctuation),
+    ('\u{FD3F}', '\u{FD3F}', OpenPunctuation),
+    ('\u{FD40}', '\u{FD4F}', OtherSymbol),
+    ('\u{FD50}', '\u{FD8F}', OtherLetter),
+    ('\u{FD92}', '\u{FDC7}', OtherLetter),
+    ('\u{FDCF}', '\u{FDCF}', OtherSymbol),
+    ('\u{FDF0}', '\u{FDFB}', OtherLetter),
+    ('\u{FDFC}', '\u{FDFC}', CurrencySymbol),
+    ('\u{FDFD}', '\u{FDFF}', OtherSymbol),
+    ('\u{FE00}', '\u{FE0F}', NonspacingMark),
+    ('\u{FE10}', '\u{FE16}', OtherPunctuation),
+    ('\u{FE17}', '\u{FE17}', OpenPunctuation),
+    ('\u{FE18}', '\u{FE18}', ClosePunctuation),
+    ('\u{FE19}', '\u{FE19}', OtherPunctuation),
+    ('\u{FE20}', '\u{FE2F}', NonspacingMark),
+    ('\u{FE30}', '\u{FE30}', OtherPunctuation),
+    ('\u{FE31}', '\u{FE32}', DashPunctuation),
+    ('\u{FE33}', '\u{FE34}', ConnectorPunctuation),
+    ('\u{FE35}', '\u{FE35}', OpenPunctuation),
+    ('\u{FE36}', '\u{FE36}', ClosePunctuation),
+    ('\u{FE37}', '\u{FE37}', OpenPunctuation),
+    ('\u{FE38}', '\u{FE38}', ClosePunctuation),
+    ('\u{FE39}', '\u{FE39}', OpenPunctuation),
+    ('\u{FE3A}', '\u{FE3A}', ClosePunctuation),
+    ('\u{FE3B}', '\u{FE3B}', OpenPunctuation),
+    ('\u{FE3C}', '\u{FE3C}', ClosePunctuation),
+    ('\u{FE3D}', '\u{FE3D}', OpenPunctuation),
+    ('\u{FE3E}', '\u{FE3E}', ClosePunctuation),
+    ('\u{FE3F}', '\u{FE3F}', OpenPunctuation),
+    ('\u{FE40}', '\u{FE40}', ClosePunctuation),
+    ('\u{FE41}', '\u{FE41}', OpenPunctuation),
+    ('\u{FE42}', '\u{FE42}', ClosePunctuation),
+    ('\u{FE43}', '\u{FE43}', OpenPunctuation),
+    ('\u{FE44}', '\u{FE44}', ClosePunctuation),
+    ('\u{FE45}', '\u{FE46}', OtherPunctuation),
+    ('\u{FE47}', '\u{FE47}', OpenPunctuation),
+    ('\u{FE48}', '\u{FE48}', ClosePunctuation),
+    ('\u{FE49}', '\u{FE4C}', OtherPunctuation),
+    ('\u{FE4D}', '\u{FE4F}', ConnectorPunctuation),
+    ('\u{FE50}', '\u{FE52}', OtherPunctuation),
+    ('\u{FE54}', '\u{FE57}', OtherPunctuation),
+    ('\u{FE58}', '\u{FE58}', DashPunctuation),
+    ('\u{FE59}', '\u{FE59}', OpenPunctuation),
+    ('\u{FE5A}', '\u{FE5A}', ClosePunctuation),
+    ('\u{FE5B}', '\u{FE5B}', OpenPunctuation),
+    ('\u{FE5C}', '\u{FE5C}', ClosePunctuation),
+    ('\u{FE5D}', '\u{FE5D}', OpenPunctuation),
+    ('\u{FE5E}', '\u{FE5E}', ClosePunctuation),
+    ('\u{FE5F}', '\u{FE61}', OtherPunctuation),
+    ('\u{FE62}', '\u{FE62}', MathSymbol),
+    ('\u{FE63}', '\u{FE63}', DashPunctuation),
+    ('\u{FE64}', '\u{FE66}', MathSymbol),
+    ('\u{FE68}', '\u{FE68}', OtherPunctuation),
+    ('\u{FE69}', '\u{FE69}', CurrencySymbol),
+    ('\u{FE6A}', '\u{FE6B}', OtherPunctuation),
+    ('\u{FE70}', '\u{FE74}', OtherLetter),
+    ('\u{FE76}', '\u{FEFC}', OtherLetter),
+    ('\u{FEFF}', '\u{FEFF}', Format),
+    ('\u{FF01}', '\u{FF03}', OtherPunctuation),
+    ('\u{FF04}', '\u{FF04}', CurrencySymbol),
+    ('\u{FF05}', '\u{FF07}', OtherPunctuation),
+    ('\u{FF08}', '\u{FF08}', OpenPunctuation),
+    ('\u{FF09}', '\u{FF09}', ClosePunctuation),
+    ('\u{FF0A}', '\u{FF0A}', OtherPunctuation),
+    ('\u{FF0B}', '\u{FF0B}', MathSymbol),
+    ('\u{FF0C}', '\u{FF0C}', OtherPunctuation),
+    ('\u{FF0D}', '\u{FF0D}', DashPunctuation),
+    ('\u{FF0E}', '\u{FF0F}', OtherPunctuation),
+    ('\u{FF10}', '\u{FF19}', DecimalNumber),
+    ('\u{FF1A}', '\u{FF1B}', OtherPunctuation),
+    ('\u{FF1C}', '\u{FF1E}', MathSymbol),
+    ('\u{FF1F}', '\u{FF20}', OtherPunctuation),
+    ('\u{FF21}', '\u{FF3A}', UppercaseLetter),
+    ('\u{FF3B}', '\u{FF3B}', OpenPunctuation),
+    ('\u{FF3C}', '\u{FF3C}', OtherPunctuation),
+    ('\u{FF3D}', '\u{FF3D}', ClosePunctuation),
+    ('\u{FF3E}', '\u{FF3E}', ModifierSymbol),
+    ('\u{FF3F}', '\u{FF3F}', ConnectorPunctuation),
+    ('\u{FF40}', '\u{FF40}', ModifierSymbol),
+    ('\u{FF41}', '\u{FF5A}', LowercaseLetter),
+    ('\u{FF5B}', '\u{FF5B}', OpenPunctuation),
+    ('\u{FF5C}', '\u{FF5C}', MathSymbol),
+    ('\u{FF5D}', '\u{FF5D}', ClosePunctuation),
+    ('\u{FF5E}', '\u{FF5E}', MathSymbol),
+    ('\u{FF5F}', '\u{FF5F}', OpenPunctuation),
+    ('\u{FF60}', '\u{FF60}', ClosePunctuation),
+    ('\u{FF61}', '\u{FF61}', OtherPunctuation),
+    ('\u{FF62}', '\u{FF62}', OpenPunctuation),
+    ('\u{FF63}', '\u{FF63}', ClosePunctuation),
+    ('\u{FF64}', '\u{FF65}', OtherPunctuation),
+    ('\u{FF66}', '\u{FF6F}', OtherLetter),
+    ('\u{FF70}', '\u{FF70}', ModifierLetter),
+    ('\u{FF71}', '\u{FF9D}', OtherLetter),
+    ('\u{FF9E}', '\u{FF9F}', ModifierLetter),
+    ('\u{FFA0}', '\u{FFBE}', OtherLetter),
+    ('\u{FFC2}', '\u{FFC7}', OtherLetter),
+    ('\u{FFCA}', '\u{FFCF}', OtherLetter),
+    ('\u{FFD2}', '\u{FFD7}', OtherLetter),
+    ('\u{FFDA}', '\u{FFDC}', OtherLetter),
+    ('\u{FFE0}', '\u{FFE1}', CurrencySymbol),
+    ('\u{FFE2}', '\u{FFE2}', MathSymbol),
+    ('\u{FFE3}', '\u{FFE3}', ModifierSymbol),
+    ('\u{FFE4}', '\u{FFE4}', OtherSymbol),
+    ('\u{FFE5}', '\u{FFE6}', CurrencySymbol),
+    ('\u{FFE8}', '\u{FFE8}', OtherSymbol),
+    ('\u{FFE9}', '\u{FFEC}', MathSymbol),
+    ('\u{FFED}', '\u{FFEE}', OtherSymbol),
+    ('\u{FFF9}', '\u{FFFB}', Format),
+    ('\u{FFFC}', '\u{FFFD}', OtherSymbol),
+    ('\u{10000}', '\u{1000B}', OtherLetter),
+    ('\u{1000D}', '\u{10026}', OtherLetter),
+    ('\u{10028}', '\u{1003A}', OtherLetter),
+    ('\u{1003C}', '\u{1003D}', OtherLetter),
+    ('\u{1003F}', '\u{1004D}', OtherLetter),
+    ('\u{10050}', '\u{1005D}', OtherLetter),
+    ('\u{10080}', '\u{100FA}', OtherLetter),
+    ('\u{10100}', '\u{10102}', OtherPunctuation),
+    ('\u{10107}', '\u{10133}', OtherNumber),
+    ('\u{10137}', '\u{1013F}', OtherSymbol),
+    ('\u{10140}', '\u{10174}', LetterNumber),
+    ('\u{10175}', '\u{10178}', OtherNumber),
+    ('\u{10179}', '\u{10189}', OtherSymbol),
+    ('\u{1018A}', '\u{1018B}', OtherNumber),
+    ('\u{1018C}', '\u{1018E}', OtherSymbol),
+    ('\u{10190}', '\u{1019C}', OtherSymbol),
+    ('\u{101A0}', '\u{101A0}', OtherSymbol),
+    ('\u{101D0}', '\u{101FC}', OtherSymbol),
+    ('\u{101FD}', '\u{101FD}', NonspacingMark),
+    ('\u{10280}', '\u{1029C}', OtherLetter),
+    ('\u{102A0}', '\u{102D0}', OtherLetter),
+    ('\u{102E0}', '\u{102E0}', NonspacingMark),
+    ('\u{102E1}', '\u{102FB}', OtherNumber),
+    ('\u{10300}', '\u{1031F}', OtherLetter),
+    ('\u{10320}', '\u{10323}', OtherNumber),
+    ('\u{1032D}', '\u{10340}', OtherLetter),
+    ('\u{10341}', '\u{10341}', LetterNumber),
+    ('\u{10342}', '\u{10349}', OtherLetter),
+    ('\u{1034A}', '\u{1034A}', LetterNumber),
+    ('\u{10350}', '\u{10375}', OtherLetter),
+    ('\u{10376}', '\u{1037A}', NonspacingMark),
+    ('\u{10380}', '\u{1039D}', OtherLetter),
+    ('\u{1039F}', '\u{1039F}', OtherPunctuation),
+    ('\u{103A0}', '\u{103C3}', OtherLetter),
+    ('\u{103C8}', '\u{103CF}', OtherLetter),
+    ('\u{103D0}', '\u{103D0}', OtherPunctuation),
+    ('\u{103D1}', '\u{103D5}', LetterNumber),
+    ('\u{10400}', '\u{10427}', UppercaseLetter),
+    ('\u{10428}', '\u{1044F}', LowercaseLetter),
+    ('\u{10450}', '\u{1049D}', OtherLetter),
+    ('\u{104A0}', '\u{104A9}', DecimalNumber),
+    ('\u{104B0}', '\u{104D3}', UppercaseLetter),
+    ('\u{104D8}', '\u{104FB}', LowercaseLetter),
+    ('\u{10500}', '\u{10527}', OtherLetter),
+    ('\u{10530}', '\u{10563}', OtherLetter),
+    ('\u{1056F}', '\u{1056F}', OtherPunctuation),
+    ('\u{10570}', '\u{1057A}', UppercaseLetter),
+    ('\u{1057C}', '\u{1058A}', UppercaseLetter),
+    ('\u{1058C}', '\u{10592}', UppercaseLetter),
+    ('\u{10594}', '\u{10595}', UppercaseLetter),
+    ('\u{10597}', '\u{105A1}', LowercaseLetter),
+    ('\u{105A3}', '\u{105B1}', LowercaseLetter),
+    ('\u{105B3}', '\u{105B9}', LowercaseLetter),
+    ('\u{105BB}', '\u{105BC}', LowercaseLetter),
+    ('\u{10600}', '\u{10736}', OtherLetter),
+    ('\u{10740}', '\u{10755}', OtherLetter),
+    ('\u{10760}', '\u{10767}', OtherLetter),
+    ('\u{10780}', '\u{10785}', ModifierLetter),
+    ('\u{10787}', '\u{107B0}', ModifierLetter),
+    ('\u{107B2}', '\u{107BA}', ModifierLetter),
+    ('\u{10800}', '\u{10805}', OtherLetter),
+    ('\u{10808}', '\u{10808}', OtherLetter),
+    ('\u{1080A}', '\u{10835}', OtherLetter),
+    ('\u{10837}', '\u{10838}', OtherLetter),
+    ('\u{1083C}', '\u{1083C}', OtherLetter),
+    ('\u{1083F}', '\u{10855}', OtherLetter),
+    ('\u{10857}', '\u{10857}', OtherPunctuation),
+    ('\u{10858}', '\u{1085F}', OtherNumber),
+    ('\u{10860}', '\u{10876}', OtherLetter),
+    ('\u{10877}', '\u{10878}', OtherSymbol),
+    ('\u{10879}', '\u{1087F}', OtherNumber),
+    ('\u{10880}', '\u{1089E}', OtherLetter),
+    ('\u{108A7}', '\u{108AF}', OtherNumber),
+    ('\u{108E0}', '\u{108F2}', OtherLetter),
+    ('\u{108F4}', '\u{108F5}', OtherLetter),
+    ('\u{108FB}', '\u{108FF}', OtherNumber),
+    ('\u{10900}', '\u{10915}', OtherLetter),
+    ('\u{10916}', '\u{1091B}', OtherNumber),
+    ('\u{1091F}', '\u{1091F}', OtherPunctuation),
+    ('\u{10920}', '\u{10939}', OtherLetter),
+    ('\u{1093F}', '\u{1093F}', OtherPunctuation),
+    ('\u{10980}', '\u{109B7}', OtherLetter),
+    ('\u{109BC}', '\u{109BD}', OtherNumber),
+    ('\u{109BE}', '\u{109BF}', OtherLetter),
+    ('\u{109C0}', '\u{109CF}', OtherNumber),
+    ('\u{109D2}', '\u{109FF}', OtherNumber),
+    ('\u{10A00}', '\u{10A00}', OtherLetter),
+    ('\u{10A01}', '\u{10A03}', NonspacingMark),
+    ('\u{10A05}', '\u{10A06}', NonspacingMark),
+    ('\u{10A0C}', '\u{10A0F}', NonspacingMark),
+    ('\u{10A10}', '\u{10A13}', OtherLetter),
+    ('\u{10A15}', '\u{10A17}', OtherLetter),
+    ('\u{10A19}', '\u{10A35}', OtherLetter),
+    ('\u{10A38}', '\u{10A3A}', NonspacingMark),
+    ('\u{10A3F}', '\u{10A3F}', NonspacingMark),
+    ('\u{10A40}', '\u{10A48}', OtherNumber),
+    ('\u{10A50}', '\u{10A58}', OtherPunctuation),
+    ('\u{10A60}', '\u{10A7C}', OtherLetter),
+    ('\u{10A7D}', '\u{10A7E}', OtherNumber),
+    ('\u{10A7F}', '\u{10A7F}', OtherPunctuation),
+    ('\u{10A80}', '\u{10A9C}', OtherLetter),
+    ('\u{10A9D}', '\u{10A9F}', OtherNumber),
+    ('\u{10AC0}', '\u{10AC7}', OtherLetter),
+    ('\u{10AC8}', '\u{10AC8}', OtherSymbol),
+    ('\u{10AC9}', '\u{10AE4}', OtherLetter),
+    ('\u{10AE5}', '\u{10AE6}', NonspacingMark),
+    ('\u{10AEB}', '\u{10AEF}', OtherNumber),
+    ('\u{10AF0}', '\u{10AF6}', OtherPunctuation),
+    ('\u{10B00}', '\u{10B35}', OtherLetter),
+    ('\u{10B39}', '\u{10B3F}', OtherPunctuation),
+    ('\u{10B40}', '\u{10B55}', OtherLetter),
+    ('\u{10B58}', '\u{10B5F}', OtherNumber),
+    ('\u{10B60}', '\u{10B72}', OtherLetter),
+    ('\u{10B78}', '\u{10B7F}', OtherNumber),
+    ('\u{10B80}', '\u{10B91}', OtherLetter),
+    ('\u{10B99}', '\u{10B9C}', OtherPunctuation),
+    ('\u{10BA9}', '\u{10BAF}', OtherNumber),
+    ('\u{10C00}', '\u{10C48}', OtherLetter),
+    ('\u{10C80}', '\u{10CB2}', UppercaseLetter),
+    ('\u{10CC0}', '\u{10CF2}', LowercaseLetter),
+    ('\u{10CFA}', '\u{10CFF}', OtherNumber),
+    ('\u{10D00}', '\u{10D23}', OtherLetter),
+    ('\u{10D24}', '\u{10D27}', NonspacingMark),
+    ('\u{10D30}', '\u{10D39}', DecimalNumber),
+    ('\u{10E60}', '\u{10E7E}', OtherNumber),
+    ('\u{10E80}', '\u{10EA9}', OtherLetter),
+    ('\u{10EAB}', '\u{10EAC}', NonspacingMark),
+    ('\u{10EAD}', '\u{10EAD}', DashPunctuation),
+    ('\u{10EB0}', '\u{10EB1}', OtherLetter),
+    ('\u{10F00}', '\u{10F1C}', OtherLetter),
+    ('\u{10F1D}', '\u{10F26}', OtherNumber),
+    ('\u{10F27}', '\u{10F27}', OtherLetter),
+    ('\u{10F30}', '\u{10F45}', OtherLetter),
+    ('\u{10F46}', '\u{10F50}', NonspacingMark),
+    ('\u{10F51}', '\u{10F54}', OtherNumber),
+    ('\u{10F55}', '\u{10F59}', OtherPunctuation),
+    ('\u{10F70}', '\u{10F81}', OtherLetter),
+    ('\u{10F82}', '\u{10F85}', NonspacingMark),
+    ('\u{10F86}', '\u{10F89}', OtherPunctuation),
+    ('\u{10FB0}', '\u{10FC4}', OtherLetter),
+    ('\u{10FC5}', '\u{10FCB}', OtherNumber),
+    ('\u{10FE0}', '\u{10FF6}', OtherLetter),
+    ('\u{11000}', '\u{11000}', SpacingMark),
+    ('\u{11001}', '\u{11001}', NonspacingMark),
+    ('\u{11002}', '\u{11002}', SpacingMark),
+    ('\u{11003}', '\u{11037}', OtherLetter),
+    ('\u{11038}', '\u{11046}', NonspacingMark),
+    ('\u{11047}', '\u{1104D}', OtherPunctuation),
+    ('\u{11052}', '\u{11065}', OtherNumber),
+    ('\u{11066}', '\u{1106F}', DecimalNumber),
+    ('\u{11070}', '\u{11070}', NonspacingMark),
+    ('\u{11071}', '\u{11072}', OtherLetter),
+    ('\u{11073}', '\u{11074}', NonspacingMark),
+    ('\u{11075}', '\u{11075}', OtherLetter),
+    ('\u{1107F}', '\u{11081}', NonspacingMark),
+    ('\u{11082}', '\u{11082}', SpacingMark),
+    ('\u{11083}', '\u{110AF}', OtherLetter),
+    ('\u{110B0}', '\u{110B2}', SpacingMark),
+    ('\u{110B3}', '\u{110B6}', NonspacingMark),
+    ('\u{110B7}', '\u{110B8}', SpacingMark),
+    ('\u{110B9}', '\u{110BA}', NonspacingMark),
+    ('\u{110BB}', '\u{110BC}', OtherPunctuation),
+    ('\u{110BD}', '\u{110BD}', Format),
+    ('\u{110BE}', '\u{110C1}', OtherPunctuation),
+    ('\u{110C2}', '\u{110C2}', NonspacingMark),
+    ('\u{110CD}', '\u{110CD}', Format),
+    ('\u{110D0}', '\u{110E8}', OtherLetter),
+    ('\u{110F0}', '\u{110F9}', DecimalNumber),
+    ('\u{11100}', '\u{11102}', NonspacingMark),
+    ('\u{11103}', '\u{11126}', OtherLetter),
+    ('\u{11127}', '\u{1112B}', NonspacingMark),
+    ('\u{1112C}', '\u{1112C}', SpacingMark),
+    ('\u{1112D}', '\u{11134}', NonspacingMark),
+    ('\u{11136}', '\u{1113F}', DecimalNumber),
+    ('\u{11140}', '\u{11143}', OtherPunctuation),
+    ('\u{11144}', '\u{11144}', OtherLetter),
+    ('\u{11145}', '\u{11146}', SpacingMark),
+    ('\u{11147}', '\u{11147}', OtherLetter),
+    ('\u{11150}', '\u{11172}', OtherLetter),
+    ('\u{11173}', '\u{11173}', NonspacingMark),
+    ('\u{11174}', '\u{11175}', OtherPunctuation),
+    ('\u{11176}', '\u{11176}', OtherLetter),
+    ('\u{11180}', '\u{11181}', NonspacingMark),
+    ('\u{11182}', '\u{11182}', SpacingMark),
+    ('\u{11183}', '\u{111B2}', OtherLetter),
+    ('\u{111B3}', '\u{111B5}', SpacingMark),
+    ('\u{111B6}', '\u{111BE}', NonspacingMark),
+    ('\u{111BF}', '\u{111C0}', SpacingMark),
+    ('\u{111C1}', '\u{111C4}', OtherLetter),
+    ('\u{111C5}', '\u{111C8}', OtherPunctuation),
+    ('\u{111C9}', '\u{111CC}', NonspacingMark),
+    ('\u{111CD}', '\u{111CD}', OtherPunctuation),
+    ('\u{111CE}', '\u{111CE}', SpacingMark),
+    ('\u{111CF}', '\u{111CF}', NonspacingMark),
+    ('\u{111D0}', '\u{111D9}', DecimalNumber),
+    ('\u{111DA}', '\u{111DA}', OtherLetter),
+    ('\u{111DB}', '\u{111DB}', OtherPunctuation),
+    ('\u{111DC}', '\u{111DC}', OtherLetter),
+    ('\u{111DD}', '\u{111DF}', OtherPunctuation),
+    ('\u{111E1}', '\u{111F4}', OtherNumber),
+    ('\u{11200}', '\u{11211}', OtherLetter),
+    ('\u{11213}', '\u{1122B}', OtherLetter),
+    ('\u{1122C}', '\u{1122E}', SpacingMark),
+    ('\u{1122F}', '\u{11231}', NonspacingMark),
+    ('\u{11232}', '\u{11233}', SpacingMark),
+    ('\u{11234}', '\u{11234}', NonspacingMark),
+    ('\u{11235}', '\u{11235}', SpacingMark),
+    ('\u{11236}', '\u{11237}', NonspacingMark),
+    ('\u{11238}', '\u{1123D}', OtherPunctuation),
+    ('\u{1123E}', '\u{1123E}', NonspacingMark),
+    ('\u{11280}', '\u{11286}', OtherLetter),
+    ('\u{11288}', '\u{11288}', OtherLetter),
+    ('\u{1128A}', '\u{1128D}', OtherLetter),
+    ('\u{1128F}', '\u{1129D}', OtherLetter),
+    ('\u{1129F}', '\u{112A8}', OtherLetter),
+    ('\u{112A9}', '\u{112A9}', OtherPunctuation),
+    ('\u{112B0}', '\u{112DE}', OtherLetter),
+    ('\u{112DF}', '\u{112DF}', NonspacingMark),
+    ('\u{112E0}', '\u{112E2}', SpacingMark),
+    ('\u{112E3}', '\u{112EA}', NonspacingMark),
+    ('\u{112F0}', '\u{112F9}', DecimalNumber),
+    ('\u{11300}', '\u{11301}', NonspacingMark),
+    ('\u{11302}', '\u{11303}', SpacingMark),
+    ('\u{11305}', '\u{1130C}', OtherLetter),
+    ('\u{1130F}', '\u{11310}', OtherLetter),
+    ('\u{11313}', '\u{11328}', OtherLetter),
+    ('\u{1132A}', '\u{11330}', OtherLetter),
+    ('\u{11332}', '\u{11333}', OtherLetter),
+    ('\u{11335}', '\u{11339}', OtherLetter),
+    ('\u{1133B}', '\u{1133C}', NonspacingMark),
+    ('\u{1133D}', '\u{1133D}', OtherLetter),
+    ('\u{1133E}', '\u{1133F}', SpacingMark),
+    ('\u{11340}', '\u{11340}', NonspacingMark),
+    ('\u{11341}', '\u{11344}', SpacingMark),
+    ('\u{11347}', '\u{11348}', SpacingMark),
+    ('\u{1134B}', '\u{1134D}', SpacingMark),
+    ('\u{11350}', '\u{11350}', OtherLetter),
+    ('\u{11357}', '\u{11357}', SpacingMark),
+    ('\u{1135D}', '\u{11361}', OtherLetter),
+    ('\u{11362}', '\u{11363}', SpacingMark),
+    ('\u{11366}', '\u{1136C}', NonspacingMark),
+    ('\u{11370}', '\u{11374}', NonspacingMark),
+    ('\u{11400}', '\u{11434}', OtherLetter),
+    ('\u{11435}', '\u{11437}', SpacingMark),
+    ('\u{11438}', '\u{1143F}', NonspacingMark),
+    ('\u{11440}', '\u{11441}', SpacingMark),
+    ('\u{11442}', '\u{11444}', NonspacingMark),
+    ('\u{11445}', '\u{11445}', SpacingMark),
+    ('\u{11446}', '\u{11446}', NonspacingMark),
+    ('\u{11447}', '\u{1144A}', OtherLetter),
+    ('\u{1144B}', '\u{1144F}', OtherPunctuation),
+    ('\u{11450}', '\u{11459}', DecimalNumber),
+    ('\u{1145A}', '\u{1145B}', OtherPunctuation),
+    ('\u{1145D}', '\u{1145D}', OtherPunctuation),
+    ('\u{1145E}', '\u{1145E}', NonspacingMark),
+    ('\u{1145F}', '\u{11461}', OtherLetter),
+    ('\u{11480}', '\u{114AF}', OtherLetter),
+    ('\u{114B0}', '\u{114B2}', SpacingMark),
+    ('\u{114B3}', '\u{114B8}', NonspacingMark),
+    ('\u{114B9}', '\u{114B9}', SpacingMark),
+    ('\u{114BA}', '\u{114BA}', NonspacingMark),
+    ('\u{114BB}', '\u{114BE}', SpacingMark),
+    ('\u{114BF}', '\u{114C0}', NonspacingMark),
+    ('\u{114C1}', '\u{114C1}', SpacingMark),
+    ('\u{114C2}', '\u{114C3}', NonspacingMark),
+    ('\u{114C4}', '\u{114C5}', OtherLetter),
+    ('\u{114C6}', '\u{114C6}', OtherPunctuation),
+    ('\u{114C7}', '\u{114C7}', OtherLetter),
+    ('\u{114D0}', '\u{114D9}', DecimalNumber),
+    ('\u{11580}', '\u{115AE}', OtherLetter),
+    ('\u{115AF}', '\u{115B1}', SpacingMark),
+    ('\u{115B2}', '\u{115B5}', NonspacingMark),
+    ('\u{115B8}', '\u{115BB}', SpacingMark),
+    ('\u{115BC}', '\u{115BD}', NonspacingMark),
+    ('\u{115BE}', '\u{115BE}', SpacingMark),
+    ('\u{115BF}', '\u{115C0}', NonspacingMark),
+    ('\u{115C1}', '\u{115D7}', OtherPunctuation),
+    ('\u{115D8}', '\u{115DB}', OtherLetter),
+    ('\u{115DC}', '\u{115DD}', NonspacingMark),
+    ('\u{11600}', '\u{1162F}', OtherLetter),
+    ('\u{11630}', '\u{11632}', SpacingMark),
+    ('\u{11633}', '\u{1163A}', NonspacingMark),
+    ('\u{1163B}', '\u{1163C}', SpacingMark),
+    ('\u{1163D}', '\u{1163D}', NonspacingMark),
+    ('\u{1163E}', '\u{1163E}', SpacingMark),
+    ('\u{1163F}', '\u{11640}', NonspacingMark),
+    ('\u{11641}', '\u{11643}', OtherPunctuation),
+    ('\u{11644}', '\u{11644}', OtherLetter),
+    ('\u{11650}', '\u{11659}', DecimalNumber),
+    ('\u{11660}', '\u{1166C}', OtherPunctuation),
+    ('\u{11680}', '\u{116AA}', OtherLetter),
+    ('\u{116AB}', '\u{116AB}', NonspacingMark),
+    ('\u{116AC}', '\u{116AC}', SpacingMark),
+    ('\u{116AD}', '\u{116AD}', NonspacingMark),
+    ('\u{116AE}', '\u{116AF}', SpacingMark),
+    ('\u{116B0}', '\u{116B5}', NonspacingMark),
+    ('\u{116B6}', '\u{116B6}', SpacingMark),
+    ('\u{116B7}', '\u{116B7}', NonspacingMark),
+    ('\u{116B8}', '\u{116B8}', OtherLetter),
+    ('\u{116B9}', '\u{116B9}', OtherPunctuation),
+    ('\u{116C0}', '\u{116C9}', DecimalNumber),
+    ('\u{11700}', '\u{1171A}', OtherLetter),
+    ('\u{1171D}', '\u{1171F}', NonspacingMark),
+    ('\u{11720}', '\u{11721}', SpacingMark),
+    ('\u{11722}', '\u{11725}', NonspacingMark),
+    ('\u{11726}', '\u{11726}', SpacingMark),
+    ('\u{11727}', '\u{1172B}', NonspacingMark),
+    ('\u{11730}', '\u{11739}', DecimalNumber),
+    ('\u{1173A}', '\u{1173B}', OtherNumber),
+    ('\u{1173C}', '\u{1173E}', OtherPunctuation),
+    ('\u{1173F}', '\u{1173F}', OtherSymbol),
+    ('\u{11740}', '\u{11746}', OtherLetter),
+    ('\u{11800}', '\u{1182B}', OtherLetter),
+    ('\u{1182C}', '\u{1182E}', SpacingMark),
+    ('\u{1182F}', '\u{11837}', NonspacingMark),
+    ('\u{11838}', '\u{11838}', SpacingMark),
+    ('\u{11839}', '\u{1183A}', NonspacingMark),
+    ('\u{1183B}', '\u{1183B}', OtherPunctuation),
+    ('\u{118A0}', '\u{118BF}', UppercaseLetter),
+    ('\u{118C0}', '\u{118DF}', LowercaseLetter),
+    ('\u{118E0}', '\u{118E9}', DecimalNumber),
+    ('\u{118EA}', '\u{118F2}', OtherNumber),
+    ('\u{118FF}', '\u{11906}', OtherLetter),
+    ('\u{11909}', '\u{11909}', OtherLetter),
+    ('\u{1190C}', '\u{11913}', OtherLetter),
+    ('\u{11915}', '\u{11916}', OtherLetter),
+    ('\u{11918}', '\u{1192F}', OtherLetter),
+    ('\u{11930}', '\u{11935}', SpacingMark),
+    ('\u{11937}', '\u{11938}', SpacingMark),
+    ('\u{1193B}', '\u{1193C}', NonspacingMark),
+    ('\u{1193D}', '\u{1193D}', SpacingMark),
+    ('\u{1193E}', '\u{1193E}', NonspacingMark),
+    ('\u{1193F}', '\u{1193F}', OtherLetter),
+    ('\u{11940}', '\u{11940}', SpacingMark),
+    ('\u{11941}', '\u{11941}', OtherLetter),
+    ('\u{11942}', '\u{11942}', SpacingMark),
+    ('\u{11943}', '\u{11943}', NonspacingMark),
+    ('\u{11944}', '\u{11946}', OtherPunctuation),
+    ('\u{11950}', '\u{11959}', DecimalNumber),
+    ('\u{119A0}', '\u{119A7}', OtherLetter),
+    ('\u{119AA}', '\u{119D0}', OtherLetter),
+    ('\u{119D1}', '\u{119D3}', SpacingMark),
+    ('\u{119D4}', '\u{119D7}', NonspacingMark),
+    ('\u{119DA}', '\u{119DB}', NonspacingMark),
+    ('\u{119DC}', '\u{119DF}', SpacingMark),
+    ('\u{119E0}', '\u{119E0}', NonspacingMark),
+    ('\u{119E1}', '\u{119E1}', OtherLetter),
+    ('\u{119E2}', '\u{119E2}', OtherPunctuation),
+    ('\u{119E3}', '\u{119E3}', OtherLetter),
+    ('\u{119E4}', '\u{119E4}', SpacingMark),
+    ('\u{11A00}', '\u{11A00}', OtherLetter),
+    ('\u{11A01}', '\u{11A0A}', NonspacingMark),
+    ('\u{11A0B}', '\u{11A32}', OtherLetter),
+    ('\u{11A33}', '\u{11A38}', NonspacingMark),
+    ('\u{11A39}', '\u{11A39}', SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', OtherLetter),
+    ('\u{11A3B}', '\u{11A3E}', NonspacingMark),
+    ('\u{11A3F}', '\u{11A46}', OtherPunctuation),
+    ('\u{11A47}', '\u{11A47}', NonspacingMark),
+    ('\u{11A50}', '\u{11A50}', OtherLetter),
+    ('\u{11A51}', '\u{11A56}', NonspacingMark),
+    ('\u{11A57}', '\u{11A58}', SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', NonspacingMark),
+    ('\u{11A5C}', '\u{11A89}', OtherLetter),
+    ('\u{11A8A}', '\u{11A96}', NonspacingMark),
+    ('\u{11A97}', '\u{11A97}', SpacingMark),
+    ('\u{11A98}', '\u{11A99}', NonspacingMark),
+    ('\u{11A9A}', '\u{11A9C}', OtherPunctuation),
+    ('\u{11A9D}', '\u{11A9D}', OtherLetter),
+    ('\u{11A9E}', '\u{11AA2}', OtherPunctuation),
+    ('\u{11AB0}', '\u{11AF8}', OtherLetter),
+    ('\u{11C00}', '\u{11C08}', OtherLetter),
+    ('\u{11C0A}', '\u{11C2E}', OtherLetter),
+    ('\u{11C2F}', '\u{11C2F}', SpacingMark),
+    ('\u{11C30}', '\u{11C36}', NonspacingMark),
+    ('\u{11C38}', '\u{11C3D}', NonspacingMark),
+    ('\u{11C3E}', '\u{11C3E}', SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', NonspacingMark),
+    ('\u{11C40}', '\u{11C40}', OtherLetter),
+    ('\u{11C41}', '\u{11C45}', OtherPunctuation),
+    ('\u{11C50}', '\u{11C59}', DecimalNumber),
+    ('\u{11C5A}', '\u{11C6C}', OtherNumber),
+    ('\u{11C70}', '\u{11C71}', OtherPunctuation),
+    ('\u{11C72}', '\u{11C8F}', OtherLetter),
+    ('\u{11C92}', '\u{11CA7}', NonspacingMark),
+    ('\u{11CA9}', '\u{11CA9}', SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', NonspacingMark),
+    ('\u{11CB1}', '\u{11CB1}', SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', NonspacingMark),
+    ('\u{11CB4}', '\u{11CB4}', SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', NonspacingMark),
+    ('\u{11D00}', '\u{11D06}', OtherLetter),
+    ('\u{11D08}', '\u{11D09}', OtherLetter),
+    ('\u{11D0B}', '\u{11D30}', OtherLetter),
+    ('\u{11D31}', '\u{11D36}', NonspacingMark),
+    ('\u{11D3A}', '\u{11D3A}', NonspacingMark),
+    ('\u{11D3C}', '\u{11D3D}', NonspacingMark),
+    ('\u{11D3F}', '\u{11D45}', NonspacingMark),
+    ('\u{11D46}', '\u{11D46}', OtherLetter),
+    ('\u{11D47}', '\u{11D47}', NonspacingMark),
+    ('\u{11D50}', '\u{11D59}', DecimalNumber),
+    ('\u{11D60}', '\u{11D65}', OtherLetter),
+    ('\u{11D67}', '\u{11D68}', OtherLetter),
+    ('\u{11D6A}', '\u{11D89}', OtherLetter),
+    ('\u{11D8A}', '\u{11D8E}', SpacingMark),
+    ('\u{11D90}', '\u{11D91}', NonspacingMark),
+    ('\u{11D93}', '\u{11D94}', SpacingMark),
+    ('\u{11D95}', '\u{11D95}', NonspacingMark),
+    ('\u{11D96}', '\u{11D96}', SpacingMark),
+    ('\u{11D97}', '\u{11D97}', NonspacingMark),
+    ('\u{11D98}', '\u{11D98}', OtherLetter),
+    ('\u{11DA0}', '\u{11DA9}', DecimalNumber),
+    ('\u{11EE0}', '\u{11EF2}', OtherLetter),
+    ('\u{11EF3}', '\u{11EF4}', NonspacingMark),
+    ('\u{11EF5}', '\u{11EF6}', SpacingMark),
+    ('\u{11EF7}', '\u{11EF8}', OtherPunctuation),
+    ('\u{11FB0}', '\u{11FB0}', OtherLetter),
+    ('\u{11FC0}', '\u{11FD4}', OtherNumber),
+    ('\u{11FD5}', '\u{11FDC}', OtherSymbol),
+    ('\u{11FDD}', '\u{11FE0}', CurrencySymbol),
+    ('\u{11FE1}', '\u{11FF1}', OtherSymbol),
+    ('\u{11FFF}', '\u{11FFF}', OtherPunctuation),
+    ('\u{12000}', '\u{12399}', OtherLetter),
+    ('\u{12400}', '\u{1246E}', LetterNumber),
+    ('\u{12470}', '\u{12474}', OtherPunctuation),
+    ('\u{12480}', '\u{12543}', OtherLetter),
+    ('\u{12F90}', '\u{12FF0}', OtherLetter),
+    ('\u{12FF1}', '\u{12FF2}', OtherPunctuation),
+    ('\u{13000}', '\u{1342E}', OtherLetter),
+    ('\u{13430}', '\u{13438}', Format),
+    ('\u{14400}', '\u{14646}', OtherLetter),
+    ('\u{16800}', '\u{16A38}', OtherLetter),
+    ('\u{16A40}', '\u{16A5E}', OtherLetter),
+    ('\u{16A60}', '\u{16A69}', DecimalNumber),
+    ('\u{16A6E}', '\u{16A6F}', OtherPunctuation),
+    ('\u{16A70}', '\u{16ABE}', OtherLetter),
+    ('\u{16AC0}', '\u{16AC9}', DecimalNumber),
+    ('\u{16AD0}', '\u{16AED}', OtherLetter),
+    ('\u{16AF0}', '\u{16AF4}', NonspacingMark),
+    ('\u{16AF5}', '\u{16AF5}', OtherPunctuation),
+    ('\u{16B00}', '\u{16B2F}', OtherLetter),
+    ('\u{16B30}', '\u{16B36}', NonspacingMark),
+    ('\u{16B37}', '\u{16B3B}', OtherPunctuation),
+    ('\u{16B3C}', '\u{16B3F}', OtherSymbol),
+    ('\u{16B40}', '\u{16B43}', ModifierLetter),
+    ('\u{16B44}', '\u{16B44}', OtherPunctuation),
+    ('\u{16B45}', '\u{16B45}', OtherSymbol),
+    ('\u{16B50}', '\u{16B59}', DecimalNumber),
+    ('\u{16B5B}', '\u{16B61}', OtherNumber),
+    ('\u{16B63}', '\u{16B77}', OtherLetter),
+    ('\u{16B7D}', '\u{16B8F}', OtherLetter),
+    ('\u{16E40}', '\u{16E5F}', UppercaseLetter),
+    ('\u{16E60}', '\u{16E7F}', LowercaseLetter),
+    ('\u{16E80}', '\u{16E96}', OtherNumber),
+    ('\u{16E97}', '\u{16E9A}', OtherPunctuation),
+    ('\u{16F00}', '\u{16F4A}', OtherLetter),
+    ('\u{16F4F}', '\u{16F4F}', NonspacingMark),
+    ('\u{16F50}', '\u{16F50}', OtherLetter),
+    ('\u{16F51}', '\u{16F87}', SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', NonspacingMark),
+    ('\u{16F93}', '\u{16F9F}', ModifierLetter),
+    ('\u{16FE0}', '\u{16FE1}', ModifierLetter),
+    ('\u{16FE2}', '\u{16FE2}', OtherPunctuation),
+    ('\u{16FE3}', '\u{16FE3}', ModifierLetter),
+    ('\u{16FE4}', '\u{16FE4}', NonspacingMark),
+    ('\u{16FF0}', '\u{16FF1}', SpacingMark),
+    ('\u{17000}', '\u{187F7}', OtherLetter),
+    ('\u{18800}', '\u{18CD5}', OtherLetter),
+    ('\u{18D00}', '\u{18D08}', OtherLetter),
+    ('\u{1AFF0}', '\u{1AFF3}', ModifierLetter),
+    ('\u{1AFF5}', '\u{1AFFB}', ModifierLetter),
+    ('\u{1AFFD}', '\u{1AFFE}', ModifierLetter),
+    ('\u{1B000}', '\u{1B122}', OtherLetter),
+    ('\u{1B150}', '\u{1B152}', OtherLetter),
+    ('\u{1B164}', '\u{1B167}', OtherLetter),
+    ('\u{1B170}', '\u{1B2FB}', OtherLetter),
+    ('\u{1BC00}', '\u{1BC6A}', OtherLetter),
+    ('\u{1BC70}', '\u{1BC7C}', OtherLetter),
+    ('\u{1BC80}', '\u{1BC88}', OtherLetter),
+    ('\u{1BC90}', '\u{1BC99}', OtherLetter),
+    ('\u{1BC9C}', '\u{1BC9C}', OtherSymbol),
+    ('\u{1BC9D}', '\u{1BC9E}', NonspacingMark),
+    ('\u{1BC9F}', '\u{1BC9F}', OtherPunctuation),
+    ('\u{1BCA0}', '\u{1BCA3}', Format),
+    ('\u{1CF00}', '\u{1CF2D}', NonspacingMark),
+    ('\u{1CF30}', '\u{1CF46}', NonspacingMark),
+    ('\u{1CF50}', '\u{1CFC3}', OtherSymbol),
+    ('\u{1D000}', '\u{1D0F5}', OtherSymbol),
+    ('\u{1D100}', '\u{1D126}', OtherSymbol),
+    ('\u{1D129}', '\u{1D164}', OtherSymbol),
+    ('\u{1D165}', '\u{1D166}', SpacingMark),
+    ('\u{1D167}', '\u{1D169}', NonspacingMark),
+    ('\u{1D16A}', '\u{1D16C}', OtherSymbol),
+    ('\u{1D16D}', '\u{1D172}', SpacingMark),
+    ('\u{1D173}', '\u{1D17A}', Format),
+    ('\u{1D17B}', '\u{1D182}', NonspacingMark),
+    ('\u{1D183}', '\u{1D184}', OtherSymbol),
+    ('\u{1D185}', '\u{1D18B}', NonspacingMark),
+    ('\u{1D18C}', '\u{1D1A9}', OtherSymbol),
+    ('\u{1D1AA}', '\u{1D1AD}', NonspacingMark),
+    ('\u{1D1AE}', '\u{1D1EA}', OtherSymbol),
+    ('\u{1D200}', '\u{1D241}', OtherSymbol),
+    ('\u{1D242}', '\u{1D244}', NonspacingMark),
+    ('\u{1D245}', '\u{1D245}', OtherSymbol),
+    ('\u{1D2E0}', '\u{1D2F3}', OtherNumber),
+    ('\u{1D300}', '\u{1D356}', OtherSymbol),
+    ('\u{1D360}', '\u{1D378}', OtherNumber),
+    ('\u{1D400}', '\u{1D419}', UppercaseLetter),
+    ('\u{1D41A}', '\u{1D433}', LowercaseLetter),
+    ('\u{1D434}', '\u{1D44D}', UppercaseLetter),
+    ('\u{1D44E}', '\u{1D454}', LowercaseLetter),
+    ('\u{1D456}', '\u{1D467}', LowercaseLetter),
+    ('\u{1D468}', '\u{1D481}', UppercaseLetter),
+    ('\u{1D482}', '\u{1D49B}', LowercaseLetter),
+    ('\u{1D49C}', '\u{1D49C}', UppercaseLetter),
+    ('\u{1D49E}', '\u{1D49F}', UppercaseLetter),
+    ('\u{1D4A2}', '\u{1D4A2}', UppercaseLetter),
+    ('\u{1D4A5}', '\u{1D4A6}', UppercaseLetter),
+    ('\u{1D4A9}', '\u{1D4AC}', UppercaseLetter),
+    ('\u{1D4AE}', '\u{1D4B5}', UppercaseLetter),
+    ('\u{1D4B6}', '\u{1D4B9}', LowercaseLetter),
+    ('\u{1D4BB}', '\u{1D4BB}', LowercaseLetter),
+    ('\u{1D4BD}', '\u{1D4C3}', LowercaseLetter),
+    ('\u{1D4C5}', '\u{1D4CF}', LowercaseLetter),
+    ('\u{1D4D0}', '\u{1D4E9}', UppercaseLetter),
+    ('\u{1D4EA}', '\u{1D503}', LowercaseLetter),
+    ('\u{1D504}', '\u{1D505}', UppercaseLetter),
+    ('\u{1D507}', '\u{1D50A}', UppercaseLetter),
+    ('\u{1D50D}', '\u{1D514}', UppercaseLetter),
+    ('\u{1D516}', '\u{1D51C}', UppercaseLetter),
+    ('\u{1D51E}', '\u{1D537}', LowercaseLetter),
+    ('\u{1D538}', '\u{1D539}', UppercaseLetter),
+    ('\u{1D53B}', '\u{1D53E}', UppercaseLetter),
+    ('\u{1D540}', '\u{1D544}', UppercaseLetter),
+    ('\u{1D546}', '\u{1D546}', UppercaseLetter),
+    ('\u{1D54A}', '\u{1D550}', UppercaseLetter),
+    ('\u{1D552}', '\u{1D56B}', LowercaseLetter),
+    ('\u{1D56C}', '\u{1D585}', UppercaseLetter),
+    ('\u{1D586}', '\u{1D59F}', LowercaseLetter),
+    ('\u{1D5A0}', '\u{1D5B9}', UppercaseLetter),
+    ('\u{1D5BA}', '\u{1D5D3}', LowercaseLetter),
+    ('\u{1D5D4}', '\u{1D5ED}', UppercaseLetter),
+    ('\u{1D5EE}', '\u{1D607}', LowercaseLetter),
+    ('\u{1D608}', '\u{1D621}', UppercaseLetter),
+    ('\u{1D622}', '\u{1D63B}', LowercaseLetter),
+    ('\u{1D63C}', '\u{1D655}', UppercaseLetter),
+    ('\u{1D656}', '\u{1D66F}', LowercaseLetter),
+    ('\u{1D670}', '\u{1D689}', UppercaseLetter),
+    ('\u{1D68A}', '\u{1D6A5}', LowercaseLetter),
+    ('\u{1D6A8}', '\u{1D6C0}', UppercaseLetter),
+    ('\u{1D6C1}', '\u{1D6C1}', MathSymbol),
+    ('\u{1D6C2}', '\u{1D6DA}', LowercaseLetter),
+    ('\u{1D6DB}', '\u{1D6DB}', MathSymbol),
+    ('\u{1D6DC}', '\u{1D6E1}', LowercaseLetter),
+    ('\u{1D6E2}', '\u{1D6FA}', UppercaseLetter),
+    ('\u{1D6FB}', '\u{1D6FB}', MathSymbol),
+    ('\u{1D6FC}', '\u{1D714}', LowercaseLetter),
+    ('\u{1D715}', '\u{1D715}', MathSymbol),
+    ('\u{1D716}', '\u{1D71B}', LowercaseLetter),
+    ('\u{1D71C}', '\u{1D734}', UppercaseLetter),
+    ('\u{1D735}', '\u{1D735}', MathSymbol),
+    ('\u{1D736}', '\u{1D74E}', LowercaseLetter),
+    ('\u{1D74F}', '\u{1D74F}', MathSymbol),
+    ('\u{1D750}', '\u{1D755}', LowercaseLetter),
+    ('\u{1D756}', '\u{1D76E}', UppercaseLetter),
+    ('\u{1D76F}', '\u{1D76F}', MathSymbol),
+    ('\u{1D770}', '\u{1D788}', LowercaseLetter),
+    ('\u{1D789}', '\u{1D789}', MathSymbol),
+    ('\u{1D78A}', '\u{1D78F}', LowercaseLetter),
+    ('\u{1D790}', '\u{1D7A8}', UppercaseLetter),
+    ('\u{1D7A9}', '\u{1D7A9}', MathSymbol),
+    ('\u{1D7AA}', '\u{1D7C2}', LowercaseLetter),
+    ('\u{1D7C3}', '\u{1D7C3}', MathSymbol),
+    ('\u{1D7C4}', '\u{1D7C9}', LowercaseLetter),
+    ('\u{1D7CA}', '\u{1D7CA}', UppercaseLetter),
+    ('\u{1D7CB}', '\u{1D7CB}', LowercaseLetter),
+    ('\u{1D7CE}', '\u{1D7FF}', DecimalNumber),
+    ('\u{1D800}', '\u{1D9FF}', OtherSymbol),
+    ('\u{1DA00}', '\u{1DA36}', NonspacingMark),
+    ('\u{1DA37}', '\u{1DA3A}', OtherSymbol),
+    ('\u{1DA3B}', '\u{1DA6C}', NonspacingMark),
+    ('\u{1DA6D}', '\u{1DA74}', OtherSymbol),
+    ('\u{1DA75}', '\u{1DA75}', NonspacingMark),
+    ('\u{1DA76}', '\u{1DA83}', OtherSymbol),
+    ('\u{1DA84}', '\u{1DA84}', NonspacingMark),
+    ('\u{1DA85}', '\u{1DA86}', OtherSymbol),
+    ('\u{1DA87}', '\u{1DA8B}', OtherPunctuation),
+    ('\u{1DA9B}', '\u{1DA9F}', NonspacingMark),
+    ('\u{1DAA1}', '\u{1DAAF}', NonspacingMark),
+    ('\u{1DF00}', '\u{1DF09}', LowercaseLetter),
+    ('\u{1DF0A}', '\u{1DF0A}', OtherLetter),
+    ('\u{1DF0B}', '\u{1DF1E}', LowercaseLetter),
+    ('\u{1E000}', '\u{1E006}', NonspacingMark),
+    ('\u{1E008}', '\u{1E018}', NonspacingMark),
+    ('\u{1E01B}', '\u{1E021}', NonspacingMark),
+    ('\u{1E023}', '\u{1E024}', NonspacingMark),
+    ('\u{1E026}', '\u{1E02A}', NonspacingMark),
+    ('\u{1E100}', '\u{1E12C}', OtherLetter),
+    ('\u{1E130}', '\u{1E136}', NonspacingMark),
+    ('\u{1E137}', '\u{1E13D}', ModifierLetter),
+    ('\u{1E140}', '\u{1E149}', DecimalNumber),
+    ('\u{1E14E}', '\u{1E14E}', OtherLetter),
+    ('\u{1E14F}', '\u{1E14F}', OtherSymbol),
+    ('\u{1E290}', '\u{1E2AD}', OtherLetter),
+    ('\u{1E2AE}', '\u{1E2AE}', NonspacingMark),
+    ('\u{1E2C0}', '\u{1E2EB}', OtherLetter),
+    ('\u{1E2EC}', '\u{1E2EF}', NonspacingMark),
+    ('\u{1E2F0}', '\u{1E2F9}', DecimalNumber),
+    ('\u{1E2FF}', '\u{1E2FF}', CurrencySymbol),
+    ('\u{1E7E0}', '\u{1E7E6}', OtherLetter),
+    ('\u{1E7E8}', '\u{1E7EB}', OtherLetter),
+    ('\u{1E7ED}', '\u{1E7EE}', OtherLetter),
+    ('\u{1E7F0}', '\u{1E7FE}', OtherLetter),
+    ('\u{1E800}', '\u{1E8C4}', OtherLetter),
+    ('\u{1E8C7}', '\u{1E8CF}', OtherNumber),
+    ('\u{1E8D0}', '\u{1E8D6}', NonspacingMark),
+    ('\u{1E900}', '\u{1E921}', UppercaseLetter),
+    ('\u{1E922}', '\u{1E943}', LowercaseLetter),
+    ('\u{1E944}', '\u{1E94A}', NonspacingMark),
+    ('\u{1E94B}', '\u{1E94B}', ModifierLetter),
+    ('\u{1E950}', '\u{1E959}', DecimalNumber),
+    ('\u{1E95E}', '\u{1E95F}', OtherPunctuation),
+    ('\u{1EC71}', '\u{1ECAB}', OtherNumber),
+    ('\u{1ECAC}', '\u{1ECAC}', OtherSymbol),
+    ('\u{1ECAD}', '\u{1ECAF}', OtherNumber),
+    ('\u{1ECB0}', '\u{1ECB0}', CurrencySymbol),
+    ('\u{1ECB1}', '\u{1ECB4}', OtherNumber),
+    ('\u{1ED01}', '\u{1ED2D}', OtherNumber),
+    ('\u{1ED2E}', '\u{1ED2E}', OtherSymbol),
+    ('\u{1ED2F}', '\u{1ED3D}', OtherNumber),
+    ('\u{1EE00}', '\u{1EE03}', OtherLetter),
+    ('\u{1EE05}', '\u{1EE1F}', OtherLetter),
+    ('\u{1EE21}', '\u{1EE22}', OtherLetter),
+    ('\u{1EE24}', '\u{1EE24}', OtherLetter),
+    ('\u{1EE27}', '\u{1EE27}', OtherLetter),
+    ('\u{1EE29}', '\u{1EE32}', OtherLetter),
+    ('\u{1EE34}', '\u{1EE37}', OtherLetter),
+    ('\u{1EE39}', '\u{1EE39}', OtherLetter),
+    ('\u{1EE3B}', '\u{1EE3B}', OtherLetter),
+    ('\u{1EE42}', '\u{1EE42}', OtherLetter),
+    ('\u{1EE47}', '\u{1EE47}', OtherLetter),
+    ('\u{1EE49}', '\u{1EE49}', OtherLetter),
+    ('\u{1EE4B}', '\u{1EE4B}', OtherLetter),
+    ('\u{1EE4D}', '\u{1EE4F}', OtherLetter),
+    ('\u{1EE51}', '\u{1EE52}', OtherLetter),
+    ('\u{1EE54}', '\u{1EE54}', OtherLetter),
+    ('\u{1EE57}', '\u{1EE57}', OtherLetter),
+    ('\u{1EE59}', '\u{1EE59}', OtherLetter),
+    ('\u{1EE5B}', '\u{1EE5B}', OtherLetter),
+    ('\u{1EE5D}', '\u{1EE5D}', OtherLetter),
+    ('\u{1EE5F}', '\u{1EE5F}', OtherLetter),
+    ('\u{1EE61}', '\u{1EE62}', OtherLetter),
+    ('\u{1EE64}', '\u{1EE64}', OtherLetter),
+    ('\u{1EE67}', '\u{1EE6A}', OtherLetter),
+    ('\u{1EE6C}', '\u{1EE72}', OtherLetter),
+    ('\u{1EE74}', '\u{1EE77}', OtherLetter),
+    ('\u{1EE79}', '\u{1EE7C}', OtherLetter),
+    ('\u{1EE7E}', '\u{1EE7E}', OtherLetter),
+    ('\u{1EE80}', '\u{1EE89}', OtherLetter),
+    ('\u{1EE8B}', '\u{1EE9B}', OtherLetter),
+    ('\u{1EEA1}', '\u{1EEA3}', OtherLetter),
+    ('\u{1EEA5}', '\u{1EEA9}', OtherLetter),
+    ('\u{1EEAB}', '\u{1EEBB}', OtherLetter),
+    ('\u{1EEF0}', '\u{1EEF1}', MathSymbol),
+    ('\u{1F000}', '\u{1F02B}', OtherSymbol),
+    ('\u{1F030}', '\u{1F093}', OtherSymbol),
+    ('\u{1F0A0}', '\u{1F0AE}', OtherSymbol),
+    ('\u{1F0B1}', '\u{1F0BF}', OtherSymbol),
+    ('\u{1F0C1}', '\u{1F0CF}', OtherSymbol),
+    ('\u{1F0D1}', '\u{1F0F5}', OtherSymbol),
+    ('\u{1F100}', '\u{1F10C}', OtherNumber),
+    ('\u{1F10D}', '\u{1F1AD}', OtherSymbol),
+    ('\u{1F1E6}', '\u{1F202}', OtherSymbol),
+    ('\u{1F210}', '\u{1F23B}', OtherSymbol),
+    ('\u{1F240}', '\u{1F248}', OtherSymbol),
+    ('\u{1F250}', '\u{1F251}', OtherSymbol),
+    ('\u{1F260}', '\u{1F265}', OtherSymbol),
+    ('\u{1F300}', '\u{1F3FA}', OtherSymbol),
+    ('\u{1F3FB}', '\u{1F3FF}', ModifierSymbol),
+    ('\u{1F400}', '\u{1F6D7}', OtherSymbol),
+    ('\u{1F6DD}', '\u{1F6EC}', OtherSymbol),
+    ('\u{1F6F0}', '\u{1F6FC}', OtherSymbol),
+    ('\u{1F700}', '\u{1F773}', OtherSymbol),
+    ('\u{1F780}', '\u{1F7D8}', OtherSymbol),
+    ('\u{1F7E0}', '\u{1F7EB}', OtherSymbol),
+    ('\u{1F7F0}', '\u{1F7F0}', OtherSymbol),
+    ('\u{1F800}', '\u{1F80B}', OtherSymbol),
+    ('\u{1F810}', '\u{1F847}', OtherSymbol),
+    ('\u{1F850}', '\u{1F859}', OtherSymbol),
+    ('\u{1F860}', '\u{1F887}', OtherSymbol),
+    ('\u{1F890}', '\u{1F8AD}', OtherSymbol),
+    ('\u{1F8B0}', '\u{1F8B1}', OtherSymbol),
+    ('\u{1F900}', '\u{1FA53}', OtherSymbol),
+    ('\u{1FA60}', '\u{1FA6D}', OtherSymbol),
+    ('\u{1FA70}', '\u{1FA74}', OtherSymbol),
+    ('\u{1FA78}', '\u{1FA7C}', OtherSymbol),
+    ('\u{1FA80}', '\u{1FA86}', OtherSymbol),
+    ('\u{1FA90}', '\u{1FAAC}', OtherSymbol),
+    ('\u{1FAB0}', '\u{1FABA}', OtherSymbol),
+    ('\u{1FAC0}', '\u{1FAC5}', OtherSymbol),
+    ('\u{1FAD0}', '\u{1FAD9}', OtherSymbol),
+    ('\u{1FAE0}', '\u{1FAE7}', OtherSymbol),
+    ('\u{1FAF0}', '\u{1FAF6}', OtherSymbol),
+    ('\u{1FB00}', '\u{1FB92}', OtherSymbol),
+    ('\u{1FB94}', '\u{1FBCA}', OtherSymbol),
+    ('\u{1FBF0}', '\u{1FBF9}', DecimalNumber),
+    ('\u{20000}', '\u{2A6DF}', OtherLetter),
+    ('\u{2A700}', '\u{2B738}', OtherLetter),
+    ('\u{2B740}', '\u{2B81D}', OtherLetter),
+    ('\u{2B820}', '\u{2CEA1}', OtherLetter),
+    ('\u{2CEB0}', '\u{2EBE0}', OtherLetter),
+    ('\u{2F800}', '\u{2FA1D}', OtherLetter),
+    ('\u{30000}', '\u{3134A}', OtherLetter),
+    ('\u{E0001}', '\u{E0001}', Format),
+    ('\u{E0020}', '\u{E007F}', Format),
+    ('\u{E0100}', '\u{E01EF}', NonspacingMark),
+    ('\u{F0000}', '\u{FFFFD}', PrivateUse),
+    ('\u{100000}', '\u{10FFFD}', PrivateUse),
+];
+
+pub(super) static SCRIPT: &[(char, char, Script)] = &[
+    ('\u{0000}', '\u{0040}', Common),
+    ('\u{0041}', '\u{005A}', Latin),
+    ('\u{005B}', '\u{0060}', Common),
+    ('\u{0061}', '\u{007A}', Latin),
+    ('\u{007B}', '\u{00A9}', Common),
+    ('\u{00AA}', '\u{00AA}', Latin),
+    ('\u{00AB}', '\u{00B9}', Common),
+    ('\u{00BA}', '\u{00BA}', Latin),
+    ('\u{00BB}', '\u{00BF}', Common),
+    ('\u{00C0}', '\u{00D6}', Latin),
+    ('\u{00D7}', '\u{00D7}', Common),
+    ('\u{00D8}', '\u{00F6}', Latin),
+    ('\u{00F7}', '\u{00F7}', Common),
+    ('\u{00F8}', '\u{02B8}', Latin),
+    ('\u{02B9}', '\u{02DF}', Common),
+    ('\u{02E0}', '\u{02E4}', Latin),
+    ('\u{02E5}', '\u{02E9}', Common),
+    ('\u{02EA}', '\u{02EB}', Bopomofo),
+    ('\u{02EC}', '\u{02FF}', Common),
+    ('\u{0300}', '\u{036F}', Inherited),
+    ('\u{0370}', '\u{0373}', Greek),
+    ('\u{0374}', '\u{0374}', Common),
+    ('\u{0375}', '\u{0377}', Greek),
+    ('\u{037A}', '\u{037D}', Greek),
+    ('\u{037E}', '\u{037E}', Common),
+    ('\u{037F}', '\u{037F}', Greek),
+    ('\u{0384}', '\u{0384}', Greek),
+    ('\u{0385}', '\u{0385}', Common),
+    ('\u{0386}', '\u{0386}', Greek),
+    ('\u{0387}', '\u{0387}', Common),
+    ('\u{0388}', '\u{038A}', Greek),
+    ('\u{038C}', '\u{038C}', Greek),
+    ('\u{038E}', '\u{03A1}', Greek),
+    ('\u{03A3}', '\u{03E1}', Greek),
+    ('\u{03E2}', '\u{03EF}', Coptic),
+    ('\u{03F0}', '\u{03FF}', Greek),
+    ('\u{0400}', '\u{0484}', Cyrillic),
+    ('\u{0485}', '\u{0486}', Inherited),
+    ('\u{0487}', '\u{052F}', Cyrillic),
+    ('\u{0531}', '\u{0556}', Armenian),
+    ('\u{0559}', '\u{058A}', Armenian),
+    ('\u{058D}', '\u{058F}', Armenian),
+    ('\u{0591}', '\u{05C7}', Hebrew),
+    ('\u{05D0}', '\u{05EA}', Hebrew),
+    ('\u{05EF}', '\u{05F4}', Hebrew),
+    ('\u{0600}', '\u{0604}', Arabic),
+    ('\u{0605}', '\u{0605}', Common),
+    ('\u{0606}', '\u{060B}', Arabic),
+    ('\u{060C}', '\u{060C}', Common),
+    ('\u{060D}', '\u{061A}', Arabic),
+    ('\u{061B}', '\u{061B}', Common),
+    ('\u{061C}', '\u{061E}', Arabic),
+    ('\u{061F}', '\u{061F}', Common),
+    ('\u{0620}', '\u{063F}', Arabic),
+    ('\u{0640}', '\u{0640}', Common),
+    ('\u{0641}', '\u{064A}', Arabic),
+    ('\u{064B}', '\u{0655}', Inherited),
+    ('\u{0656}', '\u{066F}', Arabic),
+    ('\u{0670}', '\u{0670}', Inherited),
+    ('\u{0671}', '\u{06DC}', Arabic),
+    ('\u{06DD}', '\u{06DD}', Common),
+    ('\u{06DE}', '\u{06FF}', Arabic),
+    ('\u{0700}', '\u{070D}', Syriac),
+    ('\u{070F}', '\u{074A}', Syriac),
+    ('\u{074D}', '\u{074F}', Syriac),
+    ('\u{0750}', '\u{077F}', Arabic),
+    ('\u{0780}', '\u{07B1}', Thaana),
+    ('\u{07C0}', '\u{07FA}', Nko),
+    ('\u{07FD}', '\u{07FF}', Nko),
+    ('\u{0800}', '\u{082D}', Samaritan),
+    ('\u{0830}', '\u{083E}', Samaritan),
+    ('\u{0840}', '\u{085B}', Mandaic),
+    ('\u{085E}', '\u{085E}', Mandaic),
+    ('\u{0860}', '\u{086A}', Syriac),
+    ('\u{0870}', '\u{088E}', Arabic),
+    ('\u{0890}', '\u{0891}', Arabic),
+    ('\u{0898}', '\u{08E1}', Arabic),
+    ('\u{08E2}', '\u{08E2}', Common),
+    ('\u{08E3}', '\u{08FF}', Arabic),
+    ('\u{0900}', '\u{0950}', Devanagari),
+    ('\u{0951}', '\u{0954}', Inherited),
+    ('\u{0955}', '\u{0963}', Devanagari),
+    ('\u{0964}', '\u{0965}', Common),
+    ('\u{0966}', '\u{097F}', Devanagari),
+    ('\u{0980}', '\u{0983}', Bengali),
+    ('\u{0985}', '\u{098C}', Bengali),
+    ('\u{098F}', '\u{0990}', Bengali),
+    ('\u{0993}', '\u{09A8}', Bengali),
+    ('\u{09AA}', '\u{09B0}', Bengali),
+    ('\u{09B2}', '\u{09B2}', Bengali),
+    ('\u{09B6}', '\u{09B9}', Bengali),
+    ('\u{09BC}', '\u{09C4}', Bengali),
+    ('\u{09C7}', '\u{09C8}', Bengali),
+    ('\u{09CB}', '\u{09CE}', Bengali),
+    ('\u{09D7}', '\u{09D7}', Bengali),
+    ('\u{09DC}', '\u{09DD}', Bengali),
+    ('\u{09DF}', '\u{09E3}', Bengali),
+    ('\u{09E6}', '\u{09FE}', Bengali),
+    ('\u{0A01}', '\u{0A03}', Gurmukhi),
+    ('\u{0A05}', '\u{0A0A}', Gurmukhi),
+    ('\u{0A0F}', '\u{0A10}', Gurmukhi),
+    ('\u{0A13}', '\u{0A28}', Gurmukhi),
+    ('\u{0A2A}', '\u{0A30}', Gurmukhi),
+    ('\u{0A32}', '\u{0A33}', Gurmukhi),
+    ('\u{0A35}', '\u{0A36}', Gurmukhi),
+    ('\u{0A38}', '\u{0A39}', Gurmukhi),
+    ('\u{0A3C}', '\u{0A3C}', Gurmukhi),
+    ('\u{0A3E}', '\u{0A42}', Gurmukhi),
+    ('\u{0A47}', '\u{0A48}', Gurmukhi),
+    ('\u{0A4B}', '\u{0A4D}', Gurmukhi),
+    ('\u{0A51}', '\u{0A51}', Gurmukhi),
+    ('\u{0A59}', '\u{0A5C}', Gurmukhi),
+    ('\u{0A5E}', '\u{0A5E}', Gurmukhi),
+    ('\u{0A66}', '\u{0A76}', Gurmukhi),
+    ('\u{0A81}', '\u{0A83}', Gujarati),
+    ('\u{0A85}', '\u{0A8D}', Gujarati),
+    ('\u{0A8F}', '\u{0A91}', Gujarati),
+    ('\u{0A93}', '\u{0AA8}', Gujarati),
+    ('\u{0AAA}', '\u{0AB0}', Gujarati),
+    ('\u{0AB2}', '\u{0AB3}', Gujarati),
+    ('\u{0AB5}', '\u{0AB9}', Gujarati),
+    ('\u{0ABC}', '\u{0AC5}', Gujarati),
+    ('\u{0AC7}', '\u{0AC9}', Gujarati),
+    ('\u{0ACB}', '\u{0ACD}', Gujarati),
+    ('\u{0AD0}', '\u{0AD0}', Gujarati),
+    ('\u{0AE0}', '\u{0AE3}', Gujarati),
+    ('\u{0AE6}', '\u{0AF1}', Gujarati),
+    ('\u{0AF9}', '\u{0AFF}', Gujarati),
+    ('\u{0B01}', '\u{0B03}', Oriya),
+    ('\u{0B05}', '\u{0B0C}', Oriya),
+    ('\u{0B0F}', '\u{0B10}', Oriya),
+    ('\u{0B13}', '\u{0B28}', Oriya),
+    ('\u{0B2A}', '\u{0B30}', Oriya),
+    ('\u{0B32}', '\u{0B33}', Oriya),
+    ('\u{0B35}', '\u{0B39}', Oriya),
+    ('\u{0B3C}', '\u{0B44}', Oriya),
+    ('\u{0B47}', '\u{0B48}', Oriya),
+    ('\u{0B4B}', '\u{0B4D}', Oriya),
+    ('\u{0B55}', '\u{0B57}', Oriya),
+    ('\u{0B5C}', '\u{0B5D}', Oriya),
+    ('\u{0B5F}', '\u{0B63}', Oriya),
+    ('\u{0B66}', '\u{0B77}', Oriya),
+    ('\u{0B82}', '\u{0B83}', Tamil),
+    ('\u{0B85}', '\u{0B8A}', Tamil),
+    ('\u{0B8E}', '\u{0B90}', Tamil),
+    ('\u{0B92}', '\u{0B95}', Tamil),
+    ('\u{0B99}', '\u{0B9A}', Tamil),
+    ('\u{0B9C}', '\u{0B9C}', Tamil),
+    ('\u{0B9E}', '\u{0B9F}', Tamil),
+    ('\u{0BA3}', '\u{0BA4}', Tamil),
+    ('\u{0BA8}', '\u{0BAA}', Tamil),
+    ('\u{0BAE}', '\u{0BB9}', Tamil),
+    ('\u{0BBE}', '\u{0BC2}', Tamil),
+    ('\u{0BC6}', '\u{0BC8}', Tamil),
+    ('\u{0BCA}', '\u{0BCD}', Tamil),
+    ('\u{0BD0}', '\u{0BD0}', Tamil),
+    ('\u{0BD7}', '\u{0BD7}', Tamil),
+    ('\u{0BE6}', '\u{0BFA}', Tamil),
+    ('\u{0C00}', '\u{0C0C}', Telugu),
+    ('\u{0C0E}', '\u{0C10}', Telugu),
+    ('\u{0C12}', '\u{0C28}', Telugu),
+    ('\u{0C2A}', '\u{0C39}', Telugu),
+    ('\u{0C3C}', '\u{0C44}', Telugu),
+    ('\u{0C46}', '\u{0C48}', Telugu),
+    ('\u{0C4A}', '\u{0C4D}', Telugu),
+    ('\u{0C55}', '\u{0C56}', Telugu),
+    ('\u{0C58}', '\u{0C5A}', Telugu),
+    ('\u{0C5D}', '\u{0C5D}', Telugu),
+    ('\u{0C60}', '\u{0C63}', Telugu),
+    ('\u{0C66}', '\u{0C6F}', Telugu),
+    ('\u{0C77}', '\u{0C7F}', Telugu),
+    ('\u{0C80}', '\u{0C8C}', Kannada),
+    ('\u{0C8E}', '\u{0C90}', Kannada),
+    ('\u{0C92}', '\u{0CA8}', Kannada),
+    ('\u{0CAA}', '\u{0CB3}', Kannada),
+    ('\u{0CB5}', '\u{0CB9}', Kannada),
+    ('\u{0CBC}', '\u{0CC4}', Kannada),
+    ('\u{0CC6}', '\u{0CC8}', Kannada),
+    ('\u{0CCA}', '\u{0CCD}', Kannada),
+    ('\u{0CD5}', '\u{0CD6}', Kannada),
+    ('\u{0CDD}', '\u{0CDE}', Kannada),
+    ('\u{0CE0}', '\u{0CE3}', Kannada),
+    ('\u{0CE6}', '\u{0CEF}', Kannada),
+    ('\u{0CF1}', '\u{0CF2}', Kannada),
+    ('\u{0D00}', '\u{0D0C}', Malayalam),
+    ('\u{0D0E}', '\u{0D10}', Malayalam),
+    ('\u{0D12}', '\u{0D44}', Malayalam),
+    ('\u{0D46}', '\u{0D48}', Malayalam),
+    ('\u{0D4A}', '\u{0D4F}', Malayalam),
+    ('\u{0D54}', '\u{0D63}', Malayalam),
+    ('\u{0D66}', '\u{0D7F}', Malayalam),
+    ('\u{0D81}', '\u{0D83}', Sinhala),
+    ('\u{0D85}', '\u{0D96}', Sinhala),
+    ('\u{0D9A}', '\u{0DB1}', Sinhala),
+    ('\u{0DB3}', '\u{0DBB}', Sinhala),
+    ('\u{0DBD}', '\u{0DBD}', Sinhala),
+    ('\u{0DC0}', '\u{0DC6}', Sinhala),
+    ('\u{0DCA}', '\u{0DCA}', Sinhala),
+    ('\u{0DCF}', '\u{0DD4}', Sinhala),
+    ('\u{0DD6}', '\u{0DD6}', Sinhala),
+    ('\u{0DD8}', '\u{0DDF}', Sinhala),
+    ('\u{0DE6}', '\u{0DEF}', Sinhala),
+    ('\u{0DF2}', '\u{0DF4}', Sinhala),
+    ('\u{0E01}', '\u{0E3A}', Thai),
+    ('\u{0E3F}', '\u{0E3F}', Common),
+    ('\u{0E40}', '\u{0E5B}', Thai),
+    ('\u{0E81}', '\u{0E82}', Lao),
+    ('\u{0E84}', '\u{0E84}', Lao),
+    ('\u{0E86}', '\u{0E8A}', Lao),
+    ('\u{0E8C}', '\u{0EA3}', Lao),
+    ('\u{0EA5}', '\u{0EA5}', Lao),
+    ('\u{0EA7}', '\u{0EBD}', Lao),
+    ('\u{0EC0}', '\u{0EC4}', Lao),
+    ('\u{0EC6}', '\u{0EC6}', Lao),
+    ('\u{0EC8}', '\u{0ECD}', Lao),
+    ('\u{0ED0}', '\u{0ED9}', Lao),
+    ('\u{0EDC}', '\u{0EDF}', Lao),
+    ('\u{0F00}', '\u{0F47}', Tibetan),
+    ('\u{0F49}', '\u{0F6C}', Tibetan),
+    ('\u{0F71}', '\u{0F97}', Tibetan),
+    ('\u{0F99}', '\u{0FBC}', Tibetan),
+    ('\u{0FBE}', '\u{0FCC}', Tibetan),
+    ('\u{0FCE}', '\u{0FD4}', Tibetan),
+    ('\u{0FD5}', '\u{0FD8}', Common),
+    ('\u{0FD9}', '\u{0FDA}', Tibetan),
+    ('\u{1000}', '\u{109F}', Myanmar),
+    ('\u{10A0}', '\u{10C5}', Georgian),
+    ('\u{10C7}', '\u{10C7}', Georgian),
+    ('\u{10CD}', '\u{10CD}', Georgian),
+    ('\u{10D0}', '\u{10FA}', Georgian),
+    ('\u{10FB}', '\u{10FB}', Common),
+    ('\u{10FC}', '\u{10FF}', Georgian),
+    ('\u{1100}', '\u{11FF}', Hangul),
+    ('\u{1200}', '\u{1248}', Ethiopic),
+    ('\u{124A}', '\u{124D}', Ethiopic),
+    ('\u{1250}', '\u{1256}', Ethiopic),
+    ('\u{1258}', '\u{1258}', Ethiopic),
+    ('\u{125A}', '\u{125D}', Ethiopic),
+    ('\u{1260}', '\u{1288}', Ethiopic),
+    ('\u{128A}', '\u{128D}', Ethiopic),
+    ('\u{1290}', '\u{12B0}', Ethiopic),
+    ('\u{12B2}', '\u{12B5}', Ethiopic),
+    ('\u{12B8}', '\u{12BE}', Ethiopic),
+    ('\u{12C0}', '\u{12C0}', Ethiopic),
+    ('\u{12C2}', '\u{12C5}', Ethiopic),
+    ('\u{12C8}', '\u{12D6}', Ethiopic),
+    ('\u{12D8}', '\u{1310}', Ethiopic),
+    ('\u{1312}', '\u{1315}', Ethiopic),
+    ('\u{1318}', '\u{135A}', Ethiopic),
+    ('\u{135D}', '\u{137C}', Ethiopic),
+    ('\u{1380}', '\u{1399}', Ethiopic),
+    ('\u{13A0}', '\u{13F5}', Cherokee),
+    ('\u{13F8}', '\u{13FD}', Cherokee),
+    ('\u{1400}', '\u{167F}', CanadianAboriginal),
+    ('\u{1680}', '\u{169C}', Ogham),
+    ('\u{16A0}', '\u{16EA}', Runic),
+    ('\u{16EB}', '\u{16ED}', Common),
+    ('\u{16EE}', '\u{16F8}', Runic),
+    ('\u{1700}', '\u{1715}', Tagalog),
+    ('\u{171F}', '\u{171F}', Tagalog),
+    ('\u{1720}', '\u{1734}', Hanunoo),
+    ('\u{1735}', '\u{1736}', Common),
+    ('\u{1740}', '\u{1753}', Buhid),
+    ('\u{1760}', '\u{176C}', Tagbanwa),
+    ('\u{176E}', '\u{1770}', Tagbanwa),
+    ('\u{1772}', '\u{1773}', Tagbanwa),
+    ('\u{1780}', '\u{17DD}', Khmer),
+    ('\u{17E0}', '\u{17E9}', Khmer),
+    ('\u{17F0}', '\u{17F9}', Khmer),
+    ('\u{1800}', '\u{1801}', Mongolian),
+    ('\u{1802}', '\u{1803}', Common),
+    ('\u{1804}', '\u{1804}', Mongolian),
+    ('\u{1805}', '\u{1805}', Common),
+    ('\u{1806}', '\u{1819}', Mongolian),
+    ('\u{1820}', '\u{1878}', Mongolian),
+    ('\u{1880}', '\u{18AA}', Mongolian),
+    ('\u{18B0}', '\u{18F5}', CanadianAboriginal),
+    ('\u{1900}', '\u{191E}', Limbu),
+    ('\u{1920}', '\u{192B}', Limbu),
+    ('\u{1930}', '\u{193B}', Limbu),
+    ('\u{1940}', '\u{1940}', Limbu),
+    ('\u{1944}', '\u{194F}', Limbu),
+    ('\u{1950}', '\u{196D}', TaiLe),
+    ('\u{1970}', '\u{1974}', TaiLe),
+    ('\u{1980}', '\u{19AB}', NewTaiLue),
+    ('\u{19B0}', '\u{19C9}', NewTaiLue),
+    ('\u{19D0}', '\u{19DA}', NewTaiLue),
+    ('\u{19DE}', '\u{19DF}', NewTaiLue),
+    ('\u{19E0}', '\u{19FF}', Khmer),
+    ('\u{1A00}', '\u{1A1B}', Buginese),
+    ('\u{1A1E}', '\u{1A1F}', Buginese),
+    ('\u{1A20}', '\u{1A5E}', TaiTham),
+    ('\u{1A60}', '\u{1A7C}', TaiTham),
+    ('\u{1A7F}', '\u{1A89}', TaiTham),
+    ('\u{1A90}', '\u{1A99}', TaiTham),
+    ('\u{1AA0}', '\u{1AAD}', TaiTham),
+    ('\u{1AB0}', '\u{1ACE}', Inherited),
+    ('\u{1B00}', '\u{1B4C}', Balinese),
+    ('\u{1B50}', '\u{1B7E}', Balinese),
+    ('\u{1B80}', '\u{1BBF}', Sundanese),
+    ('\u{1BC0}', '\u{1BF3}', Batak),
+    ('\u{1BFC}', '\u{1BFF}', Batak),
+    ('\u{1C00}', '\u{1C37}', Lepcha),
+    ('\u{1C3B}', '\u{1C49}', Lepcha),
+    ('\u{1C4D}', '\u{1C4F}', Lepcha),
+    ('\u{1C50}', '\u{1C7F}', OlChiki),
+    ('\u{1C80}', '\u{1C88}', Cyrillic),
+    ('\u{1C90}', '\u{1CBA}', Georgian),
+    ('\u{1CBD}', '\u{1CBF}', Georgian),
+    ('\u{1CC0}', '\u{1CC7}', Sundanese),
+    ('\u{1CD0}', '\u{1CD2}', Inherited),
+    ('\u{1CD3}', '\u{1CD3}', Common),
+    ('\u{1CD4}', '\u{1CE0}', Inherited),
+    ('\u{1CE1}', '\u{1CE1}', Common),
+    ('\u{1CE2}', '\u{1CE8}', Inherited),
+    ('\u{1CE9}', '\u{1CEC}', Common),
+    ('\u{1CED}', '\u{1CED}', Inherited),
+    ('\u{1CEE}', '\u{1CF3}', Common),
+    ('\u{1CF4}', '\u{1CF4}', Inherited),
+    ('\u{1CF5}', '\u{1CF7}', Common),
+    ('\u{1CF8}', '\u{1CF9}', Inherited),
+    ('\u{1CFA}', '\u{1CFA}', Common),
+    ('\u{1D00}', '\u{1D25}', Latin),
+    ('\u{1D26}', '\u{1D2A}', Greek),
+    ('\u{1D2B}', '\u{1D2B}', Cyrillic),
+    ('\u{1D2C}', '\u{1D5C}', Latin),
+    ('\u{1D5D}', '\u{1D61}', Greek),
+    ('\u{1D62}', '\u{1D65}', Latin),
+    ('\u{1D66}', '\u{1D6A}', Greek),
+    ('\u{1D6B}', '\u{1D77}', Latin),
+    ('\u{1D78}', '\u{1D78}', Cyrillic),
+    ('\u{1D79}', '\u{1DBE}', Latin),
+    ('\u{1DBF}', '\u{1DBF}', Greek),
+    ('\u{1DC0}', '\u{1DFF}', Inherited),
+    ('\u{1E00}', '\u{1EFF}', Latin),
+    ('\u{1F00}', '\u{1F15}', Greek),
+    ('\u{1F18}', '\u{1F1D}', Greek),
+    ('\u{1F20}', '\u{1F45}', Greek),
+    ('\u{1F48}', '\u{1F4D}', Greek),
+    ('\u{1F50}', '\u{1F57}', Greek),
+    ('\u{1F59}', '\u{1F59}', Greek),
+    ('\u{1F5B}', '\u{1F5B}', Greek),
+    ('\u{1F5D}', '\u{1F5D}', Greek),
+    ('\u{1F5F}', '\u{1F7D}', Greek),
+    ('\u{1F80}', '\u{1FB4}', Greek),
+    ('\u{1FB6}', '\u{1FC4}', Greek),
+    ('\u{1FC6}', '\u{1FD3}', Greek),
+    ('\u{1FD6}', '\u{1FDB}', Greek),
+    ('\u{1FDD}', '\u{1FEF}', Greek),
+    ('\u{1FF2}', '\u{1FF4}', Greek),
+    ('\u{1FF6}', '\u{1FFE}', Greek),
+    ('\u{2000}', '\u{200B}', Common),
+    ('\u{200C}', '\u{200D}', Inherited),
+    ('\u{200E}', '\u{2064}', Common),
+    ('\u{2066}', '\u{2070}', Common),
+    ('\u{2071}', '\u{2071}', Latin),
+    ('\u{2074}', '\u{207E}', Common),
+    ('\u{207F}', '\u{207F}', Latin),
+    ('\u{2080}', '\u{208E}', Common),
+    ('\u{2090}', '\u{209C}', Latin),
+    ('\u{20A0}', '\u{20C0}', Common),
+    ('\u{20D0}', '\u{20F0}', Inherited),
+    ('\u{2100}', '\u{2125}', Common),
+    ('\u{2126}', '\u{2126}', Greek),
+    ('\u{2127}', '\u{2129}', Common),
+    ('\u{212A}', '\u{212B}', Latin),
+    ('\u{212C}', '\u{2131}', Common),
+    ('\u{2132}', '\u{2132}', Latin),
+    ('\u{2133}', '\u{214D}', Common),
+    ('\u{214E}', '\u{214E}', Latin),
+    ('\u{214F}', '\u{215F}', Common),
+    ('\u{2160}', '\u{2188}', Latin),
+    ('\u{2189}', '\u{218B}', Common),
+    ('\u{2190}', '\u{2426}', Common),
+    ('\u{2440}', '\u{244A}', Common),
+    ('\u{2460}', '\u{27FF}', Common),
+    ('\u{2800}', '\u{28FF}', Braille),
+    ('\u{2900}', '\u{2B73}', Common),
+    ('\u{2B76}', '\u{2B95}', Common),
+    ('\u{2B97}', '\u{2BFF}', Common),
+    ('\u{2C00}', '\u{2C5F}', Glagolitic),
+    ('\u{2C60}', '\u{2C7F}', Latin),
+    ('\u{2C80}', '\u{2CF3}', Coptic),
+    ('\u{2CF9}', '\u{2CFF}', Coptic),
+    ('\u{2D00}', '\u{2D25}', Georgian),
+    ('\u{2D27}', '\u{2D27}', Georgian),
+    ('\u{2D2D}', '\u{2D2D}', Georgian),
+    ('\u{2D30}', '\u{2D67}', Tifinagh),
+    ('\u{2D6F}', '\u{2D70}', Tifinagh),
+    ('\u{2D7F}', '\u{2D7F}', Tifinagh),
+    ('\u{2D80}', '\u{2D96}', Ethiopic),
+    ('\u{2DA0}', '\u{2DA6}', Ethiopic),
+    ('\u{2DA8}', '\u{2DAE}', Ethiopic),
+    ('\u{2DB0}', '\u{2DB6}', Ethiopic),
+    ('\u{2DB8}', '\u{2DBE}', Ethiopic),
+    ('\u{2DC0}', '\u{2DC6}', Ethiopic),
+    ('\u{2DC8}', '\u{2DCE}', Ethiopic),
+    ('\u{2DD0}', '\u{2DD6}', Ethiopic),
+    ('\u{2DD8}', '\u{2DDE}', Ethiopic),
+    ('\u{2DE0}', '\u{2DFF}', Cyrillic),
+    ('\u{2E00}', '\u{2E5D}', Common),
+    ('\u{2E80}', '\u{2E99}', Han),
+    ('\u{2E9B}', '\u{2EF3}', Han),
+    ('\u{2F00}', '\u{2FD5}', Han),
+    ('\u{2FF0}', '\u{2FFB}', Common),
+    ('\u{3000}', '\u{3004}', Common),
+    ('\u{3005}', '\u{3005}', Han),
+    ('\u{3006}', '\u{3006}', Common),
+    ('\u{3007}', '\u{3007}', Han),
+    ('\u{3008}', '\u{3020}', Common),
+    ('\u{3021}', '\u{3029}', Han),
+    ('\u{302A}', '\u{302D}', Inherited),
+    ('\u{302E}', '\u{302F}', Hangul),
+    ('\u{3030}', '\u{3037}', Common),
+    ('\u{3038}', '\u{303B}', Han),
+    ('\u{303C}', '\u{303F}', Common),
+    ('\u{3041}', '\u{3096}', Hiragana),
+    ('\u{3099}', '\u{309A}', Inherited),
+    ('\u{309B}', '\u{309C}', Common),
+    ('\u{309D}', '\u{309F}', Hiragana),
+    ('\u{30A0}', '\u{30A0}', Common),
+    ('\u{30A1}', '\u{30FA}', Katakana),
+    ('\u{30FB}', '\u{30FC}', Common),
+    ('\u{30FD}', '\u{30FF}', Katakana),
+    ('\u{3105}', '\u{312F}', Bopomofo),
+    ('\u{3131}', '\u{318E}', Hangul),
+    ('\u{3190}', '\u{319F}', Common),
+    ('\u{31A0}', '\u{31BF}', Bopomofo),
+    ('\u{31C0}', '\u{31E3}', Common),
+    ('\u{31F0}', '\u{31FF}', Katakana),
+    ('\u{3200}', '\u{321E}', Hangul),
+    ('\u{3220}', '\u{325F}', Common),
+    ('\u{3260}', '\u{327E}', Hangul),
+    ('\u{327F}', '\u{32CF}', Common),
+    ('\u{32D0}', '\u{32FE}', Katakana),
+    ('\u{32FF}', '\u{32FF}', Common),
+    ('\u{3300}', '\u{3357}', Katakana),
+    ('\u{3358}', '\u{33FF}', Common),
+    ('\u{3400}', '\u{4DBF}', Han),
+    ('\u{4DC0}', '\u{4DFF}', Common),
+    ('\u{4E00}', '\u{9FFF}', Han),
+    ('\u{A000}', '\u{A48C}', Yi),
+    ('\u{A490}', '\u{A4C6}', Yi),
+    ('\u{A4D0}', '\u{A4FF}', Lisu),
+    ('\u{A500}', '\u{A62B}', Vai),
+    ('\u{A640}', '\u{A69F}', Cyrillic),
+    ('\u{A6A0}', '\u{A6F7}', Bamum),
+    ('\u{A700}', '\u{A721}', Common),
+    ('\u{A722}', '\u{A787}', Latin),
+    ('\u{A788}', '\u{A78A}', Common),
+    ('\u{A78B}', '\u{A7CA}', Latin),
+    ('\u{A7D0}', '\u{A7D1}', Latin),
+    ('\u{A7D3}', '\u{A7D3}', Latin),
+    ('\u{A7D5}', '\u{A7D9}', Latin),
+    ('\u{A7F2}', '\u{A7FF}', Latin),
+    ('\u{A800}', '\u{A82C}', SylotiNagri),
+    ('\u{A830}', '\u{A839}', Common),
+    ('\u{A840}', '\u{A877}', PhagsPa),
+    ('\u{A880}', '\u{A8C5}', Saurashtra),
+    ('\u{A8CE}', '\u{A8D9}', Saurashtra),
+    ('\u{A8E0}', '\u{A8FF}', Devanagari),
+    ('\u{A900}', '\u{A92D}', KayahLi),
+    ('\u{A92E}', '\u{A92E}', Common),
+    ('\u{A92F}', '\u{A92F}', KayahLi),
+    ('\u{A930}', '\u{A953}', Rejang),
+    ('\u{A95F}', '\u{A95F}', Rejang),
+    ('\u{A960}', '\u{A97C}', Hangul),
+    ('\u{A980}', '\u{A9CD}', Javanese),
+    ('\u{A9CF}', '\u{A9CF}', Common),
+    ('\u{A9D0}', '\u{A9D9}', Javanese),
+    ('\u{A9DE}', '\u{A9DF}', Javanese),
+    ('\u{A9E0}', '\u{A9FE}', Myanmar),
+    ('\u{AA00}', '\u{AA36}', Cham),
+    ('\u{AA40}', '\u{AA4D}', Cham),
+    ('\u{AA50}', '\u{AA59}', Cham),
+    ('\u{AA5C}', '\u{AA5F}', Cham),
+    ('\u{AA60}', '\u{AA7F}', Myanmar),
+    ('\u{AA80}', '\u{AAC2}', TaiViet),
+    ('\u{AADB}', '\u{AADF}', TaiViet),
+    ('\u{AAE0}', '\u{AAF6}', MeeteiMayek),
+    ('\u{AB01}', '\u{AB06}', Ethiopic),
+    ('\u{AB09}', '\u{AB0E}', Ethiopic),
+    ('\u{AB11}', '\u{AB16}', Ethiopic),
+    ('\u{AB20}', '\u{AB26}', Ethiopic),
+    ('\u{AB28}', '\u{AB2E}', Ethiopic),
+    ('\u{AB30}', '\u{AB5A}', Latin),
+    ('\u{AB5B}', '\u{AB5B}', Common),
+    ('\u{AB5C}', '\u{AB64}', Latin),
+    ('\u{AB65}', '\u{AB65}', Greek),
+    ('\u{AB66}', '\u{AB69}', Latin),
+    ('\u{AB6A}', '\u{AB6B}', Common),
+    ('\u{AB70}', '\u{ABBF}', Cherokee),
+    ('\u{ABC0}', '\u{ABED}', MeeteiMayek),
+    ('\u{ABF0}', '\u{ABF9}', MeeteiMayek),
+    ('\u{AC00}', '\u{D7A3}', Hangul),
+    ('\u{D7B0}', '\u{D7C6}', Hangul),
+    ('\u{D7CB}', '\u{D7FB}', Hangul),
+    ('\u{F900}', '\u{FA6D}', Han),
+    ('\u{FA70}', '\u{FAD9}', Han),
+    ('\u{FB00}', '\u{FB06}', Latin),
+    ('\u{FB13}', '\u{FB17}', Armenian),
+    ('\u{FB1D}', '\u{FB36}', Hebrew),
+    ('\u{FB38}', '\u{FB3C}', Hebrew),
+    ('\u{FB3E}', '\u{FB3E}', Hebrew),
+    ('\u{FB40}', '\u{FB41}', Hebrew),
+    ('\u{FB43}', '\u{FB44}', Hebrew),
+    ('\u{FB46}', '\u{FB4F}', Hebrew),
+    ('\u{FB50}', '\u{FBC2}', Arabic),
+    ('\u{FBD3}', '\u{FD3D}', Arabic),
+    ('\u{FD3E}', '\u{FD3F}', Common),
+    ('\u{FD40}', '\u{FD8F}', Arabic),
+    ('\u{FD92}', '\u{FDC7}', Arabic),
+    ('\u{FDCF}', '\u{FDCF}', Arabic),
+    ('\u{FDF0}', '\u{FDFF}', Arabic),
+    ('\u{FE00}', '\u{FE0F}', Inherited),
+    ('\u{FE10}', '\u{FE19}', Common),
+    ('\u{FE20}', '\u{FE2D}', Inherited),
+    ('\u{FE2E}', '\u{FE2F}', Cyrillic),
+    ('\u{FE30}', '\u{FE52}', Common),
+    ('\u{FE54}', '\u{FE66}', Common),
+    ('\u{FE68}', '\u{FE6B}', Common),
+    ('\u{FE70}', '\u{FE74}', Arabic),
+    ('\u{FE76}', '\u{FEFC}', Arabic),
+    ('\u{FEFF}', '\u{FEFF}', Common),
+    ('\u{FF01}', '\u{FF20}', Common),
+    ('\u{FF21}', '\u{FF3A}', Latin),
+    ('\u{FF3B}', '\u{FF40}', Common),
+    ('\u{FF41}', '\u{FF5A}', Latin),
+    ('\u{FF5B}', '\u{FF65}', Common),
+    ('\u{FF66}', '\u{FF6F}', Katakana),
+    ('\u{FF70}', '\u{FF70}', Common),
+    ('\u{FF71}', '\u{FF9D}', Katakana),
+    ('\u{FF9E}', '\u{FF9F}', Common),
+    ('\u{FFA0}', '\u{FFBE}', Hangul),
+    ('\u{FFC2}', '\u{FFC7}', Hangul),
+    ('\u{FFCA}', '\u{FFCF}', Hangul),
+    ('\u{FFD2}', '\u{FFD7}', Hangul),
+    ('\u{FFDA}', '\u{FFDC}', Hangul),
+    ('\u{FFE0}', '\u{FFE6}', Common),
+    ('\u{FFE8}', '\u{FFEE}', Common),
+    ('\u{FFF9}', '\u{FFFD}', Common),
+    ('\u{10000}', '\u{1000B}', LinearB),
+    ('\u{1000D}', '\u{10026}', LinearB),
+    ('\u{10028}', '\u{1003A}', LinearB),
+    ('\u{1003C}', '\u{1003D}', LinearB),
+    ('\u{1003F}', '\u{1004D}', LinearB),
+    ('\u{10050}', '\u{1005D}', LinearB),
+    ('\u{10080}', '\u{100FA}', LinearB),
+    ('\u{10100}', '\u{10102}', Common),
+    ('\u{10107}', '\u{10133}', Common),
+    ('\u{10137}', '\u{1013F}', Common),
+    ('\u{10140}', '\u{1018E}', Greek),
+    ('\u{10190}', '\u{1019C}', Common),
+    ('\u{101A0}', '\u{101A0}', Greek),
+    ('\u{101D0}', '\u{101FC}', Common),
+    ('\u{101FD}', '\u{101FD}', Inherited),
+    ('\u{10280}', '\u{1029C}', Lycian),
+    ('\u{102A0}', '\u{102D0}', Carian),
+    ('\u{102E0}', '\u{102E0}', Inherited),
+    ('\u{102E1}', '\u{102FB}', Common),
+    ('\u{10300}', '\u{10323}', OldItalic),
+    ('\u{1032D}', '\u{1032F}', OldItalic),
+    ('\u{10330}', '\u{1034A}', Gothic),
+    ('\u{10350}', '\u{1037A}', OldPermic),
+    ('\u{10380}', '\u{1039D}', Ugaritic),
+    ('\u{1039F}', '\u{1039F}', Ugaritic),
+    ('\u{103A0}', '\u{103C3}', OldPersian),
+    ('\u{103C8}', '\u{103D5}', OldPersian),
+    ('\u{10400}', '\u{1044F}', Deseret),
+    ('\u{10450}', '\u{1047F}', Shavian),
+    ('\u{10480}', '\u{1049D}', Osmanya),
+    ('\u{104A0}', '\u{104A9}', Osmanya),
+    ('\u{104B0}', '\u{104D3}', Osage),
+    ('\u{104D8}', '\u{104FB}', Osage),
+    ('\u{10500}', '\u{10527}', Elbasan),
+    ('\u{10530}', '\u{10563}', CaucasianAlbanian),
+    ('\u{1056F}', '\u{1056F}', CaucasianAlbanian),
+    ('\u{10570}', '\u{1057A}', Vithkuqi),
+    ('\u{1057C}', '\u{1058A}', Vithkuqi),
+    ('\u{1058C}', '\u{10592}', Vithkuqi),
+    ('\u{10594}', '\u{10595}', Vithkuqi),
+    ('\u{10597}', '\u{105A1}', Vithkuqi),
+    ('\u{105A3}', '\u{105B1}', Vithkuqi),
+    ('\u{105B3}', '\u{105B9}', Vithkuqi),
+    ('\u{105BB}', '\u{105BC}', Vithkuqi),
+    ('\u{10600}', '\u{10736}', LinearA),
+    ('\u{10740}', '\u{10755}', LinearA),
+    ('\u{10760}', '\u{10767}', LinearA),
+    ('\u{10780}', '\u{10785}', Latin),
+    ('\u{10787}', '\u{107B0}', Latin),
+    ('\u{107B2}', '\u{107BA}', Latin),
+    ('\u{10800}', '\u{10805}', Cypriot),
+    ('\u{10808}', '\u{10808}', Cypriot),
+    ('\u{1080A}', '\u{10835}', Cypriot),
+    ('\u{10837}', '\u{10838}', Cypriot),
+    ('\u{1083C}', '\u{1083C}', Cypriot),
+    ('\u{1083F}', '\u{1083F}', Cypriot),
+    ('\u{10840}', '\u{10855}', ImperialAramaic),
+    ('\u{10857}', '\u{1085F}', ImperialAramaic),
+    ('\u{10860}', '\u{1087F}', Palmyrene),
+    ('\u{10880}', '\u{1089E}', Nabataean),
+    ('\u{108A7}', '\u{108AF}', Nabataean),
+    ('\u{108E0}', '\u{108F2}', Hatran),
+    ('\u{108F4}', '\u{108F5}', Hatran),
+    ('\u{108FB}', '\u{108FF}', Hatran),
+    ('\u{10900}', '\u{1091B}', Phoenician),
+    ('\u{1091F}', '\u{1091F}', Phoenician),
+    ('\u{10920}', '\u{10939}', Lydian),
+    ('\u{1093F}', '\u{1093F}', Lydian),
+    ('\u{10980}', '\u{1099F}', MeroiticHieroglyphs),
+    ('\u{109A0}', '\u{109B7}', MeroiticCursive),
+    ('\u{109BC}', '\u{109CF}', MeroiticCursive),
+    ('\u{109D2}', '\u{109FF}', MeroiticCursive),
+    ('\u{10A00}', '\u{10A03}', Kharoshthi),
+    ('\u{10A05}', '\u{10A06}', Kharoshthi),
+    ('\u{10A0C}', '\u{10A13}', Kharoshthi),
+    ('\u{10A15}', '\u{10A17}', Kharoshthi),
+    ('\u{10A19}', '\u{10A35}', Kharoshthi),
+    ('\u{10A38}', '\u{10A3A}', Kharoshthi),
+    ('\u{10A3F}', '\u{10A48}', Kharoshthi),
+    ('\u{10A50}', '\u{10A58}', Kharoshthi),
+    ('\u{10A60}', '\u{10A7F}', OldSouthArabian),
+    ('\u{10A80}', '\u{10A9F}', OldNorthArabian),
+    ('\u{10AC0}', '\u{10AE6}', Manichaean),
+    ('\u{10AEB}', '\u{10AF6}', Manichaean),
+    ('\u{10B00}', '\u{10B35}', Avestan),
+    ('\u{10B39}', '\u{10B3F}', Avestan),
+    ('\u{10B40}', '\u{10B55}', InscriptionalParthian),
+    ('\u{10B58}', '\u{10B5F}', InscriptionalParthian),
+    ('\u{10B60}', '\u{10B72}', InscriptionalPahlavi),
+    ('\u{10B78}', '\u{10B7F}', InscriptionalPahlavi),
+    ('\u{10B80}', '\u{10B91}', PsalterPahlavi),
+    ('\u{10B99}', '\u{10B9C}', PsalterPahlavi),
+    ('\u{10BA9}', '\u{10BAF}', PsalterPahlavi),
+    ('\u{10C00}', '\u{10C48}', OldTurkic),
+    ('\u{10C80}', '\u{10CB2}', OldHungarian),
+    ('\u{10CC0}', '\u{10CF2}', OldHungarian),
+    ('\u{10CFA}', '\u{10CFF}', OldHungarian),
+    ('\u{10D00}', '\u{10D27}', HanifiRohingya),
+    ('\u{10D30}', '\u{10D39}', HanifiRohingya),
+    ('\u{10E60}', '\u{10E7E}', Arabic),
+    ('\u{10E80}', '\u{10EA9}', Yezidi),
+    ('\u{10EAB}', '\u{10EAD}', Yezidi),
+    ('\u{10EB0}', '\u{10EB1}', Yezidi),
+    ('\u{10F00}', '\u{10F27}', OldSogdian),
+    ('\u{10F30}', '\u{10F59}', Sogdian),
+    ('\u{10F70}', '\u{10F89}', OldUyghur),
+    ('\u{10FB0}', '\u{10FCB}', Chorasmian),
+    ('\u{10FE0}', '\u{10FF6}', Elymaic),
+    ('\u{11000}', '\u{1104D}', Brahmi),
+    ('\u{11052}', '\u{11075}', Brahmi),
+    ('\u{1107F}', '\u{1107F}', Brahmi),
+    ('\u{11080}', '\u{110C2}', Kaithi),
+    ('\u{110CD}', '\u{110CD}', Kaithi),
+    ('\u{110D0}', '\u{110E8}', SoraSompeng),
+    ('\u{110F0}', '\u{110F9}', SoraSompeng),
+    ('\u{11100}', '\u{11134}', Chakma),
+    ('\u{11136}', '\u{11147}', Chakma),
+    ('\u{11150}', '\u{11176}', Mahajani),
+    ('\u{11180}', '\u{111DF}', Sharada),
+    ('\u{111E1}', '\u{111F4}', Sinhala),
+    ('\u{11200}', '\u{11211}', Khojki),
+    ('\u{11213}', '\u{1123E}', Khojki),
+    ('\u{11280}', '\u{11286}', Multani),
+    ('\u{11288}', '\u{11288}', Multani),
+    ('\u{1128A}', '\u{1128D}', Multani),
+    ('\u{1128F}', '\u{1129D}', Multani),
+    ('\u{1129F}', '\u{112A9}', Multani),
+    ('\u{112B0}', '\u{112EA}', Khudawadi),
+    ('\u{112F0}', '\u{112F9}', Khudawadi),
+    ('\u{11300}', '\u{11303}', Grantha),
+    ('\u{11305}', '\u{1130C}', Grantha),
+    ('\u{1130F}', '\u{11310}', Grantha),
+    ('\u{11313}', '\u{11328}', Grantha),
+    ('\u{1132A}', '\u{11330}', Grantha),
+    ('\u{11332}', '\u{11333}', Grantha),
+    ('\u{11335}', '\u{11339}', Grantha),
+    ('\u{1133B}', '\u{1133B}', Inherited),
+    ('\u{1133C}', '\u{11344}', Grantha),
+    ('\u{11347}', '\u{11348}', Grantha),
+    ('\u{1134B}', '\u{1134D}', Grantha),
+    ('\u{11350}', '\u{11350}', Grantha),
+    ('\u{11357}', '\u{11357}', Grantha),
+    ('\u{1135D}', '\u{11363}', Grantha),
+    ('\u{11366}', '\u{1136C}', Grantha),
+    ('\u{11370}', '\u{11374}', Grantha),
+    ('\u{11400}', '\u{1145B}', Newa),
+    ('\u{1145D}', '\u{11461}', Newa),
+    ('\u{11480}', '\u{114C7}', Tirhuta),
+    ('\u{114D0}', '\u{114D9}', Tirhuta),
+    ('\u{11580}', '\u{115B5}', Siddham),
+    ('\u{115B8}', '\u{115DD}', Siddham),
+    ('\u{11600}', '\u{11644}', Modi),
+    ('\u{11650}', '\u{11659}', Modi),
+    ('\u{11660}', '\u{1166C}', Mongolian),
+    ('\u{11680}', '\u{116B9}', Takri),
+    ('\u{116C0}', '\u{116C9}', Takri),
+    ('\u{11700}', '\u{1171A}', Ahom),
+    ('\u{1171D}', '\u{1172B}', Ahom),
+    ('\u{11730}', '\u{11746}', Ahom),
+    ('\u{11800}', '\u{1183B}', Dogra),
+    ('\u{118A0}', '\u{118F2}', WarangCiti),
+    ('\u{118FF}', '\u{118FF}', WarangCiti),
+    ('\u{11900}', '\u{11906}', DivesAkuru),
+    ('\u{11909}', '\u{11909}', DivesAkuru),
+    ('\u{1190C}', '\u{11913}', DivesAkuru),
+    ('\u{11915}', '\u{11916}', DivesAkuru),
+    ('\u{11918}', '\u{11935}', DivesAkuru),
+    ('\u{11937}', '\u{11938}', DivesAkuru),
+    ('\u{1193B}', '\u{11946}', DivesAkuru),
+    ('\u{11950}', '\u{11959}', DivesAkuru),
+    ('\u{119A0}', '\u{119A7}', Nandinagari),
+    ('\u{119AA}', '\u{119D7}', Nandinagari),
+    ('\u{119DA}', '\u{119E4}', Nandinagari),
+    ('\u{11A00}', '\u{11A47}', ZanabazarSquare),
+    ('\u{11A50}', '\u{11AA2}', Soyombo),
+    ('\u{11AB0}', '\u{11ABF}', CanadianAboriginal),
+    ('\u{11AC0}', '\u{11AF8}', PauCinHau),
+    ('\u{11C00}', '\u{11C08}', Bhaiksuki),
+    ('\u{11C0A}', '\u{11C36}', Bhaiksuki),
+    ('\u{11C38}', '\u{11C45}', Bhaiksuki),
+    ('\u{11C50}', '\u{11C6C}', Bhaiksuki),
+    ('\u{11C70}', '\u{11C8F}', Marchen),
+    ('\u{11C92}', '\u{11CA7}', Marchen),
+    ('\u{11CA9}', '\u{11CB6}', Marchen),
+    ('\u{11D00}', '\u{11D06}', MasaramGondi),
+    ('\u{11D08}', '\u{11D09}', MasaramGondi),
+    ('\u{11D0B}', '\u{11D36}', MasaramGondi),
+    ('\u{11D3A}', '\u{11D3A}', MasaramGondi),
+    ('\u{11D3C}', '\u{11D3D}', MasaramGondi),
+    ('\u{11D3F}', '\u{11D47}', MasaramGondi),
+    ('\u{11D50}', '\u{11D59}', MasaramGondi),
+    ('\u{11D60}', '\u{11D65}', GunjalaGondi),
+    ('\u{11D67}', '\u{11D68}', GunjalaGondi),
+    ('\u{11D6A}', '\u{11D8E}', GunjalaGondi),
+    ('\u{11D90}', '\u{11D91}', GunjalaGondi),
+    ('\u{11D93}', '\u{11D98}', GunjalaGondi),
+    ('\u{11DA0}', '\u{11DA9}', GunjalaGondi),
+    ('\u{11EE0}', '\u{11EF8}', Makasar),
+    ('\u{11FB0}', '\u{11FB0}', Lisu),
+    ('\u{11FC0}', '\u{11FF1}', Tamil),
+    ('\u{11FFF}', '\u{11FFF}', Tamil),
+    ('\u{12000}', '\u{12399}', Cuneiform),
+    ('\u{12400}', '\u{1246E}', Cuneiform),
+    ('\u{12470}', '\u{12474}', Cuneiform),
+    ('\u{12480}', '\u{12543}', Cuneiform),
+    ('\u{12F90}', '\u{12FF2}', CyproMinoan),
+    ('\u{13000}', '\u{1342E}', EgyptianHieroglyphs),
+    ('\u{13430}', '\u{13438}', EgyptianHieroglyphs),
+    ('\u{14400}', '\u{14646}', AnatolianHieroglyphs),
+    ('\u{16800}', '\u{16A38}', Bamum),
+    ('\u{16A40}', '\u{16A5E}', Mro),
+    ('\u{16A60}', '\u{16A69}', Mro),
+    ('\u{16A6E}', '\u{16A6F}', Mro),
+    ('\u{16A70}', '\u{16ABE}', Tangsa),
+    ('\u{16AC0}', '\u{16AC9}', Tangsa),
+    ('\u{16AD0}', '\u{16AED}', BassaVah),
+    ('\u{16AF0}', '\u{16AF5}', BassaVah),
+    ('\u{16B00}', '\u{16B45}', PahawhHmong),
+    ('\u{16B50}', '\u{16B59}', PahawhHmong),
+    ('\u{16B5B}', '\u{16B61}', PahawhHmong),
+    ('\u{16B63}', '\u{16B77}', PahawhHmong),
+    ('\u{16B7D}', '\u{16B8F}', PahawhHmong),
+    ('\u{16E40}', '\u{16E9A}', Medefaidrin),
+    ('\u{16F00}', '\u{16F4A}', Miao),
+    ('\u{16F4F}', '\u{16F87}', Miao),
+    ('\u{16F8F}', '\u{16F9F}', Miao),
+    ('\u{16FE0}', '\u{16FE0}', Tangut),
+    ('\u{16FE1}', '\u{16FE1}', Nushu),
+    ('\u{16FE2}', '\u{16FE3}', Han),
+    ('\u{16FE4}', '\u{16FE4}', KhitanSmallScript),
+    ('\u{16FF0}', '\u{16FF1}', Han),
+    ('\u{17000}', '\u{187F7}', Tangut),
+    ('\u{18800}', '\u{18AFF}', Tangut),
+    ('\u{18B00}', '\u{18CD5}', KhitanSmallScript),
+    ('\u{18D00}', '\u{18D08}', Tangut),
+    ('\u{1AFF0}', '\u{1AFF3}', Katakana),
+    ('\u{1AFF5}', '\u{1AFFB}', Katakana),
+    ('\u{1AFFD}', '\u{1AFFE}', Katakana),
+    ('\u{1B000}', '\u{1B000}', Katakana),
+    ('\u{1B001}', '\u{1B11F}', Hiragana),
+    ('\u{1B120}', '\u{1B122}', Katakana),
+    ('\u{1B150}', '\u{1B152}', Hiragana),
+    ('\u{1B164}', '\u{1B167}', Katakana),
+    ('\u{1B170}', '\u{1B2FB}', Nushu),
+    ('\u{1BC00}', '\u{1BC6A}', Duployan),
+    ('\u{1BC70}', '\u{1BC7C}', Duployan),
+    ('\u{1BC80}', '\u{1BC88}', Duployan),
+    ('\u{1BC90}', '\u{1BC99}', Duployan),
+    ('\u{1BC9C}', '\u{1BC9F}', Duployan),
+    ('\u{1BCA0}', '\u{1BCA3}', Common),
+    ('\u{1CF00}', '\u{1CF2D}', Inherited),
+    ('\u{1CF30}', '\u{1CF46}', Inherited),
+    ('\u{1CF50}', '\u{1CFC3}', Common),
+    ('\u{1D000}', '\u{1D0F5}', Common),
+    ('\u{1D100}', '\u{1D126}', Common),
+    ('\u{1D129}', '\u{1D166}', Common),
+    ('\u{1D167}', '\u{1D169}', Inherited),
+    ('\u{1D16A}', '\u{1D17A}', Common),
+    ('\u{1D17B}', '\u{1D182}', Inherited),
+    ('\u{1D183}', '\u{1D184}', Common),
+    ('\u{1D185}', '\u{1D18B}', Inherited),
+    ('\u{1D18C}', '\u{1D1A9}', Common),
+    ('\u{1D1AA}', '\u{1D1AD}', Inherited),
+    ('\u{1D1AE}', '\u{1D1EA}', Common),
+    ('\u{1D200}', '\u{1D245}', Greek),
+    ('\u{1D2E0}', '\u{1D2F3}', Common),
+    ('\u{1D300}', '\u{1D356}', Common),
+    ('\u{1D360}', '\u{1D378}', Common),
+    ('\u{1D400}', '\u{1D454}', Common),
+    ('\u{1D456}', '\u{1D49C}', Common),
+    ('\u{1D49E}', '\u{1D49F}', Common),
+    ('\u{1D4A2}', '\u{1D4A2}', Common),
+    ('\u{1D4A5}', '\u{1D4A6}', Common),
+    ('\u{1D4A9}', '\u{1D4AC}', Common),
+    ('\u{1D4AE}', '\u{1D4B9}', Common),
+    ('\u{1D4BB}', '\u{1D4BB}', Common),
+    ('\u{1D4BD}', '\u{1D4C3}', Common),
+    ('\u{1D4C5}', '\u{1D505}', Common),
+    ('\u{1D507}', '\u{1D50A}', Common),
+    ('\u{1D50D}', '\u{1D514}', Common),
+    ('\u{1D516}', '\u{1D51C}', Common),
+    ('\u{1D51E}', '\u{1D539}', Common),
+    ('\u{1D53B}', '\u{1D53E}', Common),
+    ('\u{1D540}', '\u{1D544}', Common),
+    ('\u{1D546}', '\u{1D546}', Common),
+    ('\u{1D54A}', '\u{1D550}', Common),
+    ('\u{1D552}', '\u{1D6A5}', Common),
+    ('\u{1D6A8}', '\u{1D7CB}', Common),
+    ('\u{1D7CE}', '\u{1D7FF}', Common),
+    ('\u{1D800}', '\u{1DA8B}', SignWriting),
+    ('\u{1DA9B}', '\u{1DA9F}', SignWriting),
+    ('\u{1DAA1}', '\u{1DAAF}', SignWriting),
+    ('\u{1DF00}', '\u{1DF1E}', Latin),
+    ('\u{1E000}', '\u{1E006}', Glagolitic),
+    ('\u{1E008}', '\u{1E018}', Glagolitic),
+    ('\u{1E01B}', '\u{1E021}', Glagolitic),
+    ('\u{1E023}', '\u{1E024}', Glagolitic),
+    ('\u{1E026}', '\u{1E02A}', Glagolitic),
+    ('\u{1E100}', '\u{1E12C}', NyiakengPuachueHmong),
+    ('\u{1E130}', '\u{1E13D}', NyiakengPuachueHmong),
+    ('\u{1E140}', '\u{1E149}', NyiakengPuachueHmong),
+    ('\u{1E14E}', '\u{1E14F}', NyiakengPuachueHmong),
+    ('\u{1E290}', '\u{1E2AE}', Toto),
+    ('\u{1E2C0}', '\u{1E2F9}', Wancho),
+    ('\u{1E2FF}', '\u{1E2FF}', Wancho),
+    ('\u{1E7E0}', '\u{1E7E6}', Ethiopic),
+    ('\u{1E7E8}', '\u{1E7EB}', Ethiopic),
+    ('\u{1E7ED}', '\u{1E7EE}', Ethiopic),
+    ('\u{1E7F0}', '\u{1E7FE}', Ethiopic),
+    ('\u{1E800}', '\u{1E8C4}', MendeKikakui),
+    ('\u{1E8C7}', '\u{1E8D6}', MendeKikakui),
+    ('\u{1E900}', '\u{1E94B}', Adlam),
+    ('\u{1E950}', '\u{1E959}', Adlam),
+    ('\u{1E95E}', '\u{1E95F}', Adlam),
+    ('\u{1EC71}', '\u{1ECB4}', Common),
+    ('\u{1ED01}', '\u{1ED3D}', Common),
+    ('\u{1EE00}', '\u{1EE03}', Arabic),
+    ('\u{1EE05}', '\u{1EE1F}', Arabic),
+    ('\u{1EE21}', '\u{1EE22}', Arabic),
+    ('\u{1EE24}', '\u{1EE24}', Arabic),
+    ('\u{1EE27}', '\u{1EE27}', Arabic),
+    ('\u{1EE29}', '\u{1EE32}', Arabic),
+    ('\u{1EE34}', '\u{1EE37}', Arabic),
+    ('\u{1EE39}', '\u{1EE39}', Arabic),
+    ('\u{1EE3B}', '\u{1EE3B}', Arabic),
+    ('\u{1EE42}', '\u{1EE42}', Arabic),
+    ('\u{1EE47}', '\u{1EE47}', Arabic),
+    ('\u{1EE49}', '\u{1EE49}', Arabic),
+    ('\u{1EE4B}', '\u{1EE4B}', Arabic),
+    ('\u{1EE4D}', '\u{1EE4F}', Arabic),
+    ('\u{1EE51}', '\u{1EE52}', Arabic),
+    ('\u{1EE54}', '\u{1EE54}', Arabic),
+    ('\u{1EE57}', '\u{1EE57}', Arabic),
+    ('\u{1EE59}', '\u{1EE59}', Arabic),
+    ('\u{1EE5B}', '\u{1EE5B}', Arabic),
+    ('\u{1EE5D}', '\u{1EE5D}', Arabic),
+    ('\u{1EE5F}', '\u{1EE5F}', Arabic),
+    ('\u{1EE61}', '\u{1EE62}', Arabic),
+    ('\u{1EE64}', '\u{1EE64}', Arabic),
+    ('\u{1EE67}', '\u{1EE6A}', Arabic),
+    ('\u{1EE6C}', '\u{1EE72}', Arabic),
+    ('\u{1EE74}', '\u{1EE77}', Arabic),
+    ('\u{1EE79}', '\u{1EE7C}', Arabic),
+    ('\u{1EE7E}', '\u{1EE7E}', Arabic),
+    ('\u{1EE80}', '\u{1EE89}', Arabic),
+    ('\u{1EE8B}', '\u{1EE9B}', Arabic),
+    ('\u{1EEA1}', '\u{1EEA3}', Arabic),
+    ('\u{1EEA5}', '\u{1EEA9}', Arabic),
+    ('\u{1EEAB}', '\u{1EEBB}', Arabic),
+    ('\u{1EEF0}', '\u{1EEF1}', Arabic),
+    ('\u{1F000}', '\u{1F02B}', Common),
+    ('\u{1F030}', '\u{1F093}', Common),
+    ('\u{1F0A0}', '\u{1F0AE}', Common),
+    ('\u{1F0B1}', '\u{1F0BF}', Common),
+    ('\u{1F0C1}', '\u{1F0CF}', Common),
+    ('\u{1F0D1}', '\u{1F0F5}', Common),
+    ('\u{1F100}', '\u{1F1AD}', Common),
+    ('\u{1F1E6}', '\u{1F1FF}', Common),
+    ('\u{1F200}', '\u{1F200}', Hiragana),
+    ('\u{1F201}', '\u{1F202}', Common),
+    ('\u{1F210}', '\u{1F23B}', Common),
+    ('\u{1F240}', '\u{1F248}', Common),
+    ('\u{1F250}', '\u{1F251}', Common),
+    ('\u{1F260}', '\u{1F265}', Common),
+    ('\u{1F300}', '\u{1F6D7}', Common),
+    ('\u{1F6DD}', '\u{1F6EC}', Common),
+    ('\u{1F6F0}', '\u{1F6FC}', Common),
+    ('\u{1F700}', '\u{1F773}', Common),
+    ('\u{1F780}', '\u{1F7D8}', Common),
+    ('\u{1F7E0}', '\u{1F7EB}', Common),
+    ('\u{1F7F0}', '\u{1F7F0}', Common),
+    ('\u{1F800}', '\u{1F80B}', Common),
+    ('\u{1F810}', '\u{1F847}', Common),
+    ('\u{1F850}', '\u{1F859}', Common),
+    ('\u{1F860}', '\u{1F887}', Common),
+    ('\u{1F890}', '\u{1F8AD}', Common),
+    ('\u{1F8B0}', '\u{1F8B1}', Common),
+    ('\u{1F900}', '\u{1FA53}', Common),
+    ('\u{1FA60}', '\u{1FA6D}', Common),
+    ('\u{1FA70}', '\u{1FA74}', Common),
+    ('\u{1FA78}', '\u{1FA7C}', Common),
+    ('\u{1FA80}', '\u{1FA86}', Common),
+    ('\u{1FA90}', '\u{1FAAC}', Common),
+    ('\u{1FAB0}', '\u{1FABA}', Common),
+    ('\u{1FAC0}', '\u{1FAC5}', Common),
+    ('\u{1FAD0}', '\u{1FAD9}', Common),
+    ('\u{1FAE0}', '\u{1FAE7}', Common),
+    ('\u{1FAF0}', '\u{1FAF6}', Common),
+    ('\u{1FB00}', '\u{1FB92}', Common),
+    ('\u{1FB94}', '\u{1FBCA}', Common),
+    ('\u{1FBF0}', '\u{1FBF9}', Common),
+    ('\u{20000}', '\u{2A6DF}', Han),
+    ('\u{2A700}', '\u{2B738}', Han),
+    ('\u{2B740}', '\u{2B81D}', Han),
+    ('\u{2B820}', '\u{2CEA1}', Han),
+    ('\u{2CEB0}', '\u{2EBE0}', Han),
+    ('\u{2F800}', '\u{2FA1D}', Han),
+    ('\u{30000}', '\u{3134A}', Han),
+    ('\u{E0001}', '\u{E0001}', Common),
+    ('\u{E0020}', '\u{E007F}', Common),
+    ('\u{E0100}', '\u{E01EF}', Inherited),
+];
+
+pub(super) static XID_START: &[(char, char)] = &[
+    ('\u{0041}', '\u{005A}'),
+    ('\u{0061}', '\u{007A}'),
+    ('\u{00AA}', '\u{00AA}'),
+    ('\u{00B5}', '\u{00B5}'),
+    ('\u{00BA}', '\u{00BA}'),
+    ('\u{00C0}', '\u{00D6}'),
+    ('\u{00D8}', '\u{00F6}'),
+    ('\u{00F8}', '\u{02C1}'),
+    ('\u{02C6}', '\u{02D1}'),
+    ('\u{02E0}', '\u{02E4}'),
+    ('\u{02EC}', '\u{02EC}'),
+    ('\u{02EE}', '\u{02EE}'),
+    ('\u{0370}', '\u{0374}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037B}', '\u{037D}'),
+    ('\u{037F}', '\u{037F}'),
+    ('\u{0386}', '\u{0386}'),
+    ('\u{0388}', '\u{038A}'),
+    ('\u{038C}', '\u{038C}'),
+    ('\u{038E}', '\u{03A1}'),
+    ('\u{03A3}', '\u{03F5}'),
+    ('\u{03F7}', '\u{0481}'),
+    ('\u{048A}', '\u{052F}'),
+    ('\u{0531}', '\u{0556}'),
+    ('\u{0559}', '\u{0559}'),
+    ('\u{0560}', '\u{0588}'),
+    ('\u{05D0}', '\u{05EA}'),
+    ('\u{05EF}', '\u{05F2}'),
+    ('\u{0620}', '\u{064A}'),
+    ('\u{066E}', '\u{066F}'),
+    ('\u{0671}', '\u{06D3}'),
+    ('\u{06D5}', '\u{06D5}'),
+    ('\u{06E5}', '\u{06E6}'),
+    ('\u{06EE}', '\u{06EF}'),
+    ('\u{06FA}', '\u{06FC}'),
+    ('\u{06FF}', '\u{06FF}'),
+    ('\u{0710}', '\u{0710}'),
+    ('\u{0712}', '\u{072F}'),
+    ('\u{074D}', '\u{07A5}'),
+    ('\u{07B1}', '\u{07B1}'),
+    ('\u{07CA}', '\u{07EA}'),
+    ('\u{07F4}', '\u{07F5}'),
+    ('\u{07FA}', '\u{07FA}'),
+    ('\u{0800}', '\u{0815}'),
+    ('\u{081A}', '\u{081A}'),
+    ('\u{0824}', '\u{0824}'),
+    ('\u{0828}', '\u{0828}'),
+    ('\u{0840}', '\u{0858}'),
+    ('\u{0860}', '\u{086A}'),
+    ('\u{0870}', '\u{0887}'),
+    ('\u{0889}', '\u{088E}'),
+    ('\u{08A0}', '\u{08C9}'),
+    ('\u{0904}', '\u{0939}'),
+    ('\u{093D}', '\u{093D}'),
+    ('\u{0950}', '\u{0950}'),
+    ('\u{0958}', '\u{0961}'),
+    ('\u{0971}', '\u{0980}'),
+    ('\u{0985}', '\u{098C}'),
+    ('\u{098F}', '\u{0990}'),
+    ('\u{0993}', '\u{09A8}'),
+    ('\u{09AA}', '\u{09B0}'),
+    ('\u{09B2}', '\u{09B2}'),
+    ('\u{09B6}', '\u{09B9}'),
+    ('\u{09BD}', '\u{09BD}'),
+    ('\u{09CE}', '\u{09CE}'),
+    ('\u{09DC}', '\u{09DD}'),
+    ('\u{09DF}', '\u{09E1}'),
+    ('\u{09F0}', '\u{09F1}'),
+    ('\u{09FC}', '\u{09FC}'),
+    ('\u{0A05}', '\u{0A0A}'),
+    ('\u{0A0F}', '\u{0A10}'),
+    ('\u{0A13}', '\u{0A28}'),
+    ('\u{0A2A}', '\u{0A30}'),
+    ('\u{0A32}', '\u{0A33}'),
+    ('\u{0A35}', '\u{0A36}'),
+    ('\u{0A38}', '\u{0A39}'),
+    ('\u{0A59}', '\u{0A5C}'),
+    ('\u{0A5E}', '\u{0A5E}'),
+    ('\u{0A72}', '\u{0A74}'),
+    ('\u{0A85}', '\u{0A8D}'),
+    ('\u{0A8F}', '\u{0A91}'),
+    ('\u{0A93}', '\u{0AA8}'),
+    ('\u{0AAA}', '\u{0AB0}'),
+    ('\u{0AB2}', '\u{0AB3}'),
+    ('\u{0AB5}', '\u{0AB9}'),
+    ('\u{0ABD}', '\u{0ABD}'),
+    ('\u{0AD0}', '\u{0AD0}'),
+    ('\u{0AE0}', '\u{0AE1}'),
+    ('\u{0AF9}', '\u{0AF9}'),
+    ('\u{0B05}', '\u{0B0C}'),
+    ('\u{0B0F}', '\u{0B10}'),
+    ('\u{0B13}', '\u{0B28}'),
+    ('\u{0B2A}', '\u{0B30}'),
+    ('\u{0B32}', '\u{0B33}'),
+    ('\u{0B35}', '\u{0B39}'),
+    ('\u{0B3D}', '\u{0B3D}'),
+    ('\u{0B5C}', '\u{0B5D}'),
+    ('\u{0B5F}', '\u{0B61}'),
+    ('\u{0B71}', '\u{0B71}'),
+    ('\u{0B83}', '\u{0B83}'),
+    ('\u{0B85}', '\u{0B8A}'),
+    ('\u{0B8E}', '\u{0B90}'),
+    ('\u{0B92}', '\u{0B95}'),
+    ('\u{0B99}', '\u{0B9A}'),
+    ('\u{0B9C}', '\u{0B9C}'),
+    ('\u{0B9E}', '\u{0B9F}'),
+    ('\u{0BA3}', '\u{0BA4}'),
+    ('\u{0BA8}', '\u{0BAA}'),
+    ('\u{0BAE}', '\u{0BB9}'),
+    ('\u{0BD0}', '\u{0BD0}'),
+    ('\u{0C05}', '\u{0C0C}'),
+    ('\u{0C0E}', '\u{0C10}'),
+    ('\u{0C12}', '\u{0C28}'),
+    ('\u{0C2A}', '\u{0C39}'),
+    ('\u{0C3D}', '\u{0C3D}'),
+    ('\u{0C58}', '\u{0C5A}'),
+    ('\u{0C5D}', '\u{0C5D}'),
+    ('\u{0C60}', '\u{0C61}'),
+    ('\u{0C80}', '\u{0C80}'),
+    ('\u{0C85}', '\u{0C8C}'),
+    ('\u{0C8E}', '\u{0C90}'),
+    ('\u{0C92}', '\u{0CA8}'),
+    ('\u{0CAA}', '\u{0CB3}'),
+    ('\u{0CB5}', '\u{0CB9}'),
+    ('\u{0CBD}', '\u{0CBD}'),
+    ('\u{0CDD}', '\u{0CDE}'),
+    ('\u{0CE0}', '\u{0CE1}'),
+    ('\u{0CF1}', '\u{0CF2}'),
+    ('\u{0D04}', '\u{0D0C}'),
+    ('\u{0D0E}', '\u{0D10}'),
+    ('\u{0D12}', '\u{0D3A}'),
+    ('\u{0D3D}', '\u{0D3D}'),
+    ('\u{0D4E}', '\u{0D4E}'),
+    ('\u{0D54}', '\u{0D56}'),
+    ('\u{0D5F}', '\u{0D61}'),
+    ('\u{0D7A}', '\u{0D7F}'),
+    ('\u{0D85}', '\u{0D96}'),
+    ('\u{0D9A}', '\u{0DB1}'),
+    ('\u{0DB3}', '\u{0DBB}'),
+    ('\u{0DBD}', '\u{0DBD}'),
+    ('\u{0DC0}', '\u{0DC6}'),
+    ('\u{0E01}', '\u{0E30}'),
+    ('\u{0E32}', '\u{0E32}'),
+    ('\u{0E40}', '\u{0E46}'),
+    ('\u{0E81}', '\u{0E82}'),
+    ('\u{0E84}', '\u{0E84}'),
+    ('\u{0E86}', '\u{0E8A}'),
+    ('\u{0E8C}', '\u{0EA3}'),
+    ('\u{0EA5}', '\u{0EA5}'),
+    ('\u{0EA7}', '\u{0EB0}'),
+    ('\u{0EB2}', '\u{0EB2}'),
+    ('\u{0EBD}', '\u{0EBD}'),
+    ('\u{0EC0}', '\u{0EC4}'),
+    ('\u{0EC6}', '\u{0EC6}'),
+    ('\u{0EDC}', '\u{0EDF}'),
+    ('\u{0F00}', '\u{0F00}'),
+    ('\u{0F40}', '\u{0F47}'),
+    ('\u{0F49}', '\u{0F6C}'),
+    ('\u{0F88}', '\u{0F8C}'),
+    ('\u{1000}', '\u{102A}'),
+    ('\u{103F}', '\u{103F}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105A}', '\u{105D}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106E}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108E}', '\u{108E}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171F}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1780}', '\u{17B3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DC}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18A8}'),
+    ('\u{18AA}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1950}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{1A00}', '\u{1A16}'),
+    ('\u{1A20}', '\u{1A54}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1B05}', '\u{1B33}'),
+    ('\u{1B45}', '\u{1B4C}'),
+    ('\u{1B83}', '\u{1BA0}'),
+    ('\u{1BAE}', '\u{1BAF}'),
+    ('\u{1BBA}', '\u{1BE5}'),
+    ('\u{1C00}', '\u{1C23}'),
+    ('\u{1C4D}', '\u{1C4F}'),
+    ('\u{1C5A}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CE9}', '\u{1CEC}'),
+    ('\u{1CEE}', '\u{1CF3}'),
+    ('\u{1CF5}', '\u{1CF6}'),
+    ('\u{1CFA}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1DBF}'),
+    ('\u{1E00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D80}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A61F}'),
+    ('\u{A62A}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66E}'),
+    ('\u{A67F}', '\u{A69D}'),
+    ('\u{A6A0}', '\u{A6EF}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A801}'),
+    ('\u{A803}', '\u{A805}'),
+    ('\u{A807}', '\u{A80A}'),
+    ('\u{A80C}', '\u{A822}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A882}', '\u{A8B3}'),
+    ('\u{A8F2}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A8FE}'),
+    ('\u{A90A}', '\u{A925}'),
+    ('\u{A930}', '\u{A946}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A984}', '\u{A9B2}'),
+    ('\u{A9CF}', '\u{A9CF}'),
+    ('\u{A9E0}', '\u{A9E4}'),
+    ('\u{A9E6}', '\u{A9EF}'),
+    ('\u{A9FA}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA28}'),
+    ('\u{AA40}', '\u{AA42}'),
+    ('\u{AA44}', '\u{AA4B}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AA7A}'),
+    ('\u{AA7E}', '\u{AAAF}'),
+    ('\u{AAB1}', '\u{AAB1}'),
+    ('\u{AAB5}', '\u{AAB6}'),
+    ('\u{AAB9}', '\u{AABD}'),
+    ('\u{AAC0}', '\u{AAC0}'),
+    ('\u{AAC2}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEA}'),
+    ('\u{AAF2}', '\u{AAF4}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABE2}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB1D}'),
+    ('\u{FB1F}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FC5D}'),
+    ('\u{FC64}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDF9}'),
+    ('\u{FE71}', '\u{FE71}'),
+    ('\u{FE73}', '\u{FE73}'),
+    ('\u{FE77}', '\u{FE77}'),
+    ('\u{FE79}', '\u{FE79}'),
+    ('\u{FE7B}', '\u{FE7B}'),
+    ('\u{FE7D}', '\u{FE7D}'),
+    ('\u{FE7F}', '\u{FEFC}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FF9D}'),
+    ('\u{FFA0}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A00}'),
+    ('\u{10A10}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE4}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D23}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F45}'),
+    ('\u{10F70}', '\u{10F81}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110AF}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111B2}'),
+    ('\u{111C1}', '\u{111C4}'),
+    ('\u{111DA}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122B}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112DE}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133D}', '\u{1133D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135D}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144A}'),
+    ('\u{1145F}', '\u{11461}'),
+    ('\u{11480}', '\u{114AF}'),
+    ('\u{114C4}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{11580}', '\u{115AE}'),
+    ('\u{115D8}', '\u{115DB}'),
+    ('\u{11600}', '\u{1162F}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116AA}'),
+    ('\u{116B8}', '\u{116B8}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182B}'),
+    ('\u{118A0}', '\u{118DF}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192F}'),
+    ('\u{1193F}', '\u{1193F}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D0}'),
+    ('\u{119E1}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E3}'),
+    ('\u{11A00}', '\u{11A00}'),
+    ('\u{11A0B}', '\u{11A32}'),
+    ('\u{11A3A}', '\u{11A3A}'),
+    ('\u{11A50}', '\u{11A50}'),
+    ('\u{11A5C}', '\u{11A89}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C2E}'),
+    ('\u{11C40}', '\u{11C40}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D30}'),
+    ('\u{11D46}', '\u{11D46}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D89}'),
+    ('\u{11D98}', '\u{11D98}'),
+    ('\u{11EE0}', '\u{11EF2}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16B00}', '\u{16B2F}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F50}', '\u{16F50}'),
+    ('\u{16F93}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE3}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E137}', '\u{1E13D}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AD}'),
+    ('\u{1E2C0}', '\u{1E2EB}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E900}', '\u{1E943}'),
+    ('\u{1E94B}', '\u{1E94B}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+];
+
+pub(super) static XID_CONTINUE: &[(char, char)] = &[
+    ('\u{0030}', '\u{0039}'),
+    ('\u{0041}', '\u{005A}'),
+    ('\u{005F}', '\u{005F}'),
+    ('\u{0061}', '\u{007A}'),
+    ('\u{00AA}', '\u{00AA}'),
+    ('\u{00B5}', '\u{00B5}'),
+    ('\u{00B7}', '\u{00B7}'),
+    ('\u{00BA}', '\u{00BA}'),
+    ('\u{00C0}', '\u{00D6}'),
+    ('\u{00D8}', '\u{00F6}'),
+    ('\u{00F8}', '\u{02C1}'),
+    ('\u{02C6}', '\u{02D1}'),
+    ('\u{02E0}', '\u{02E4}'),
+    ('\u{02EC}', '\u{02EC}'),
+    ('\u{02EE}', '\u{02EE}'),
+    ('\u{0300}', '\u{0374}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037B}', '\u{037D}'),
+    ('\u{037F}', '\u{037F}'),
+    ('\u{0386}', '\u{038A}'),
+    ('\u{038C}', '\u{038C}'),
+    ('\u{038E}', '\u{03A1}'),
+    ('\u{03A3}', '\u{03F5}'),
+    ('\u{03F7}', '\u{0481}'),
+    ('\u{0483}', '\u{0487}'),
+    ('\u{048A}', '\u{052F}'),
+    ('\u{0531}', '\u{0556}'),
+    ('\u{0559}', '\u{0559}'),
+    ('\u{0560}', '\u{0588}'),
+    ('\u{0591}', '\u{05BD}'),
+    ('\u{05BF}', '\u{05BF}'),
+    ('\u{05C1}', '\u{05C2}'),
+    ('\u{05C4}', '\u{05C5}'),
+    ('\u{05C7}', '\u{05C7}'),
+    ('\u{05D0}', '\u{05EA}'),
+    ('\u{05EF}', '\u{05F2}'),
+    ('\u{0610}', '\u{061A}'),
+    ('\u{0620}', '\u{0669}'),
+    ('\u{066E}', '\u{06D3}'),
+    ('\u{06D5}', '\u{06DC}'),
+    ('\u{06DF}', '\u{06E8}'),
+    ('\u{06EA}', '\u{06FC}'),
+    ('\u{06FF}', '\u{06FF}'),
+    ('\u{0710}', '\u{074A}'),
+    ('\u{074D}', '\u{07B1}'),
+    ('\u{07C0}', '\u{07F5}'),
+    ('\u{07FA}', '\u{07FA}'),
+    ('\u{07FD}', '\u{07FD}'),
+    ('\u{0800}', '\u{082D}'),
+    ('\u{0840}', '\u{085B}'),
+    ('\u{0860}', '\u{086A}'),
+    ('\u{0870}', '\u{0887}'),
+    ('\u{0889}', '\u{088E}'),
+    ('\u{0898}', '\u{08E1}'),
+    ('\u{08E3}', '\u{0963}'),
+    ('\u{0966}', '\u{096F}'),
+    ('\u{0971}', '\u{0983}'),
+    ('\u{0985}', '\u{098C}'),
+    ('\u{098F}', '\u{0990}'),
+    ('\u{0993}', '\u{09A8}'),
+    ('\u{09AA}', '\u{09B0}'),
+    ('\u{09B2}', '\u{09B2}'),
+    ('\u{09B6}', '\u{09B9}'),
+    ('\u{09BC}', '\u{09C4}'),
+    ('\u{09C7}', '\u{09C8}'),
+    ('\u{09CB}', '\u{09CE}'),
+    ('\u{09D7}', '\u{09D7}'),
+    ('\u{09DC}', '\u{09DD}'),
+    ('\u{09DF}', '\u{09E3}'),
+    ('\u{09E6}', '\u{09F1}'),
+    ('\u{09FC}', '\u{09FC}'),
+    ('\u{09FE}', '\u{09FE}'),
+    ('\u{0A01}', '\u{0A03}'),
+    ('\u{0A05}', '\u{0A0A}'),
+    ('\u{0A0F}', '\u{0A10}'),
+    ('\u{0A13}', '\u{0A28}'),
+    ('\u{0A2A}', '\u{0A30}'),
+    ('\u{0A32}', '\u{0A33}'),
+    ('\u{0A35}', '\u{0A36}'),
+    ('\u{0A38}', '\u{0A39}'),
+    ('\u{0A3C}', '\u{0A3C}'),
+    ('\u{0A3E}', '\u{0A42}'),
+    ('\u{0A47}', '\u{0A48}'),
+    ('\u{0A4B}', '\u{0A4D}'),
+    ('\u{0A51}', '\u{0A51}'),
+    ('\u{0A59}', '\u{0A5C}'),
+    ('\u{0A5E}', '\u{0A5E}'),
+    ('\u{0A66}', '\u{0A75}'),
+    ('\u{0A81}', '\u{0A83}'),
+    ('\u{0A85}', '\u{0A8D}'),
+    ('\u{0A8F}', '\u{0A91}'),
+    ('\u{0A93}', '\u{0AA8}'),
+    ('\u{0AAA}', '\u{0AB0}'),
+    ('\u{0AB2}', '\u{0AB3}'),
+    ('\u{0AB5}', '\u{0AB9}'),
+    ('\u{0ABC}', '\u{0AC5}'),
+    ('\u{0AC7}', '\u{0AC9}'),
+    ('\u{0ACB}', '\u{0ACD}'),
+    ('\u{0AD0}', '\u{0AD0}'),
+    ('\u{0AE0}', '\u{0AE3}'),
+    ('\u{0AE6}', '\u{0AEF}'),
+    ('\u{0AF9}', '\u{0AFF}'),
+    ('\u{0B01}', '\u{0B03}'),
+    ('\u{0B05}', '\u{0B0C}'),
+    ('\u{0B0F}', '\u{0B10}'),
+    ('\u{0B13}', '\u{0B28}'),
+    ('\u{0B2A}', '\u{0B30}'),
+    ('\u{0B32}', '\u{0B33}'),
+    ('\u{0B35}', '\u{0B39}'),
+    ('\u{0B3C}', '\u{0B44}'),
+    ('\u{0B47}', '\u{0B48}'),
+    ('\u{0B4B}', '\u{0B4D}'),
+    ('\u{0B55}', '\u{0B57}'),
+    ('\u{0B5C}', '\u{0B5D}'),
+    ('\u{0B5F}', '\u{0B63}'),
+    ('\u{0B66}', '\u{0B6F}'),
+    ('\u{0B71}', '\u{0B71}'),
+    ('\u{0B82}', '\u{0B83}'),
+    ('\u{0B85}', '\u{0B8A}'),
+    ('\u{0B8E}', '\u{0B90}'),
+    ('\u{0B92}', '\u{0B95}'),
+    ('\u{0B99}', '\u{0B9A}'),
+    ('\u{0B9C}', '\u{0B9C}'),
+    ('\u{0B9E}', '\u{0B9F}'),
+    ('\u{0BA3}', '\u{0BA4}'),
+    ('\u{0BA8}', '\u{0BAA}'),
+    ('\u{0BAE}', '\u{0BB9}'),
+    ('\u{0BBE}', '\u{0BC2}'),
+    ('\u{0BC6}', '\u{0BC8}'),
+    ('\u{0BCA}', '\u{0BCD}'),
+    ('\u{0BD0}', '\u{0BD0}'),
+    ('\u{0BD7}', '\u{0BD7}'),
+    ('\u{0BE6}', '\u{0BEF}'),
+    ('\u{0C00}', '\u{0C0C}'),
+    ('\u{0C0E}', '\u{0C10}'),
+    ('\u{0C12}', '\u{0C28}'),
+    ('\u{0C2A}', '\u{0C39}'),
+    ('\u{0C3C}', '\u{0C44}'),
+    ('\u{0C46}', '\u{0C48}'),
+    ('\u{0C4A}', '\u{0C4D}'),
+    ('\u{0C55}', '\u{0C56}'),
+    ('\u{0C58}', '\u{0C5A}'),
+    ('\u{0C5D}', '\u{0C5D}'),
+    ('\u{0C60}', '\u{0C63}'),
+    ('\u{0C66}', '\u{0C6F}'),
+    ('\u{0C80}', '\u{0C83}'),
+    ('\u{0C85}', '\u{0C8C}'),
+    ('\u{0C8E}', '\u{0C90}'),
+    ('\u{0C92}', '\u{0CA8}'),
+    ('\u{0CAA}', '\u{0CB3}'),
+    ('\u{0CB5}', '\u{0CB9}'),
+    ('\u{0CBC}', '\u{0CC4}'),
+    ('\u{0CC6}', '\u{0CC8}'),
+    ('\u{0CCA}', '\u{0CCD}'),
+    ('\u{0CD5}', '\u{0CD6}'),
+    ('\u{0CDD}', '\u{0CDE}'),
+    ('\u{0CE0}', '\u{0CE3}'),
+    ('\u{0CE6}', '\u{0CEF}'),
+    ('\u{0CF1}', '\u{0CF2}'),
+    ('\u{0D00}', '\u{0D0C}'),
+    ('\u{0D0E}', '\u{0D10}'),
+    ('\u{0D12}', '\u{0D44}'),
+    ('\u{0D46}', '\u{0D48}'),
+    ('\u{0D4A}', '\u{0D4E}'),
+    ('\u{0D54}', '\u{0D57}'),
+    ('\u{0D5F}', '\u{0D63}'),
+    ('\u{0D66}', '\u{0D6F}'),
+    ('\u{0D7A}', '\u{0D7F}'),
+    ('\u{0D81}', '\u{0D83}'),
+    ('\u{0D85}', '\u{0D96}'),
+    ('\u{0D9A}', '\u{0DB1}'),
+    ('\u{0DB3}', '\u{0DBB}'),
+    ('\u{0DBD}', '\u{0DBD}'),
+    ('\u{0DC0}', '\u{0DC6}'),
+    ('\u{0DCA}', '\u{0DCA}'),
+    ('\u{0DCF}', '\u{0DD4}'),
+    ('\u{0DD6}', '\u{0DD6}'),
+    ('\u{0DD8}', '\u{0DDF}'),
+    ('\u{0DE6}', '\u{0DEF}'),
+    ('\u{0DF2}', '\u{0DF3}'),
+    ('\u{0E01}', '\u{0E3A}'),
+    ('\u{0E40}', '\u{0E4E}'),
+    ('\u{0E50}', '\u{0E59}'),
+    ('\u{0E81}', '\u{0E82}'),
+    ('\u{0E84}', '\u{0E84}'),
+    ('\u{0E86}', '\u{0E8A}'),
+    ('\u{0E8C}', '\u{0EA3}'),
+    ('\u{0EA5}', '\u{0EA5}'),
+    ('\u{0EA7}', '\u{0EBD}'),
+    ('\u{0EC0}', '\u{0EC4}'),
+    ('\u{0EC6}', '\u{0EC6}'),
+    ('\u{0EC8}', '\u{0ECD}'),
+    ('\u{0ED0}', '\u{0ED9}'),
+    ('\u{0EDC}', '\u{0EDF}'),
+    ('\u{0F00}', '\u{0F00}'),
+    ('\u{0F18}', '\u{0F19}'),
+    ('\u{0F20}', '\u{0F29}'),
+    ('\u{0F35}', '\u{0F35}'),
+    ('\u{0F37}', '\u{0F37}'),
+    ('\u{0F39}', '\u{0F39}'),
+    ('\u{0F3E}', '\u{0F47}'),
+    ('\u{0F49}', '\u{0F6C}'),
+    ('\u{0F71}', '\u{0F84}'),
+    ('\u{0F86}', '\u{0F97}'),
+    ('\u{0F99}', '\u{0FBC}'),
+    ('\u{0FC6}', '\u{0FC6}'),
+    ('\u{1000}', '\u{1049}'),
+    ('\u{1050}', '\u{109D}'),
+    ('\u{10A0}', '\u{10C5}'),
+    ('\u{10C7}', '\u{10C7}'),
+    ('\u{10CD}', '\u{10CD}'),
+    ('\u{10D0}', '\u{10FA}'),
+    ('\u{10FC}', '\u{1248}'),
+    ('\u{124A}', '\u{124D}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125A}', '\u{125D}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128A}', '\u{128D}'),
+    ('\u{1290}', '\u{12B0}'),
+    ('\u{12B2}', '\u{12B5}'),
+    ('\u{12B8}', '\u{12BE}'),
+    ('\u{12C0}', '\u{12C0}'),
+    ('\u{12C2}', '\u{12C5}'),
+    ('\u{12C8}', '\u{12D6}'),
+    ('\u{12D8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135A}'),
+    ('\u{135D}', '\u{135F}'),
+    ('\u{1369}', '\u{1371}'),
+    ('\u{1380}', '\u{138F}'),
+    ('\u{13A0}', '\u{13F5}'),
+    ('\u{13F8}', '\u{13FD}'),
+    ('\u{1401}', '\u{166C}'),
+    ('\u{166F}', '\u{167F}'),
+    ('\u{1681}', '\u{169A}'),
+    ('\u{16A0}', '\u{16EA}'),
+    ('\u{16EE}', '\u{16F8}'),
+    ('\u{1700}', '\u{1715}'),
+    ('\u{171F}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176C}'),
+    ('\u{176E}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17D3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DC}', '\u{17DD}'),
+    ('\u{17E0}', '\u{17E9}'),
+    ('\u{180B}', '\u{180D}'),
+    ('\u{180F}', '\u{1819}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18AA}'),
+    ('\u{18B0}', '\u{18F5}'),
+    ('\u{1900}', '\u{191E}'),
+    ('\u{1920}', '\u{192B}'),
+    ('\u{1930}', '\u{193B}'),
+    ('\u{1946}', '\u{196D}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19AB}'),
+    ('\u{19B0}', '\u{19C9}'),
+    ('\u{19D0}', '\u{19DA}'),
+    ('\u{1A00}', '\u{1A1B}'),
+    ('\u{1A20}', '\u{1A5E}'),
+    ('\u{1A60}', '\u{1A7C}'),
+    ('\u{1A7F}', '\u{1A89}'),
+    ('\u{1A90}', '\u{1A99}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1AB0}', '\u{1ABD}'),
+    ('\u{1ABF}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B4C}'),
+    ('\u{1B50}', '\u{1B59}'),
+    ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1B80}', '\u{1BF3}'),
+    ('\u{1C00}', '\u{1C37}'),
+    ('\u{1C40}', '\u{1C49}'),
+    ('\u{1C4D}', '\u{1C7D}'),
+    ('\u{1C80}', '\u{1C88}'),
+    ('\u{1C90}', '\u{1CBA}'),
+    ('\u{1CBD}', '\u{1CBF}'),
+    ('\u{1CD0}', '\u{1CD2}'),
+    ('\u{1CD4}', '\u{1CFA}'),
+    ('\u{1D00}', '\u{1F15}'),
+    ('\u{1F18}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{1F45}'),
+    ('\u{1F48}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{1F57}'),
+    ('\u{1F59}', '\u{1F59}'),
+    ('\u{1F5B}', '\u{1F5B}'),
+    ('\u{1F5D}', '\u{1F5D}'),
+    ('\u{1F5F}', '\u{1F7D}'),
+    ('\u{1F80}', '\u{1FB4}'),
+    ('\u{1FB6}', '\u{1FBC}'),
+    ('\u{1FBE}', '\u{1FBE}'),
+    ('\u{1FC2}', '\u{1FC4}'),
+    ('\u{1FC6}', '\u{1FCC}'),
+    ('\u{1FD0}', '\u{1FD3}'),
+    ('\u{1FD6}', '\u{1FDB}'),
+    ('\u{1FE0}', '\u{1FEC}'),
+    ('\u{1FF2}', '\u{1FF4}'),
+    ('\u{1FF6}', '\u{1FFC}'),
+    ('\u{203F}', '\u{2040}'),
+    ('\u{2054}', '\u{2054}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{20D0}', '\u{20DC}'),
+    ('\u{20E1}', '\u{20E1}'),
+    ('\u{20E5}', '\u{20F0}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210A}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211D}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212A}', '\u{2139}'),
+    ('\u{213C}', '\u{213F}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214E}', '\u{214E}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2C00}', '\u{2CE4}'),
+    ('\u{2CEB}', '\u{2CF3}'),
+    ('\u{2D00}', '\u{2D25}'),
+    ('\u{2D27}', '\u{2D27}'),
+    ('\u{2D2D}', '\u{2D2D}'),
+    ('\u{2D30}', '\u{2D67}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D7F}', '\u{2D96}'),
+    ('\u{2DA0}', '\u{2DA6}'),
+    ('\u{2DA8}', '\u{2DAE}'),
+    ('\u{2DB0}', '\u{2DB6}'),
+    ('\u{2DB8}', '\u{2DBE}'),
+    ('\u{2DC0}', '\u{2DC6}'),
+    ('\u{2DC8}', '\u{2DCE}'),
+    ('\u{2DD0}', '\u{2DD6}'),
+    ('\u{2DD8}', '\u{2DDE}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302F}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303C}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309A}'),
+    ('\u{309D}', '\u{309F}'),
+    ('\u{30A1}', '\u{30FA}'),
+    ('\u{30FC}', '\u{30FF}'),
+    ('\u{3105}', '\u{312F}'),
+    ('\u{3131}', '\u{318E}'),
+    ('\u{31A0}', '\u{31BF}'),
+    ('\u{31F0}', '\u{31FF}'),
+    ('\u{3400}', '\u{4DBF}'),
+    ('\u{4E00}', '\u{A48C}'),
+    ('\u{A4D0}', '\u{A4FD}'),
+    ('\u{A500}', '\u{A60C}'),
+    ('\u{A610}', '\u{A62B}'),
+    ('\u{A640}', '\u{A66F}'),
+    ('\u{A674}', '\u{A67D}'),
+    ('\u{A67F}', '\u{A6F1}'),
+    ('\u{A717}', '\u{A71F}'),
+    ('\u{A722}', '\u{A788}'),
+    ('\u{A78B}', '\u{A7CA}'),
+    ('\u{A7D0}', '\u{A7D1}'),
+    ('\u{A7D3}', '\u{A7D3}'),
+    ('\u{A7D5}', '\u{A7D9}'),
+    ('\u{A7F2}', '\u{A827}'),
+    ('\u{A82C}', '\u{A82C}'),
+    ('\u{A840}', '\u{A873}'),
+    ('\u{A880}', '\u{A8C5}'),
+    ('\u{A8D0}', '\u{A8D9}'),
+    ('\u{A8E0}', '\u{A8F7}'),
+    ('\u{A8FB}', '\u{A8FB}'),
+    ('\u{A8FD}', '\u{A92D}'),
+    ('\u{A930}', '\u{A953}'),
+    ('\u{A960}', '\u{A97C}'),
+    ('\u{A980}', '\u{A9C0}'),
+    ('\u{A9CF}', '\u{A9D9}'),
+    ('\u{A9E0}', '\u{A9FE}'),
+    ('\u{AA00}', '\u{AA36}'),
+    ('\u{AA40}', '\u{AA4D}'),
+    ('\u{AA50}', '\u{AA59}'),
+    ('\u{AA60}', '\u{AA76}'),
+    ('\u{AA7A}', '\u{AAC2}'),
+    ('\u{AADB}', '\u{AADD}'),
+    ('\u{AAE0}', '\u{AAEF}'),
+    ('\u{AAF2}', '\u{AAF6}'),
+    ('\u{AB01}', '\u{AB06}'),
+    ('\u{AB09}', '\u{AB0E}'),
+    ('\u{AB11}', '\u{AB16}'),
+    ('\u{AB20}', '\u{AB26}'),
+    ('\u{AB28}', '\u{AB2E}'),
+    ('\u{AB30}', '\u{AB5A}'),
+    ('\u{AB5C}', '\u{AB69}'),
+    ('\u{AB70}', '\u{ABEA}'),
+    ('\u{ABEC}', '\u{ABED}'),
+    ('\u{ABF0}', '\u{ABF9}'),
+    ('\u{AC00}', '\u{D7A3}'),
+    ('\u{D7B0}', '\u{D7C6}'),
+    ('\u{D7CB}', '\u{D7FB}'),
+    ('\u{F900}', '\u{FA6D}'),
+    ('\u{FA70}', '\u{FAD9}'),
+    ('\u{FB00}', '\u{FB06}'),
+    ('\u{FB13}', '\u{FB17}'),
+    ('\u{FB1D}', '\u{FB28}'),
+    ('\u{FB2A}', '\u{FB36}'),
+    ('\u{FB38}', '\u{FB3C}'),
+    ('\u{FB3E}', '\u{FB3E}'),
+    ('\u{FB40}', '\u{FB41}'),
+    ('\u{FB43}', '\u{FB44}'),
+    ('\u{FB46}', '\u{FBB1}'),
+    ('\u{FBD3}', '\u{FC5D}'),
+    ('\u{FC64}', '\u{FD3D}'),
+    ('\u{FD50}', '\u{FD8F}'),
+    ('\u{FD92}', '\u{FDC7}'),
+    ('\u{FDF0}', '\u{FDF9}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FE20}', '\u{FE2F}'),
+    ('\u{FE33}', '\u{FE34}'),
+    ('\u{FE4D}', '\u{FE4F}'),
+    ('\u{FE71}', '\u{FE71}'),
+    ('\u{FE73}', '\u{FE73}'),
+    ('\u{FE77}', '\u{FE77}'),
+    ('\u{FE79}', '\u{FE79}'),
+    ('\u{FE7B}', '\u{FE7B}'),
+    ('\u{FE7D}', '\u{FE7D}'),
+    ('\u{FE7F}', '\u{FEFC}'),
+    ('\u{FF10}', '\u{FF19}'),
+    ('\u{FF21}', '\u{FF3A}'),
+    ('\u{FF3F}', '\u{FF3F}'),
+    ('\u{FF41}', '\u{FF5A}'),
+    ('\u{FF66}', '\u{FFBE}'),
+    ('\u{FFC2}', '\u{FFC7}'),
+    ('\u{FFCA}', '\u{FFCF}'),
+    ('\u{FFD2}', '\u{FFD7}'),
+    ('\u{FFDA}', '\u{FFDC}'),
+    ('\u{10000}', '\u{1000B}'),
+    ('\u{1000D}', '\u{10026}'),
+    ('\u{10028}', '\u{1003A}'),
+    ('\u{1003C}', '\u{1003D}'),
+    ('\u{1003F}', '\u{1004D}'),
+    ('\u{10050}', '\u{1005D}'),
+    ('\u{10080}', '\u{100FA}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{101FD}', '\u{101FD}'),
+    ('\u{10280}', '\u{1029C}'),
+    ('\u{102A0}', '\u{102D0}'),
+    ('\u{102E0}', '\u{102E0}'),
+    ('\u{10300}', '\u{1031F}'),
+    ('\u{1032D}', '\u{1034A}'),
+    ('\u{10350}', '\u{1037A}'),
+    ('\u{10380}', '\u{1039D}'),
+    ('\u{103A0}', '\u{103C3}'),
+    ('\u{103C8}', '\u{103CF}'),
+    ('\u{103D1}', '\u{103D5}'),
+    ('\u{10400}', '\u{1049D}'),
+    ('\u{104A0}', '\u{104A9}'),
+    ('\u{104B0}', '\u{104D3}'),
+    ('\u{104D8}', '\u{104FB}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057A}'),
+    ('\u{1057C}', '\u{1058A}'),
+    ('\u{1058C}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105A1}'),
+    ('\u{105A3}', '\u{105B1}'),
+    ('\u{105B3}', '\u{105B9}'),
+    ('\u{105BB}', '\u{105BC}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080A}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083C}', '\u{1083C}'),
+    ('\u{1083F}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089E}'),
+    ('\u{108E0}', '\u{108F2}'),
+    ('\u{108F4}', '\u{108F5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109B7}'),
+    ('\u{109BE}', '\u{109BF}'),
+    ('\u{10A00}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'),
+    ('\u{10A0C}', '\u{10A13}'),
+    ('\u{10A15}', '\u{10A17}'),
+    ('\u{10A19}', '\u{10A35}'),
+    ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'),
+    ('\u{10A60}', '\u{10A7C}'),
+    ('\u{10A80}', '\u{10A9C}'),
+    ('\u{10AC0}', '\u{10AC7}'),
+    ('\u{10AC9}', '\u{10AE6}'),
+    ('\u{10B00}', '\u{10B35}'),
+    ('\u{10B40}', '\u{10B55}'),
+    ('\u{10B60}', '\u{10B72}'),
+    ('\u{10B80}', '\u{10B91}'),
+    ('\u{10C00}', '\u{10C48}'),
+    ('\u{10C80}', '\u{10CB2}'),
+    ('\u{10CC0}', '\u{10CF2}'),
+    ('\u{10D00}', '\u{10D27}'),
+    ('\u{10D30}', '\u{10D39}'),
+    ('\u{10E80}', '\u{10EA9}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10EB0}', '\u{10EB1}'),
+    ('\u{10F00}', '\u{10F1C}'),
+    ('\u{10F27}', '\u{10F27}'),
+    ('\u{10F30}', '\u{10F50}'),
+    ('\u{10F70}', '\u{10F85}'),
+    ('\u{10FB0}', '\u{10FC4}'),
+    ('\u{10FE0}', '\u{10FF6}'),
+    ('\u{11000}', '\u{11046}'),
+    ('\u{11066}', '\u{11075}'),
+    ('\u{1107F}', '\u{110BA}'),
+    ('\u{110C2}', '\u{110C2}'),
+    ('\u{110D0}', '\u{110E8}'),
+    ('\u{110F0}', '\u{110F9}'),
+    ('\u{11100}', '\u{11134}'),
+    ('\u{11136}', '\u{1113F}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111C4}'),
+    ('\u{111C9}', '\u{111CC}'),
+    ('\u{111CE}', '\u{111DA}'),
+    ('\u{111DC}', '\u{111DC}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11237}'),
+    ('\u{1123E}', '\u{1123E}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128A}', '\u{1128D}'),
+    ('\u{1128F}', '\u{1129D}'),
+    ('\u{1129F}', '\u{112A8}'),
+    ('\u{112B0}', '\u{112EA}'),
+    ('\u{112F0}', '\u{112F9}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130C}'),
+    ('\u{1130F}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132A}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133B}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134B}', '\u{1134D}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135D}', '\u{11363}'),
+    ('\u{11366}', '\u{1136C}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11400}', '\u{1144A}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{1145E}', '\u{11461}'),
+    ('\u{11480}', '\u{114C5}'),
+    ('\u{114C7}', '\u{114C7}'),
+    ('\u{114D0}', '\u{114D9}'),
+    ('\u{11580}', '\u{115B5}'),
+    ('\u{115B8}', '\u{115C0}'),
+    ('\u{115D8}', '\u{115DD}'),
+    ('\u{11600}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116B8}'),
+    ('\u{116C0}', '\u{116C9}'),
+    ('\u{11700}', '\u{1171A}'),
+    ('\u{1171D}', '\u{1172B}'),
+    ('\u{11730}', '\u{11739}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1183A}'),
+    ('\u{118A0}', '\u{118E9}'),
+    ('\u{118FF}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190C}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193B}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{119A0}', '\u{119A7}'),
+    ('\u{119AA}', '\u{119D7}'),
+    ('\u{119DA}', '\u{119E1}'),
+    ('\u{119E3}', '\u{119E4}'),
+    ('\u{11A00}', '\u{11A3E}'),
+    ('\u{11A47}', '\u{11A47}'),
+    ('\u{11A50}', '\u{11A99}'),
+    ('\u{11A9D}', '\u{11A9D}'),
+    ('\u{11AB0}', '\u{11AF8}'),
+    ('\u{11C00}', '\u{11C08}'),
+    ('\u{11C0A}', '\u{11C36}'),
+    ('\u{11C38}', '\u{11C40}'),
+    ('\u{11C50}', '\u{11C59}'),
+    ('\u{11C72}', '\u{11C8F}'),
+    ('\u{11C92}', '\u{11CA7}'),
+    ('\u{11CA9}', '\u{11CB6}'),
+    ('\u{11D00}', '\u{11D06}'),
+    ('\u{11D08}', '\u{11D09}'),
+    ('\u{11D0B}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'),
+    ('\u{11D3C}', '\u{11D3D}'),
+    ('\u{11D3F}', '\u{11D47}'),
+    ('\u{11D50}', '\u{11D59}'),
+    ('\u{11D60}', '\u{11D65}'),
+    ('\u{11D67}', '\u{11D68}'),
+    ('\u{11D6A}', '\u{11D8E}'),
+    ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D93}', '\u{11D98}'),
+    ('\u{11DA0}', '\u{11DA9}'),
+    ('\u{11EE0}', '\u{11EF6}'),
+    ('\u{11FB0}', '\u{11FB0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246E}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12F90}', '\u{12FF0}'),
+    ('\u{13000}', '\u{1342E}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16A38}'),
+    ('\u{16A40}', '\u{16A5E}'),
+    ('\u{16A60}', '\u{16A69}'),
+    ('\u{16A70}', '\u{16ABE}'),
+    ('\u{16AC0}', '\u{16AC9}'),
+    ('\u{16AD0}', '\u{16AED}'),
+    ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B00}', '\u{16B36}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16B50}', '\u{16B59}'),
+    ('\u{16B63}', '\u{16B77}'),
+    ('\u{16B7D}', '\u{16B8F}'),
+    ('\u{16E40}', '\u{16E7F}'),
+    ('\u{16F00}', '\u{16F4A}'),
+    ('\u{16F4F}', '\u{16F87}'),
+    ('\u{16F8F}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE4}'),
+    ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18800}', '\u{18CD5}'),
+    ('\u{18D00}', '\u{18D08}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1B000}', '\u{1B122}'),
+    ('\u{1B150}', '\u{1B152}'),
+    ('\u{1B164}', '\u{1B167}'),
+    ('\u{1B170}', '\u{1B2FB}'),
+    ('\u{1BC00}', '\u{1BC6A}'),
+    ('\u{1BC70}', '\u{1BC7C}'),
+    ('\u{1BC80}', '\u{1BC88}'),
+    ('\u{1BC90}', '\u{1BC99}'),
+    ('\u{1BC9D}', '\u{1BC9E}'),
+    ('\u{1CF00}', '\u{1CF2D}'),
+    ('\u{1CF30}', '\u{1CF46}'),
+    ('\u{1D165}', '\u{1D169}'),
+    ('\u{1D16D}', '\u{1D172}'),
+    ('\u{1D17B}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'),
+    ('\u{1D1AA}', '\u{1D1AD}'),
+    ('\u{1D242}', '\u{1D244}'),
+    ('\u{1D400}', '\u{1D454}'),
+    ('\u{1D456}', '\u{1D49C}'),
+    ('\u{1D49E}', '\u{1D49F}'),
+    ('\u{1D4A2}', '\u{1D4A2}'),
+    ('\u{1D4A5}', '\u{1D4A6}'),
+    ('\u{1D4A9}', '\u{1D4AC}'),
+    ('\u{1D4AE}', '\u{1D4B9}'),
+    ('\u{1D4BB}', '\u{1D4BB}'),
+    ('\u{1D4BD}', '\u{1D4C3}'),
+    ('\u{1D4C5}', '\u{1D505}'),
+    ('\u{1D507}', '\u{1D50A}'),
+    ('\u{1D50D}', '\u{1D514}'),
+    ('\u{1D516}', '\u{1D51C}'),
+    ('\u{1D51E}', '\u{1D539}'),
+    ('\u{1D53B}', '\u{1D53E}'),
+    ('\u{1D540}', '\u{1D544}'),
+    ('\u{1D546}', '\u{1D546}'),
+    ('\u{1D54A}', '\u{1D550}'),
+    ('\u{1D552}', '\u{1D6A5}'),
+    ('\u{1D6A8}', '\u{1D6C0}'),
+    ('\u{1D6C2}', '\u{1D6DA}'),
+    ('\u{1D6DC}', '\u{1D6FA}'),
+    ('\u{1D6FC}', '\u{1D714}'),
+    ('\u{1D716}', '\u{1D734}'),
+    ('\u{1D736}', '\u{1D74E}'),
+    ('\u{1D750}', '\u{1D76E}'),
+    ('\u{1D770}', '\u{1D788}'),
+    ('\u{1D78A}', '\u{1D7A8}'),
+    ('\u{1D7AA}', '\u{1D7C2}'),
+    ('\u{1D7C4}', '\u{1D7CB}'),
+    ('\u{1D7CE}', '\u{1D7FF}'),
+    ('\u{1DA00}', '\u{1DA36}'),
+    ('\u{1DA3B}', '\u{1DA6C}'),
+    ('\u{1DA75}', '\u{1DA75}'),
+    ('\u{1DA84}', '\u{1DA84}'),
+    ('\u{1DA9B}', '\u{1DA9F}'),
+    ('\u{1DAA1}', '\u{1DAAF}'),
+    ('\u{1DF00}', '\u{1DF1E}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E100}', '\u{1E12C}'),
+    ('\u{1E130}', '\u{1E13D}'),
+    ('\u{1E140}', '\u{1E149}'),
+    ('\u{1E14E}', '\u{1E14E}'),
+    ('\u{1E290}', '\u{1E2AE}'),
+    ('\u{1E2C0}', '\u{1E2F9}'),
+    ('\u{1E7E0}', '\u{1E7E6}'),
+    ('\u{1E7E8}', '\u{1E7EB}'),
+    ('\u{1E7ED}', '\u{1E7EE}'),
+    ('\u{1E7F0}', '\u{1E7FE}'),
+    ('\u{1E800}', '\u{1E8C4}'),
+    ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E900}', '\u{1E94B}'),
+    ('\u{1E950}', '\u{1E959}'),
+    ('\u{1EE00}', '\u{1EE03}'),
+    ('\u{1EE05}', '\u{1EE1F}'),
+    ('\u{1EE21}', '\u{1EE22}'),
+    ('\u{1EE24}', '\u{1EE24}'),
+    ('\u{1EE27}', '\u{1EE27}'),
+    ('\u{1EE29}', '\u{1EE32}'),
+    ('\u{1EE34}', '\u{1EE37}'),
+    ('\u{1EE39}', '\u{1EE39}'),
+    ('\u{1EE3B}', '\u{1EE3B}'),
+    ('\u{1EE42}', '\u{1EE42}'),
+    ('\u{1EE47}', '\u{1EE47}'),
+    ('\u{1EE49}', '\u{1EE49}'),
+    ('\u{1EE4B}', '\u{1EE4B}'),
+    ('\u{1EE4D}', '\u{1EE4F}'),
+    ('\u{1EE51}', '\u{1EE52}'),
+    ('\u{1EE54}', '\u{1EE54}'),
+    ('\u{1EE57}', '\u{1EE57}'),
+    ('\u{1EE59}', '\u{1EE59}'),
+    ('\u{1EE5B}', '\u{1EE5B}'),
+    ('\u{1EE5D}', '\u{1EE5D}'),
+    ('\u{1EE5F}', '\u{1EE5F}'),
+    ('\u{1EE61}', '\u{1EE62}'),
+    ('\u{1EE64}', '\u{1EE64}'),
+    ('\u{1EE67}', '\u{1EE6A}'),
+    ('\u{1EE6C}', '\u{1EE72}'),
+    ('\u{1EE74}', '\u{1EE77}'),
+    ('\u{1EE79}', '\u{1EE7C}'),
+    ('\u{1EE7E}', '\u{1EE7E}'),
+    ('\u{1EE80}', '\u{1EE89}'),
+    ('\u{1EE8B}', '\u{1EE9B}'),
+    ('\u{1EEA1}', '\u{1EEA3}'),
+    ('\u{1EEA5}', '\u{1EEA9}'),
+    ('\u{1EEAB}', '\u{1EEBB}'),
+    ('\u{1FBF0}', '\u{1FBF9}'),
+    ('\u{20000}', '\u{2A6DF}'),
+    ('\u{2A700}', '\u{2B738}'),
+    ('\u{2B740}', '\u{2B81D}'),
+    ('\u{2B820}', '\u{2CEA1}'),
+    ('\u{2CEB0}', '\u{2EBE0}'),
+    ('\u{2F800}', '\u{2FA1D}'),
+    ('\u{30000}', '\u{3134A}'),
+    ('\u{E0100}', '\u{E01EF}'),
+];
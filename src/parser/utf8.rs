@@ -0,0 +1,193 @@
+use crate::combinator::RepeatCount;
+use crate::{Either, ParsedItem, Parser, ParserResult};
+
+/// A parser whose consumed input is always valid UTF-8.
+///
+/// This is returned by parsers that are known to consume valid UTF-8, such as the `utf8_*` parsers
+/// and those in [`ascii::utf8`](crate::parser::ascii::utf8). Combining these parsers with the
+/// methods provided here preserves the guarantee, permitting [`recognize_str`](Self::recognize_str)
+/// to output the consumed input as a string without validating it.
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8<P>(P);
+
+impl<P> Utf8<P> {
+    /// Mark the parser as consuming only valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// For any input, the bytes consumed by the parser when it succeeds must be valid UTF-8. If the
+    /// parser outputs bytes, they must also be valid UTF-8.
+    pub const unsafe fn new_unchecked(parser: P) -> Self {
+        Self(parser)
+    }
+
+    /// Run this parser followed by another that consumes valid UTF-8, as with [`Parser::and`].
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip] // rust-lang/rustfmt#3599
+    pub fn and_utf8<'input, P2>(
+        self,
+        other: Utf8<P2>,
+    ) -> Utf8<
+        impl Parser<
+            'input,
+            Output = (P::Output, P2::Output),
+            Error = Either<P::Error, P2::Error>,
+        >,
+    >
+    where
+        P: Parser<'input>,
+        P2: Parser<'input>,
+    {
+        // Safety: The concatenation of valid UTF-8 is valid UTF-8.
+        unsafe { Utf8::new_unchecked(self.0.and(other.0)) }
+    }
+
+    /// Run the parser, converting the bytes it outputs to a string.
+    ///
+    /// Unlike [`Parser::as_str`], the output is not validated, as it is known to be valid UTF-8.
+    pub fn as_str<'input>(self) -> impl Parser<'input, Output = &'input str, Error = P::Error>
+    where
+        P: Parser<'input, Output = &'input [u8]>,
+    {
+        move |input| {
+            let (remaining_input, output) = self.0.parse(input)?.into_parts();
+            // Safety: The parser only outputs valid UTF-8.
+            let output = unsafe { core::str::from_utf8_unchecked(output) };
+            Ok(ParsedItem::from_parts(remaining_input, output))
+        }
+    }
+
+    /// Run this parser, or another that consumes valid UTF-8 if it fails, as with [`Parser::or`].
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip] // rust-lang/rustfmt#3599
+    pub fn or_utf8<'input, P2>(
+        self,
+        other: Utf8<P2>,
+    ) -> Utf8<
+        impl Parser<
+            'input,
+            Output = Either<P::Output, P2::Output>,
+            Error = (P::Error, P2::Error),
+        >,
+    >
+    where
+        P: Parser<'input>,
+        P2: Parser<'input>,
+    {
+        // Safety: Only one of the parsers consumes input, and both consume valid UTF-8.
+        unsafe { Utf8::new_unchecked(self.0.or(other.0)) }
+    }
+
+    /// Run the parser repeatedly, as with [`Parser::repeat_raw`].
+    pub fn repeat_raw<'input, R>(
+        self,
+        count: R,
+    ) -> Utf8<impl Parser<'input, Output = &'input [u8], Error = P::Error>>
+    where
+        P: Parser<'input>,
+        R: RepeatCount,
+    {
+        // Safety: The concatenation of valid UTF-8 is valid UTF-8.
+        unsafe { Utf8::new_unchecked(self.0.repeat_raw(count)) }
+    }
+
+    /// Run the parser, outputting the consumed input as a string.
+    ///
+    /// Unlike [`Parser::as_str`], the input is not validated, as it is known to be valid UTF-8.
+    pub fn recognize_str<'input>(
+        self,
+    ) -> impl Parser<'input, Output = &'input str, Error = P::Error>
+    where
+        P: Parser<'input>,
+    {
+        move |input: &'input [u8]| {
+            let remaining_input = self.0.parse(input)?.input();
+            let output = &input[..(input.len() - remaining_input.len())];
+            // Safety: The parser only consumes valid UTF-8.
+            let output = unsafe { core::str::from_utf8_unchecked(output) };
+            Ok(ParsedItem::from_parts(remaining_input, output))
+        }
+    }
+}
+
+impl<'input, P> Parser<'input> for Utf8<P>
+where
+    P: Parser<'input>,
+{
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(self, input: &'input [u8]) -> ParserResult<'input, Self::Output, Self::Error> {
+        self.0.parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error;
+    use crate::parser::{ascii, utf8_char, utf8_take_while};
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_as_str() {
+        let parser = utf8_char('é').repeat_raw(1..).as_str();
+
+        assert_eq!(
+            parser.parse("ééx".as_bytes()).map(ParsedItem::into_parts),
+            Ok((b"x".as_ref(), "éé"))
+        );
+        assert_eq!(parser.parse(b"xy"), Err(Either::A(error::Utf8Char)));
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_recognize_str() {
+        let parser = ascii::utf8::alphabetic()
+            .and_utf8(ascii::utf8::alphanumeric().repeat_raw(..))
+            .recognize_str();
+
+        assert_eq!(
+            parser.parse(b"ab12 c").map(ParsedItem::into_parts),
+            Ok((b" c".as_ref(), "ab12"))
+        );
+        assert_eq!(
+            parser.parse(b"1ab"),
+            Err(Either::A(Either::A(error::AsciiAlphabetic)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_recognize_str_multibyte() {
+        let parser = utf8_char('é')
+            .or_utf8(ascii::utf8::digit())
+            .repeat_raw(1..)
+            .and_utf8(utf8_take_while(char::is_alphabetic))
+            .recognize_str();
+
+        assert_eq!(
+            parser
+                .parse("é1éжx!".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"!".as_ref(), "é1éжx"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_parser() {
+        assert_eq!(
+            ascii::utf8::hex_digit()
+                .parse(b"f")
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 15))
+        );
+        assert_eq!(
+            ascii::utf8::whitespace()
+                .parse(b"a")
+                .map(ParsedItem::into_parts),
+            Err(Either::A(error::AsciiWhitespace))
+        );
+    }
+}
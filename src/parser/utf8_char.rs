@@ -1,14 +1,17 @@
+use crate::parser::Utf8;
 use crate::{error, hrtb_hack, Either, ParsedItem, Parser};
 
 #[rustfmt::skip] // rust-lang/rustfmt#3599
 pub fn utf8_char(
     c: char,
-) -> impl for<'input> Parser<
-    'input,
-    Output = char,
-    Error = Either<error::Utf8Char, error::EndOfInput>,
+) -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = char,
+        Error = Either<error::Utf8Char, error::EndOfInput>,
+    >,
 > {
-    hrtb_hack(move |input: &[u8]| {
+    let parser = hrtb_hack(move |input: &[u8]| {
         if input.len() < c.len_utf8() {
             return Err(Either::B(error::EndOfInput));
         }
@@ -20,7 +23,9 @@ pub fn utf8_char(
             Some(remaining_input) => Ok(ParsedItem::from_parts(remaining_input, c)),
             None => Err(Either::A(error::Utf8Char)),
         }
-    })
+    });
+    // Safety: The parser only consumes the UTF-8 encoding of a character.
+    unsafe { Utf8::new_unchecked(parser) }
 }
 
 #[cfg(test)]
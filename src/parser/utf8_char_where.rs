@@ -0,0 +1,88 @@
+use crate::parser::{any_utf8_char, Utf8};
+use crate::{error, hrtb_hack, Either, Either3, Parser};
+
+/// Consume a single UTF-8 encoded character that satisfies the predicate.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn utf8_char_where<F>(
+    predicate: F,
+) -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = char,
+        Error = Either3<error::Filter, error::InvalidUtf8, error::EndOfInput>,
+    >,
+>
+where
+    F: Fn(char) -> bool + Copy,
+{
+    let parser = hrtb_hack(move |input| match any_utf8_char(input) {
+        Ok(parsed_item) if predicate(*parsed_item.value()) => Ok(parsed_item),
+        Ok(_) => Err(Either3::A(error::Filter)),
+        Err(Either::A(err)) => Err(Either3::B(err)),
+        Err(Either::B(err)) => Err(Either3::C(err)),
+    });
+    // Safety: The parser only consumes input that was decoded as a character.
+    unsafe { Utf8::new_unchecked(parser) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Utf8ErrorKind;
+    use crate::parser::unicode::{self, GeneralCategory, Script};
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_char_where_valid() {
+        assert_eq!(
+            utf8_char_where(char::is_alphabetic)
+                .parse("ж1".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"1".as_ref(), 'ж'))
+        );
+        assert_eq!(
+            utf8_char_where(
+                #[cfg_attr(coverage, coverage(off))]
+                |c| unicode::script(c) == Script::Greek
+            )
+            .parse("λ".as_bytes())
+            .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), 'λ'))
+        );
+        assert_eq!(
+            utf8_char_where(
+                #[cfg_attr(coverage, coverage(off))]
+                |c| unicode::general_category(c) == GeneralCategory::CurrencySymbol
+            )
+            .parse("€".as_bytes())
+            .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), '€'))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_char_where_invalid() {
+        assert_eq!(
+            utf8_char_where(char::is_alphabetic).parse(b"1"),
+            Err(Either3::A(error::Filter))
+        );
+        assert_eq!(
+            utf8_char_where(char::is_alphabetic).parse(b"\xFF"),
+            Err(Either3::B(error::InvalidUtf8 {
+                kind: Utf8ErrorKind::InvalidStartByte
+            }))
+        );
+        assert_eq!(
+            utf8_char_where(char::is_alphabetic).parse(b"\xD0"),
+            Err(Either3::B(error::InvalidUtf8 {
+                kind: Utf8ErrorKind::Incomplete
+            }))
+        );
+        assert_eq!(
+            utf8_char_where(char::is_alphabetic).parse(b""),
+            Err(Either3::C(error::EndOfInput))
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parser::Utf8;
+use crate::{error, hrtb_hack, Either, ParsedItem, Parser};
+
+/// Consume the expected string.
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn utf8_str(
+    expected: &str,
+) -> Utf8<
+    impl for<'input> Parser<
+        'input,
+        Output = &str,
+        Error = Either<error::NonMatchingInput, error::EndOfInput>,
+    >,
+> {
+    let parser = hrtb_hack(move |input| {
+        if input.len() < expected.len() {
+            return Err(Either::B(error::EndOfInput));
+        }
+
+        match input.strip_prefix(expected.as_bytes()) {
+            Some(remaining_input) => Ok(ParsedItem::from_parts(remaining_input, expected)),
+            None => Err(Either::A(error::NonMatchingInput)),
+        }
+    });
+    // Safety: The parser only consumes input equal to a string.
+    unsafe { Utf8::new_unchecked(parser) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_str_valid() {
+        assert_eq!(
+            utf8_str("héllo")
+                .parse("héllo!".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"!".as_ref(), "héllo"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_str_invalid() {
+        assert_eq!(
+            utf8_str("héllo").parse(b"hello!"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+        assert_eq!(
+            utf8_str("héllo").parse(b"h"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
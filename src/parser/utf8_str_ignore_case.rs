@@ -0,0 +1,98 @@
+use crate::parser::unicode::simple_fold;
+use crate::parser::{any_utf8_char, Utf8};
+use crate::{error, Either, ParsedItem, Parser};
+
+/// Consume the expected string, ignoring case under Unicode simple case folding. The output is
+/// the matching input, which may differ in case from the expected string.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn utf8_str_ignore_case<'input>(
+    expected: &str,
+) -> Utf8<
+    impl Parser<
+        'input,
+        Output = &'input str,
+        Error = Either<error::NonMatchingInput, error::EndOfInput>,
+    > + '_,
+> {
+    let parser = move |input: &'input [u8]| {
+        let mut offset = 0;
+
+        for expected_char in expected.chars() {
+            let remaining_input = &input[offset..];
+            let (after_char, c) = match any_utf8_char(remaining_input) {
+                Ok(parsed_item) => parsed_item.into_parts(),
+                Err(Either::A(error::InvalidUtf8 {
+                    kind: error::Utf8ErrorKind::Incomplete,
+                })) => return Err(Either::B(error::EndOfInput)),
+                Err(Either::A(error::InvalidUtf8 { .. })) => {
+                    return Err(Either::A(error::NonMatchingInput));
+                }
+                Err(Either::B(err)) => return Err(Either::B(err)),
+            };
+            if simple_fold(c) != simple_fold(expected_char) {
+                return Err(Either::A(error::NonMatchingInput));
+            }
+            offset += remaining_input.len() - after_char.len();
+        }
+
+        let (output, remaining_input) = input.split_at(offset);
+        // Safety: Every character in the output was decoded from valid UTF-8.
+        let output = unsafe { core::str::from_utf8_unchecked(output) };
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    };
+    // Safety: The parser only consumes input that was decoded as characters.
+    unsafe { Utf8::new_unchecked(parser) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_str_ignore_case_valid() {
+        assert_eq!(
+            utf8_str_ignore_case("ärger")
+                .parse("ÄRGER!".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"!".as_ref(), "ÄRGER"))
+        );
+        assert_eq!(
+            utf8_str_ignore_case("kelvin")
+                .parse("\u{212A}elvin".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b"".as_ref(), "\u{212A}elvin"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_str_ignore_case_invalid() {
+        assert_eq!(
+            utf8_str_ignore_case("straße").parse(b"STRASSE"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+        assert_eq!(
+            utf8_str_ignore_case("a").parse(b"\xFF"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+        assert_eq!(
+            utf8_str_ignore_case("é").parse(b"\xC3\x28"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_str_ignore_case_end_of_input() {
+        assert_eq!(
+            utf8_str_ignore_case("ärger").parse("ÄR".as_bytes()),
+            Err(Either::B(error::EndOfInput))
+        );
+        assert_eq!(
+            utf8_str_ignore_case("ä").parse(b"\xC3"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use core::convert::Infallible;
+
+use crate::parser::{any_utf8_char, Utf8};
+use crate::{ParsedItem, Parser};
+
+/// Consume UTF-8 encoded characters while the predicate holds, outputting the consumed string.
+///
+/// Consumption stops at the first character that does not satisfy the predicate or that is not
+/// valid UTF-8. The output may be empty.
+pub fn utf8_take_while<'input, F>(
+    predicate: F,
+) -> Utf8<impl Parser<'input, Output = &'input str, Error = Infallible>>
+where
+    F: Fn(char) -> bool + Copy,
+{
+    let parser = move |input: &'input [u8]| {
+        let mut remaining_input = input;
+        while let Ok(parsed_item) = any_utf8_char(remaining_input) {
+            if !predicate(*parsed_item.value()) {
+                break;
+            }
+            remaining_input = parsed_item.input();
+        }
+
+        let output = &input[..(input.len() - remaining_input.len())];
+        // Safety: Every character in the output was decoded from valid UTF-8.
+        let output = unsafe { core::str::from_utf8_unchecked(output) };
+        Ok(ParsedItem::from_parts(remaining_input, output))
+    };
+    // Safety: The parser only consumes input that was decoded as characters.
+    unsafe { Utf8::new_unchecked(parser) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_utf8_take_while() {
+        let parser = utf8_take_while(char::is_alphabetic);
+
+        assert_eq!(
+            parser
+                .parse("añejo 1".as_bytes())
+                .map(ParsedItem::into_parts),
+            Ok((b" 1".as_ref(), "añejo"))
+        );
+        assert_eq!(
+            parser.parse(b"ab\xFF").map(ParsedItem::into_parts),
+            Ok((b"\xFF".as_ref(), "ab"))
+        );
+        assert_eq!(
+            parser.parse(b"1").map(ParsedItem::into_parts),
+            Ok((b"1".as_ref(), ""))
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::parser::{verbatim_with_case, Case};
+use crate::{error, Either, Parser};
+
+/// Consume the expected bytes, ignoring ASCII case. The output is the matching input, which may
+/// differ in case from the expected bytes.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn verbatim_ignore_ascii_case<'input>(
+    expected: &[u8],
+) -> impl Parser<
+    'input,
+    Output = &'input [u8],
+    Error = Either<error::NonMatchingInput, error::EndOfInput>,
+> + '_ {
+    verbatim_with_case(expected, Case::InsensitiveAscii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsedItem;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_verbatim_ignore_ascii_case_valid() {
+        assert_eq!(
+            verbatim_ignore_ascii_case(b"content-length")
+                .parse(b"Content-Length: 5")
+                .map(ParsedItem::into_parts),
+            Ok((b": 5".as_ref(), b"Content-Length".as_ref()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_verbatim_ignore_ascii_case_invalid() {
+        assert_eq!(
+            verbatim_ignore_ascii_case(b"get").parse(b"PUT"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+        assert_eq!(
+            verbatim_ignore_ascii_case(b"\xC3\xA9").parse(b"\xC3\x89"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_verbatim_ignore_ascii_case_end_of_input() {
+        assert_eq!(
+            verbatim_ignore_ascii_case(b"get").parse(b"GE"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::parser::Case;
+use crate::{error, Either, ParsedItem, Parser};
+
+/// Consume the expected bytes, comparing them according to `case`. The output is the matching
+/// input, which may differ in case from the expected bytes.
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip] // rust-lang/rustfmt#3599
+pub fn verbatim_with_case<'input>(
+    expected: &[u8],
+    case: Case,
+) -> impl Parser<
+    'input,
+    Output = &'input [u8],
+    Error = Either<error::NonMatchingInput, error::EndOfInput>,
+> + '_ {
+    move |input: &'input [u8]| {
+        if input.len() < expected.len() {
+            return Err(Either::B(error::EndOfInput));
+        }
+
+        let (output, remaining_input) = input.split_at(expected.len());
+        if case.matches(output, expected) {
+            Ok(ParsedItem::from_parts(remaining_input, output))
+        } else {
+            Err(Either::A(error::NonMatchingInput))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(coverage, coverage(off))]
+    fn test_verbatim_with_case() {
+        assert_eq!(
+            verbatim_with_case(b"select", Case::InsensitiveAscii)
+                .parse(b"SELECT *")
+                .map(ParsedItem::into_parts),
+            Ok((b" *".as_ref(), b"SELECT".as_ref()))
+        );
+        assert_eq!(
+            verbatim_with_case(b"select", Case::Sensitive).parse(b"SELECT *"),
+            Err(Either::A(error::NonMatchingInput))
+        );
+        assert_eq!(
+            verbatim_with_case(b"select", Case::Sensitive).parse(b"sel"),
+            Err(Either::B(error::EndOfInput))
+        );
+    }
+}